@@ -851,6 +851,26 @@ mat_mul! {mat_mul_i32, i32,
     (m127, 127, 127, 127)
 }
 
+#[bench]
+fn mat_mul_widening_i8_i32_m127(bench: &mut test::Bencher)
+{
+    let a = Array::<i8, _>::zeros((127, 127));
+    let b = Array::<i8, _>::zeros((127, 127));
+    let a = black_box(a.view());
+    let b = black_box(b.view());
+    bench.iter(|| a.dot_widening::<i32, _>(&b));
+}
+
+#[bench]
+fn mat_mul_widening_f32_f64_m127(bench: &mut test::Bencher)
+{
+    let a = Array::<f32, _>::zeros((127, 127));
+    let b = Array::<f32, _>::zeros((127, 127));
+    let a = black_box(a.view());
+    let b = black_box(b.view());
+    bench.iter(|| a.dot_widening::<f64, _>(&b));
+}
+
 #[bench]
 fn create_iter_4d(bench: &mut test::Bencher)
 {
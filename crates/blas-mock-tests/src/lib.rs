@@ -4,10 +4,10 @@ use core::cell::RefCell;
 use core::ffi::{c_double, c_float, c_int};
 use std::thread_local;
 
-use cblas_sys::{c_double_complex, c_float_complex, CBLAS_LAYOUT, CBLAS_TRANSPOSE};
+use cblas_sys::{c_double_complex, c_float_complex, CBLAS_DIAG, CBLAS_LAYOUT, CBLAS_SIDE, CBLAS_TRANSPOSE, CBLAS_UPLO};
 
 thread_local! {
-    /// This counter is incremented every time a gemm function is called
+    /// This counter is incremented every time a mocked function is called
     pub static CALL_COUNT: RefCell<usize> = RefCell::new(0);
 }
 
@@ -98,3 +98,593 @@ pub unsafe extern "C" fn cblas_zgemm(
 ) {
     CALL_COUNT.with(|ctx| *ctx.borrow_mut() += 1);
 }
+
+#[rustfmt::skip]
+#[no_mangle]
+#[allow(unused)]
+pub unsafe extern "C" fn cblas_sdot(n: c_int, x: *const c_float, incx: c_int, y: *const c_float, incy: c_int) -> c_float
+{
+    CALL_COUNT.with(|ctx| *ctx.borrow_mut() += 1);
+    0.
+}
+
+#[rustfmt::skip]
+#[no_mangle]
+#[allow(unused)]
+pub unsafe extern "C" fn cblas_ddot(n: c_int, x: *const c_double, incx: c_int, y: *const c_double, incy: c_int) -> c_double
+{
+    CALL_COUNT.with(|ctx| *ctx.borrow_mut() += 1);
+    0.
+}
+
+#[rustfmt::skip]
+#[no_mangle]
+#[allow(unused)]
+pub unsafe extern "C" fn cblas_sgemv(
+    layout: CBLAS_LAYOUT,
+    transa: CBLAS_TRANSPOSE,
+    m: c_int,
+    n: c_int,
+    alpha: c_float,
+    a: *const c_float,
+    lda: c_int,
+    x: *const c_float,
+    incx: c_int,
+    beta: c_float,
+    y: *mut c_float,
+    incy: c_int
+) {
+    CALL_COUNT.with(|ctx| *ctx.borrow_mut() += 1);
+}
+
+#[rustfmt::skip]
+#[no_mangle]
+#[allow(unused)]
+pub unsafe extern "C" fn cblas_dgemv(
+    layout: CBLAS_LAYOUT,
+    transa: CBLAS_TRANSPOSE,
+    m: c_int,
+    n: c_int,
+    alpha: c_double,
+    a: *const c_double,
+    lda: c_int,
+    x: *const c_double,
+    incx: c_int,
+    beta: c_double,
+    y: *mut c_double,
+    incy: c_int
+) {
+    CALL_COUNT.with(|ctx| *ctx.borrow_mut() += 1);
+}
+
+#[rustfmt::skip]
+#[no_mangle]
+#[allow(unused)]
+pub unsafe extern "C" fn cblas_cgemv(
+    layout: CBLAS_LAYOUT,
+    transa: CBLAS_TRANSPOSE,
+    m: c_int,
+    n: c_int,
+    alpha: *const c_float_complex,
+    a: *const c_float_complex,
+    lda: c_int,
+    x: *const c_float_complex,
+    incx: c_int,
+    beta: *const c_float_complex,
+    y: *mut c_float_complex,
+    incy: c_int
+) {
+    CALL_COUNT.with(|ctx| *ctx.borrow_mut() += 1);
+}
+
+#[rustfmt::skip]
+#[no_mangle]
+#[allow(unused)]
+pub unsafe extern "C" fn cblas_zgemv(
+    layout: CBLAS_LAYOUT,
+    transa: CBLAS_TRANSPOSE,
+    m: c_int,
+    n: c_int,
+    alpha: *const c_double_complex,
+    a: *const c_double_complex,
+    lda: c_int,
+    x: *const c_double_complex,
+    incx: c_int,
+    beta: *const c_double_complex,
+    y: *mut c_double_complex,
+    incy: c_int
+) {
+    CALL_COUNT.with(|ctx| *ctx.borrow_mut() += 1);
+}
+
+#[rustfmt::skip]
+#[no_mangle]
+#[allow(unused)]
+pub unsafe extern "C" fn cblas_snrm2(n: c_int, x: *const c_float, incx: c_int) -> c_float
+{
+    CALL_COUNT.with(|ctx| *ctx.borrow_mut() += 1);
+    0.
+}
+
+#[rustfmt::skip]
+#[no_mangle]
+#[allow(unused)]
+pub unsafe extern "C" fn cblas_dnrm2(n: c_int, x: *const c_double, incx: c_int) -> c_double
+{
+    CALL_COUNT.with(|ctx| *ctx.borrow_mut() += 1);
+    0.
+}
+
+#[rustfmt::skip]
+#[no_mangle]
+#[allow(unused)]
+pub unsafe extern "C" fn cblas_scnrm2(n: c_int, x: *const c_float_complex, incx: c_int) -> c_float
+{
+    CALL_COUNT.with(|ctx| *ctx.borrow_mut() += 1);
+    0.
+}
+
+#[rustfmt::skip]
+#[no_mangle]
+#[allow(unused)]
+pub unsafe extern "C" fn cblas_dznrm2(n: c_int, x: *const c_double_complex, incx: c_int) -> c_double
+{
+    CALL_COUNT.with(|ctx| *ctx.borrow_mut() += 1);
+    0.
+}
+
+#[rustfmt::skip]
+#[no_mangle]
+#[allow(unused)]
+pub unsafe extern "C" fn cblas_sasum(n: c_int, x: *const c_float, incx: c_int) -> c_float
+{
+    CALL_COUNT.with(|ctx| *ctx.borrow_mut() += 1);
+    0.
+}
+
+#[rustfmt::skip]
+#[no_mangle]
+#[allow(unused)]
+pub unsafe extern "C" fn cblas_dasum(n: c_int, x: *const c_double, incx: c_int) -> c_double
+{
+    CALL_COUNT.with(|ctx| *ctx.borrow_mut() += 1);
+    0.
+}
+
+#[rustfmt::skip]
+#[no_mangle]
+#[allow(unused)]
+pub unsafe extern "C" fn cblas_saxpy(
+    n: c_int,
+    alpha: c_float,
+    x: *const c_float,
+    incx: c_int,
+    y: *mut c_float,
+    incy: c_int,
+) {
+    CALL_COUNT.with(|ctx| *ctx.borrow_mut() += 1);
+}
+
+#[rustfmt::skip]
+#[no_mangle]
+#[allow(unused)]
+pub unsafe extern "C" fn cblas_daxpy(
+    n: c_int,
+    alpha: c_double,
+    x: *const c_double,
+    incx: c_int,
+    y: *mut c_double,
+    incy: c_int,
+) {
+    CALL_COUNT.with(|ctx| *ctx.borrow_mut() += 1);
+}
+
+#[rustfmt::skip]
+#[no_mangle]
+#[allow(unused)]
+pub unsafe extern "C" fn cblas_caxpy(
+    n: c_int,
+    alpha: *const c_float_complex,
+    x: *const c_float_complex,
+    incx: c_int,
+    y: *mut c_float_complex,
+    incy: c_int,
+) {
+    CALL_COUNT.with(|ctx| *ctx.borrow_mut() += 1);
+}
+
+#[rustfmt::skip]
+#[no_mangle]
+#[allow(unused)]
+pub unsafe extern "C" fn cblas_zaxpy(
+    n: c_int,
+    alpha: *const c_double_complex,
+    x: *const c_double_complex,
+    incx: c_int,
+    y: *mut c_double_complex,
+    incy: c_int,
+) {
+    CALL_COUNT.with(|ctx| *ctx.borrow_mut() += 1);
+}
+
+#[rustfmt::skip]
+#[no_mangle]
+#[allow(unused)]
+pub unsafe extern "C" fn cblas_sscal(
+    n: c_int,
+    alpha: c_float,
+    x: *mut c_float,
+    incx: c_int,
+) {
+    CALL_COUNT.with(|ctx| *ctx.borrow_mut() += 1);
+}
+
+#[rustfmt::skip]
+#[no_mangle]
+#[allow(unused)]
+pub unsafe extern "C" fn cblas_dscal(
+    n: c_int,
+    alpha: c_double,
+    x: *mut c_double,
+    incx: c_int,
+) {
+    CALL_COUNT.with(|ctx| *ctx.borrow_mut() += 1);
+}
+
+#[rustfmt::skip]
+#[no_mangle]
+#[allow(unused)]
+pub unsafe extern "C" fn cblas_cscal(
+    n: c_int,
+    alpha: *const c_float_complex,
+    x: *mut c_float_complex,
+    incx: c_int,
+) {
+    CALL_COUNT.with(|ctx| *ctx.borrow_mut() += 1);
+}
+
+#[rustfmt::skip]
+#[no_mangle]
+#[allow(unused)]
+pub unsafe extern "C" fn cblas_zscal(
+    n: c_int,
+    alpha: *const c_double_complex,
+    x: *mut c_double_complex,
+    incx: c_int,
+) {
+    CALL_COUNT.with(|ctx| *ctx.borrow_mut() += 1);
+}
+
+#[rustfmt::skip]
+#[no_mangle]
+#[allow(unused)]
+pub unsafe extern "C" fn cblas_sger(
+    layout: CBLAS_LAYOUT,
+    m: c_int,
+    n: c_int,
+    alpha: c_float,
+    x: *const c_float,
+    incx: c_int,
+    y: *const c_float,
+    incy: c_int,
+    a: *mut c_float,
+    lda: c_int,
+) {
+    CALL_COUNT.with(|ctx| *ctx.borrow_mut() += 1);
+}
+
+#[rustfmt::skip]
+#[no_mangle]
+#[allow(unused)]
+pub unsafe extern "C" fn cblas_dger(
+    layout: CBLAS_LAYOUT,
+    m: c_int,
+    n: c_int,
+    alpha: c_double,
+    x: *const c_double,
+    incx: c_int,
+    y: *const c_double,
+    incy: c_int,
+    a: *mut c_double,
+    lda: c_int,
+) {
+    CALL_COUNT.with(|ctx| *ctx.borrow_mut() += 1);
+}
+
+#[rustfmt::skip]
+#[no_mangle]
+#[allow(unused)]
+pub unsafe extern "C" fn cblas_cgeru(
+    layout: CBLAS_LAYOUT,
+    m: c_int,
+    n: c_int,
+    alpha: *const c_float_complex,
+    x: *const c_float_complex,
+    incx: c_int,
+    y: *const c_float_complex,
+    incy: c_int,
+    a: *mut c_float_complex,
+    lda: c_int,
+) {
+    CALL_COUNT.with(|ctx| *ctx.borrow_mut() += 1);
+}
+
+#[rustfmt::skip]
+#[no_mangle]
+#[allow(unused)]
+pub unsafe extern "C" fn cblas_zgeru(
+    layout: CBLAS_LAYOUT,
+    m: c_int,
+    n: c_int,
+    alpha: *const c_double_complex,
+    x: *const c_double_complex,
+    incx: c_int,
+    y: *const c_double_complex,
+    incy: c_int,
+    a: *mut c_double_complex,
+    lda: c_int,
+) {
+    CALL_COUNT.with(|ctx| *ctx.borrow_mut() += 1);
+}
+
+#[rustfmt::skip]
+#[no_mangle]
+#[allow(unused)]
+pub unsafe extern "C" fn cblas_strmv(
+    layout: CBLAS_LAYOUT,
+    uplo: CBLAS_UPLO,
+    transa: CBLAS_TRANSPOSE,
+    diag: CBLAS_DIAG,
+    n: c_int,
+    a: *const c_float,
+    lda: c_int,
+    x: *mut c_float,
+    incx: c_int,
+) {
+    CALL_COUNT.with(|ctx| *ctx.borrow_mut() += 1);
+}
+
+#[rustfmt::skip]
+#[no_mangle]
+#[allow(unused)]
+pub unsafe extern "C" fn cblas_dtrmv(
+    layout: CBLAS_LAYOUT,
+    uplo: CBLAS_UPLO,
+    transa: CBLAS_TRANSPOSE,
+    diag: CBLAS_DIAG,
+    n: c_int,
+    a: *const c_double,
+    lda: c_int,
+    x: *mut c_double,
+    incx: c_int,
+) {
+    CALL_COUNT.with(|ctx| *ctx.borrow_mut() += 1);
+}
+
+#[rustfmt::skip]
+#[no_mangle]
+#[allow(unused)]
+pub unsafe extern "C" fn cblas_ctrmv(
+    layout: CBLAS_LAYOUT,
+    uplo: CBLAS_UPLO,
+    transa: CBLAS_TRANSPOSE,
+    diag: CBLAS_DIAG,
+    n: c_int,
+    a: *const c_float_complex,
+    lda: c_int,
+    x: *mut c_float_complex,
+    incx: c_int,
+) {
+    CALL_COUNT.with(|ctx| *ctx.borrow_mut() += 1);
+}
+
+#[rustfmt::skip]
+#[no_mangle]
+#[allow(unused)]
+pub unsafe extern "C" fn cblas_ztrmv(
+    layout: CBLAS_LAYOUT,
+    uplo: CBLAS_UPLO,
+    transa: CBLAS_TRANSPOSE,
+    diag: CBLAS_DIAG,
+    n: c_int,
+    a: *const c_double_complex,
+    lda: c_int,
+    x: *mut c_double_complex,
+    incx: c_int,
+) {
+    CALL_COUNT.with(|ctx| *ctx.borrow_mut() += 1);
+}
+
+#[rustfmt::skip]
+#[no_mangle]
+#[allow(unused)]
+pub unsafe extern "C" fn cblas_ssyrk(
+    layout: CBLAS_LAYOUT,
+    uplo: CBLAS_UPLO,
+    trans: CBLAS_TRANSPOSE,
+    n: c_int,
+    k: c_int,
+    alpha: c_float,
+    a: *const c_float,
+    lda: c_int,
+    beta: c_float,
+    c: *mut c_float,
+    ldc: c_int,
+) {
+    CALL_COUNT.with(|ctx| *ctx.borrow_mut() += 1);
+}
+
+#[rustfmt::skip]
+#[no_mangle]
+#[allow(unused)]
+pub unsafe extern "C" fn cblas_dsyrk(
+    layout: CBLAS_LAYOUT,
+    uplo: CBLAS_UPLO,
+    trans: CBLAS_TRANSPOSE,
+    n: c_int,
+    k: c_int,
+    alpha: c_double,
+    a: *const c_double,
+    lda: c_int,
+    beta: c_double,
+    c: *mut c_double,
+    ldc: c_int,
+) {
+    CALL_COUNT.with(|ctx| *ctx.borrow_mut() += 1);
+}
+
+#[rustfmt::skip]
+#[no_mangle]
+#[allow(unused)]
+pub unsafe extern "C" fn cblas_csyrk(
+    layout: CBLAS_LAYOUT,
+    uplo: CBLAS_UPLO,
+    trans: CBLAS_TRANSPOSE,
+    n: c_int,
+    k: c_int,
+    alpha: *const c_float_complex,
+    a: *const c_float_complex,
+    lda: c_int,
+    beta: *const c_float_complex,
+    c: *mut c_float_complex,
+    ldc: c_int,
+) {
+    CALL_COUNT.with(|ctx| *ctx.borrow_mut() += 1);
+}
+
+#[rustfmt::skip]
+#[no_mangle]
+#[allow(unused)]
+pub unsafe extern "C" fn cblas_zsyrk(
+    layout: CBLAS_LAYOUT,
+    uplo: CBLAS_UPLO,
+    trans: CBLAS_TRANSPOSE,
+    n: c_int,
+    k: c_int,
+    alpha: *const c_double_complex,
+    a: *const c_double_complex,
+    lda: c_int,
+    beta: *const c_double_complex,
+    c: *mut c_double_complex,
+    ldc: c_int,
+) {
+    CALL_COUNT.with(|ctx| *ctx.borrow_mut() += 1);
+}
+
+#[rustfmt::skip]
+#[no_mangle]
+#[allow(unused)]
+pub unsafe extern "C" fn cblas_cherk(
+    layout: CBLAS_LAYOUT,
+    uplo: CBLAS_UPLO,
+    trans: CBLAS_TRANSPOSE,
+    n: c_int,
+    k: c_int,
+    alpha: c_float,
+    a: *const c_float_complex,
+    lda: c_int,
+    beta: c_float,
+    c: *mut c_float_complex,
+    ldc: c_int,
+) {
+    CALL_COUNT.with(|ctx| *ctx.borrow_mut() += 1);
+}
+
+#[rustfmt::skip]
+#[no_mangle]
+#[allow(unused)]
+pub unsafe extern "C" fn cblas_zherk(
+    layout: CBLAS_LAYOUT,
+    uplo: CBLAS_UPLO,
+    trans: CBLAS_TRANSPOSE,
+    n: c_int,
+    k: c_int,
+    alpha: c_double,
+    a: *const c_double_complex,
+    lda: c_int,
+    beta: c_double,
+    c: *mut c_double_complex,
+    ldc: c_int,
+) {
+    CALL_COUNT.with(|ctx| *ctx.borrow_mut() += 1);
+}
+
+#[rustfmt::skip]
+#[no_mangle]
+#[allow(unused)]
+pub unsafe extern "C" fn cblas_strsm(
+    layout: CBLAS_LAYOUT,
+    side: CBLAS_SIDE,
+    uplo: CBLAS_UPLO,
+    transa: CBLAS_TRANSPOSE,
+    diag: CBLAS_DIAG,
+    m: c_int,
+    n: c_int,
+    alpha: c_float,
+    a: *const c_float,
+    lda: c_int,
+    b: *mut c_float,
+    ldb: c_int,
+) {
+    CALL_COUNT.with(|ctx| *ctx.borrow_mut() += 1);
+}
+
+#[rustfmt::skip]
+#[no_mangle]
+#[allow(unused)]
+pub unsafe extern "C" fn cblas_dtrsm(
+    layout: CBLAS_LAYOUT,
+    side: CBLAS_SIDE,
+    uplo: CBLAS_UPLO,
+    transa: CBLAS_TRANSPOSE,
+    diag: CBLAS_DIAG,
+    m: c_int,
+    n: c_int,
+    alpha: c_double,
+    a: *const c_double,
+    lda: c_int,
+    b: *mut c_double,
+    ldb: c_int,
+) {
+    CALL_COUNT.with(|ctx| *ctx.borrow_mut() += 1);
+}
+
+#[rustfmt::skip]
+#[no_mangle]
+#[allow(unused)]
+pub unsafe extern "C" fn cblas_ctrsm(
+    layout: CBLAS_LAYOUT,
+    side: CBLAS_SIDE,
+    uplo: CBLAS_UPLO,
+    transa: CBLAS_TRANSPOSE,
+    diag: CBLAS_DIAG,
+    m: c_int,
+    n: c_int,
+    alpha: *const c_float_complex,
+    a: *const c_float_complex,
+    lda: c_int,
+    b: *mut c_float_complex,
+    ldb: c_int,
+) {
+    CALL_COUNT.with(|ctx| *ctx.borrow_mut() += 1);
+}
+
+#[rustfmt::skip]
+#[no_mangle]
+#[allow(unused)]
+pub unsafe extern "C" fn cblas_ztrsm(
+    layout: CBLAS_LAYOUT,
+    side: CBLAS_SIDE,
+    uplo: CBLAS_UPLO,
+    transa: CBLAS_TRANSPOSE,
+    diag: CBLAS_DIAG,
+    m: c_int,
+    n: c_int,
+    alpha: *const c_double_complex,
+    a: *const c_double_complex,
+    lda: c_int,
+    b: *mut c_double_complex,
+    ldb: c_int,
+) {
+    CALL_COUNT.with(|ctx| *ctx.borrow_mut() += 1);
+}
@@ -3,7 +3,7 @@ extern crate ndarray;
 use ndarray::prelude::*;
 
 use blas_mock_tests::CALL_COUNT;
-use ndarray::linalg::general_mat_mul;
+use ndarray::linalg::{general_mat_mul, Diagonal, Triangle};
 use ndarray::Order;
 use ndarray_gen::array_builder::ArrayBuilder;
 
@@ -86,3 +86,129 @@ fn test_gen_mat_mul_uses_blas()
         }
     }
 }
+
+#[test]
+fn test_norms_use_blas()
+{
+    let count = || CALL_COUNT.with(|ctx| *ctx.borrow());
+
+    // one call per contiguous lane
+    let a = Array2::<f64>::zeros((40, 30));
+    let pre_count = count();
+    a.norm_l2();
+    assert_eq!(count() - pre_count, 40);
+    let pre_count = count();
+    a.t().norm_l1();
+    assert_eq!(count() - pre_count, 40);
+    let pre_count = count();
+    a.column(0).norm_l2();
+    a.slice(s![..;-1, 0]).norm_l1();
+    assert_eq!(count() - pre_count, 2);
+
+    // too small, or no BLAS compatible layout
+    let pre_count = count();
+    a.slice(s![..4, ..4]).norm_l2();
+    a.slice(s![.., ..;-1]).norm_l2();
+    a.slice(s![..;2, ..;2]).norm_l1();
+    assert_eq!(count() - pre_count, 0);
+}
+
+#[test]
+fn test_level_1_uses_blas()
+{
+    let count = || CALL_COUNT.with(|ctx| *ctx.borrow());
+
+    let mut y = Array1::<f64>::zeros(80);
+    let x = Array1::<f64>::zeros(80);
+    let pre_count = count();
+    y.scaled_add(2., &x);
+    y.slice_mut(s![..;-2]).scaled_add(2., &x.slice(s![..;2]));
+    assert_eq!(count() - pre_count, 2);
+
+    // one call per contiguous lane
+    let mut a = Array2::<f64>::zeros((40, 30));
+    let pre_count = count();
+    a *= 2.;
+    a.t().to_owned().scaled_add(2., &a.t());
+    assert_eq!(count() - pre_count, 80);
+
+    // too small, broadcasting, or zero that must keep NaNs
+    let pre_count = count();
+    a.slice_mut(s![..4, ..4]).scaled_add(2., &x.slice(s![..4]));
+    a.scaled_add(2., &x.slice(s![..30]));
+    a *= 0.;
+    assert_eq!(count() - pre_count, 0);
+}
+
+#[test]
+fn test_level_2_and_3_kernels_use_blas()
+{
+    let count = || CALL_COUNT.with(|ctx| *ctx.borrow());
+
+    // a matrix times its transpose uses syrk instead of gemm
+    let a = Array2::<f64>::zeros((20, 10));
+    for a in [a.view(), a.t()] {
+        let pre_count = count();
+        a.t().dot(&a);
+        assert_eq!(count() - pre_count, 1);
+    }
+
+    let pre_count = count();
+    Array2::<f64>::eye(10)
+        .solve_triangular(&Array1::zeros(10), Triangle::Upper, Diagonal::NonUnit)
+        .unwrap();
+    assert_eq!(count() - pre_count, 1);
+}
+
+#[test]
+fn test_complex_and_conjugate_products_use_blas()
+{
+    use ndarray::linalg::{general_mat_vec_mul_op, Transpose};
+    use num_complex::Complex64;
+
+    let count = || CALL_COUNT.with(|ctx| *ctx.borrow());
+
+    let a = Array2::<Complex64>::zeros((20, 10));
+    let x = Array1::<Complex64>::zeros(10);
+    let mut y = Array1::<Complex64>::zeros(10);
+    let pre_count = count();
+    a.dot(&x);
+    general_mat_vec_mul_op(
+        Complex64::new(1., 0.),
+        &a,
+        Transpose::ConjTrans,
+        &a.column(0),
+        Complex64::new(0., 0.),
+        &mut y,
+    );
+    general_mat_vec_mul_op(
+        Complex64::new(1., 0.),
+        &a.t(),
+        Transpose::ConjTrans,
+        &x,
+        Complex64::new(0., 0.),
+        &mut a.column(0).to_owned(),
+    );
+    assert_eq!(count() - pre_count, 3);
+
+    // conjugate transpose of a matrix that is stored transposed relative to
+    // the result, in either operand
+    let b = Array2::<Complex64>::zeros((20, 30));
+    let pre_count = count();
+    a.dot_op(Transpose::ConjTrans, &b, Transpose::NoTrans);
+    b.dot_op(Transpose::ConjTrans, &a, Transpose::NoTrans);
+    a.t()
+        .dot_op(Transpose::NoTrans, &b.t(), Transpose::ConjTrans);
+    assert_eq!(count() - pre_count, 3);
+
+    // the operands would need to be conjugated in different memory orders
+    let d = Array2::<Complex64>::zeros((30, 10));
+    let pre_count = count();
+    a.t().dot_op(Transpose::ConjTrans, &d, Transpose::ConjTrans);
+    assert_eq!(count() - pre_count, 0);
+
+    // a matrix times its conjugate transpose uses herk instead of gemm
+    let pre_count = count();
+    a.dot_op(Transpose::NoTrans, &a, Transpose::ConjTrans);
+    assert_eq!(count() - pre_count, 1);
+}
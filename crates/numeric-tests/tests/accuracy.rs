@@ -14,7 +14,7 @@ use rand::{Rng, SeedableRng};
 
 use ndarray::linalg::general_mat_mul;
 use ndarray::prelude::*;
-use ndarray::{Data, LinalgScalar};
+use ndarray::{Compensated, Data, LinalgScalar, Pairwise};
 
 use num_complex::Complex;
 use num_traits::{AsPrimitive, Float};
@@ -83,6 +83,25 @@ where
     gen(d.clone(), rng).mapv(Complex::from) + gen(d, rng).mapv(|x| Complex::new(A::zero(), x))
 }
 
+#[test]
+fn accurate_sum_f32()
+{
+    let rng = &mut SmallRng::from_os_rng();
+    for &n in &[10, 1000, 100_000, 1_000_000] {
+        let a = gen::<f32, _>(Ix1(n), rng).mapv(|x| x + 1.);
+        let b = a.slice(s![..;-2]);
+        let reference = |a: ArrayView1<f32>| a.iter().map(|&x| x as f64).sum::<f64>();
+        for x in [a.view(), b] {
+            let exact = reference(x);
+            let tol = exact.abs() * f32::EPSILON as f64;
+            assert!((x.sum_with(Compensated) as f64 - exact).abs() <= tol);
+            assert!((x.sum_with(Pairwise) as f64 - exact).abs() <= 10. * tol);
+            let ones = Array::ones(x.len());
+            assert!((x.dot_with(&ones, Compensated) as f64 - exact).abs() <= tol);
+        }
+    }
+}
+
 #[test]
 fn accurate_eye_f32()
 {
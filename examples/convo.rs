@@ -5,6 +5,7 @@ extern crate ndarray;
 use num_traits::Float;
 
 use ndarray::prelude::*;
+use ndarray::{PadMode, Zip};
 
 const SOBEL_X: [[f32; 3]; 3] = [[-1., 0., 1.], [-2., 0., 2.], [-1., 0., 1.]];
 const SOBEL_Y: [[f32; 3]; 3] = [[1., 2., 1.], [0., 0., 0.], [-1., -2., -1.]];
@@ -41,6 +42,20 @@ where F: Float
     }
 }
 
+/// Convolve `a` with `kernel` into an array of the same shape, repeating the
+/// elements at the edge of `a` to fill in the border.
+#[cfg(feature = "std")]
+fn conv_3x3_same<F>(a: &ArrayRef2<F>, kernel: &Kernel3x3<F>) -> Array2<F>
+where F: Float
+{
+    let kernel = aview2(kernel);
+    Zip::from(a.padded(&[(1, 1), (1, 1)], PadMode::Edge).windows((3, 3))).map_collect(|w| {
+        Zip::from(w)
+            .and(kernel)
+            .fold(F::zero(), |acc, x, &k| acc + x * k)
+    })
+}
+
 #[cfg(feature = "std")]
 fn main()
 {
@@ -62,6 +77,7 @@ fn main()
         conv_3x3(&a.view(), &mut res.view_mut(), &SOBEL_X);
     }
     println!("{:2}", res);
+    println!("{:2}", conv_3x3_same(&a, &SOBEL_X));
 }
 #[cfg(not(feature = "std"))]
 fn main() {}
@@ -1,6 +1,7 @@
-//! This is an example of sorting arrays along an axis.
-//! This file may not be so instructive except for advanced users, instead it
-//! could be a "feature preview" before sorting is added to the main crate.
+//! This is an example of permuting arrays along an axis.
+//! This file may not be so instructive except for advanced users; for
+//! sorting the elements of each lane, use `sort_axis` and `argsort_axis`
+//! from the main crate instead.
 //!
 use ndarray::prelude::*;
 use ndarray::{Data, RemoveAxis, Zip};
@@ -0,0 +1,637 @@
+// Copyright 2014-2024 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Advanced (“fancy”) indexing: gathering and scattering elements through
+//! integer index arrays, and selecting or assigning elements through boolean
+//! masks.
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+use std::ops::{Add, Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive};
+
+use crate::dimension::abs_index;
+use crate::dimension::broadcast::co_broadcast;
+use crate::imp_prelude::*;
+use crate::{DimMax, NewAxis, Slice, SliceInfoElem, Zip};
+
+/// An element of an advanced indexing expression, see [`ArrayRef::gather`].
+///
+/// An index expression is a list of `GatherElem`, one for each axis of the
+/// array (plus any new axes). Each element is either a basic slicing element,
+/// the same as is used by the [`s![]`](s!) macro, or an array of integer
+/// indices into the corresponding axis.
+///
+/// `GatherElem` can be created with `.into()` from anything that converts into
+/// a [`SliceInfoElem`] (ranges, integers, [`Slice`] and [`NewAxis`]) and from
+/// arrays and array views with `usize` elements.
+#[derive(Debug, Clone)]
+pub enum GatherElem<'a>
+{
+    /// A basic slicing element: a range, a single index or a new axis.
+    Basic(SliceInfoElem),
+    /// An array of indices into the axis.
+    Indices(ArrayViewD<'a, usize>),
+}
+
+impl From<SliceInfoElem> for GatherElem<'_>
+{
+    #[inline]
+    fn from(elem: SliceInfoElem) -> Self
+    {
+        GatherElem::Basic(elem)
+    }
+}
+
+macro_rules! impl_gatherelem_from_basic {
+    ($($from:ty),*) => {
+        $(
+            impl From<$from> for GatherElem<'_> {
+                #[inline]
+                fn from(elem: $from) -> Self {
+                    GatherElem::Basic(SliceInfoElem::from(elem))
+                }
+            }
+        )*
+    };
+}
+impl_gatherelem_from_basic!(Slice, RangeFull, NewAxis, isize, usize, i32);
+impl_gatherelem_from_basic!(Range<isize>, RangeFrom<isize>, RangeTo<isize>, RangeInclusive<isize>, RangeToInclusive<isize>);
+impl_gatherelem_from_basic!(Range<usize>, RangeFrom<usize>, RangeTo<usize>, RangeInclusive<usize>, RangeToInclusive<usize>);
+impl_gatherelem_from_basic!(Range<i32>, RangeFrom<i32>, RangeTo<i32>, RangeInclusive<i32>, RangeToInclusive<i32>);
+
+impl<'a, E> From<ArrayView<'a, usize, E>> for GatherElem<'a>
+where E: Dimension
+{
+    #[inline]
+    fn from(indices: ArrayView<'a, usize, E>) -> Self
+    {
+        GatherElem::Indices(indices.into_dyn())
+    }
+}
+
+impl<'a, S, E> From<&'a ArrayBase<S, E>> for GatherElem<'a>
+where
+    S: Data<Elem = usize>,
+    E: Dimension,
+{
+    #[inline]
+    fn from(indices: &'a ArrayBase<S, E>) -> Self
+    {
+        GatherElem::Indices(indices.view().into_dyn())
+    }
+}
+
+/// How elements are combined when scattering values into an array, see
+/// [`ArrayRef::scatter`] and [`ArrayRef::put_along_axis`].
+///
+/// When an index is repeated, every value written to it is combined in turn,
+/// in the order of the indices; like NumPy’s `np.add.at` for
+/// `ScatterMode::Add`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ScatterMode
+{
+    /// Replace the element by the value; the last value for a repeated index
+    /// wins.
+    Overwrite,
+    /// Add the value to the element.
+    Add,
+    /// Keep the smaller of the element and the value. Values that do not
+    /// compare (like NaN) leave the element unchanged.
+    Min,
+    /// Keep the larger of the element and the value. Values that do not
+    /// compare (like NaN) leave the element unchanged.
+    Max,
+}
+
+impl ScatterMode
+{
+    #[inline]
+    fn combine<A>(self, elt: &mut A, value: &A)
+    where A: Clone + Add<Output = A> + PartialOrd
+    {
+        match self {
+            ScatterMode::Overwrite => *elt = value.clone(),
+            ScatterMode::Add => *elt = elt.clone() + value.clone(),
+            ScatterMode::Min =>
+                if *value < *elt {
+                    *elt = value.clone();
+                },
+            ScatterMode::Max =>
+                if *value > *elt {
+                    *elt = value.clone();
+                },
+        }
+    }
+}
+
+/// An advanced index applied to one axis of the basic-sliced view.
+enum AdvancedIndex<'a>
+{
+    /// A single index; takes part in broadcasting as a 0-dimensional array.
+    Scalar(usize),
+    /// An index array, broadcast to the common index shape.
+    Array(ArrayViewD<'a, usize>),
+}
+
+impl<A, D> ArrayRef<A, D>
+where D: Dimension
+{
+    /// Gather elements using an advanced (“fancy”) index expression and copy
+    /// them into a new array.
+    ///
+    /// `info` has one element for each axis of the array, plus one for each
+    /// new axis; see [`GatherElem`]. Basic elements (ranges, single indices
+    /// and new axes) work just like in [`.slice()`](Self::slice). Index arrays
+    /// pick arbitrary elements along their axis, following the NumPy rules for
+    /// advanced indexing:
+    ///
+    /// - All index arrays are broadcast together to a common index shape.
+    ///   When index arrays are present, single indices count as
+    ///   0-dimensional index arrays.
+    /// - If all index arrays (and single indices) are next to each other in
+    ///   `info`, the axes of the common index shape replace them in the
+    ///   result. Otherwise, the axes of the common index shape come first in
+    ///   the result, followed by the sliced axes.
+    ///
+    /// If `info` contains no index arrays, the result is the same as
+    /// `self.slice(info).to_owned()`, with dynamic dimensionality.
+    ///
+    /// See also [`.take()`](Self::take) for indexing a single axis and
+    /// [`.select()`](Self::select) for a list of indices along one axis.
+    ///
+    /// **Panics** if the number of elements in `info` does not match the
+    /// dimensionality of the array, if an index is out of bounds or if the
+    /// index arrays can not be broadcast together.
+    ///
+    /// ```
+    /// use ndarray::{array, GatherElem, Slice};
+    ///
+    /// let a = array![[ 0,  1,  2,  3],
+    ///                [ 4,  5,  6,  7],
+    ///                [ 8,  9, 10, 11]];
+    ///
+    /// // Pick the elements at (0, 3), (2, 1) and (1, 1)
+    /// let rows = array![0, 2, 1];
+    /// let cols = array![3, 1, 1];
+    /// let picked = a.gather(&[(&rows).into(), (&cols).into()]);
+    /// assert_eq!(picked, array![3, 9, 5].into_dyn());
+    ///
+    /// // Index arrays can be combined with slicing; here the rows are picked by
+    /// // a 2 × 2 index array, and every other column is kept.
+    /// let rows = array![[0, 1], [2, 0]];
+    /// let picked = a.gather(&[(&rows).into(), GatherElem::from(Slice::from(..).step_by(2))]);
+    /// assert_eq!(picked.shape(), &[2, 2, 2]);
+    /// assert_eq!(picked, array![[[0, 2], [4, 6]], [[8, 10], [0, 2]]].into_dyn());
+    /// ```
+    #[track_caller]
+    pub fn gather(&self, info: &[GatherElem<'_>]) -> ArrayD<A>
+    where A: Clone
+    {
+        let in_ndim = info
+            .iter()
+            .filter(|elem| !matches!(elem, GatherElem::Basic(SliceInfoElem::NewAxis)))
+            .count();
+        assert_eq!(
+            in_ndim,
+            self.ndim(),
+            "ndarray: the index expression has {} axes, but the array has {} axes",
+            in_ndim,
+            self.ndim()
+        );
+        let has_arrays = info
+            .iter()
+            .any(|elem| matches!(elem, GatherElem::Indices(_)));
+
+        // Replace every advanced index by a full slice, and remember which
+        // axis of the sliced view it applies to.
+        let mut basic = Vec::with_capacity(info.len());
+        let mut advanced = Vec::new();
+        let mut in_axis = 0;
+        for elem in info {
+            let out_axis = basic
+                .iter()
+                .filter(|s: &&SliceInfoElem| !s.is_index())
+                .count();
+            match elem {
+                GatherElem::Basic(SliceInfoElem::Index(i)) if has_arrays => {
+                    let len = self.len_of(Axis(in_axis));
+                    let i = *i;
+                    if i < -(len as isize) || i >= len as isize {
+                        panic!("ndarray: index {} is out of bounds for axis {} with length {}", i, in_axis, len);
+                    }
+                    advanced.push((out_axis, AdvancedIndex::Scalar(abs_index(len, i))));
+                    basic.push(SliceInfoElem::from(..));
+                }
+                GatherElem::Basic(elem) => basic.push(*elem),
+                GatherElem::Indices(indices) => {
+                    let len = self.len_of(Axis(in_axis));
+                    if let Some(&max_index) = indices.iter().max() {
+                        if max_index >= len {
+                            panic!(
+                                "ndarray: index {} is out of bounds for axis {} with length {}",
+                                max_index, in_axis, len
+                            );
+                        }
+                    }
+                    advanced.push((out_axis, AdvancedIndex::Array(indices.view())));
+                    basic.push(SliceInfoElem::from(..));
+                }
+            }
+            if !matches!(elem, GatherElem::Basic(SliceInfoElem::NewAxis)) {
+                in_axis += 1;
+            }
+        }
+
+        let view = self.view().into_dyn().slice_move(&basic[..]);
+        if advanced.is_empty() {
+            return view.to_owned();
+        }
+
+        // Broadcast all index arrays to their common shape.
+        let mut index_dim = IxDyn(&[]);
+        for (_, index) in &advanced {
+            if let AdvancedIndex::Array(indices) = index {
+                index_dim = match co_broadcast::<IxDyn, IxDyn, IxDyn>(&index_dim, &indices.raw_dim()) {
+                    Ok(dim) => dim,
+                    Err(_) => panic!(
+                        "ndarray: could not broadcast index arrays together, shapes {:?} and {:?}",
+                        index_dim.slice(),
+                        indices.shape()
+                    ),
+                };
+            }
+        }
+        let broadcast_indices: Vec<_> = advanced
+            .iter()
+            .map(|(_, index)| match index {
+                AdvancedIndex::Scalar(_) => None,
+                AdvancedIndex::Array(indices) => Some(indices.broadcast(index_dim.clone()).unwrap()),
+            })
+            .collect();
+
+        // If the advanced indices are adjacent, the index axes take their
+        // place in the result, otherwise they go first.
+        let adjacent = advanced.windows(2).all(|w| w[1].0 == w[0].0 + 1);
+        let n_leading = if adjacent { advanced[0].0 } else { 0 };
+        let mut outer_shape = view.shape()[..n_leading].to_vec();
+        outer_shape.extend_from_slice(index_dim.slice());
+        let mut out_shape = outer_shape.clone();
+        out_shape.extend(
+            (n_leading..view.ndim())
+                .filter(|&ax| advanced.iter().all(|&(adv_ax, _)| adv_ax != ax))
+                .map(|ax| view.len_of(Axis(ax))),
+        );
+
+        let mut result = Vec::with_capacity(out_shape.iter().product());
+        for index in crate::indices(IxDyn(&outer_shape)) {
+            let mut sub = view.view();
+            for (ax, &i) in index.slice()[..n_leading].iter().enumerate() {
+                sub.collapse_axis(Axis(ax), i);
+            }
+            let index_pos = &index.slice()[n_leading..];
+            for ((ax, adv), indices) in advanced.iter().zip(&broadcast_indices) {
+                let i = match (adv, indices) {
+                    (_, Some(indices)) => indices[index_pos],
+                    (AdvancedIndex::Scalar(i), None) => *i,
+                    (AdvancedIndex::Array(_), None) => unreachable!(),
+                };
+                sub.collapse_axis(Axis(*ax), i);
+            }
+            result.extend(sub.iter().cloned());
+        }
+        unsafe { Array::from_shape_vec_unchecked(out_shape, result) }
+    }
+
+    /// Take elements along `axis` using an array of indices and copy them
+    /// into a new array.
+    ///
+    /// The result has the shape of `self`, with `axis` replaced by the axes of
+    /// `indices`; the equivalent of NumPy’s `np.take(a, indices, axis)`. For a
+    /// one-dimensional `indices` this is the same as
+    /// [`.select()`](Self::select).
+    ///
+    /// See [`.gather()`](Self::gather) for indexing several axes at once.
+    ///
+    /// **Panics** if `axis` or an element of `indices` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![[1., 2., 3.],
+    ///                [4., 5., 6.]];
+    /// let indices = array![[2, 0], [1, 1]];
+    /// let taken = a.take(Axis(1), &indices);
+    /// assert_eq!(taken.shape(), &[2, 2, 2]);
+    /// assert_eq!(taken, array![[[3., 1.], [2., 2.]],
+    ///                          [[6., 4.], [5., 5.]]].into_dyn());
+    /// ```
+    #[track_caller]
+    pub fn take<E>(&self, axis: Axis, indices: &ArrayRef<usize, E>) -> ArrayD<A>
+    where
+        A: Clone,
+        E: Dimension,
+    {
+        assert!(
+            axis.index() < self.ndim(),
+            "ndarray: axis {} is out of bounds for array of dimension {}",
+            axis.index(),
+            self.ndim()
+        );
+        let info: Vec<GatherElem<'_>> = (0..self.ndim())
+            .map(|ax| {
+                if ax == axis.index() {
+                    GatherElem::from(indices.view())
+                } else {
+                    GatherElem::from(..)
+                }
+            })
+            .collect();
+        self.gather(&info)
+    }
+
+    /// Return the elements where `mask` is `true`, in logical order, as a new
+    /// one-dimensional array.
+    ///
+    /// The array and the mask are broadcast together using the usual
+    /// [broadcasting rules](ArrayBase#broadcasting) before selecting; the
+    /// equivalent of NumPy’s `a[mask]`.
+    ///
+    /// **Panics** if broadcasting isn’t possible.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[1., f64::NAN, 3.],
+    ///                [f64::NAN, 5., 6.]];
+    /// let finite = a.mask_select(&a.mapv(|x| !x.is_nan()));
+    /// assert_eq!(finite, array![1., 3., 5., 6.]);
+    ///
+    /// // A mask for the columns is broadcast over the rows
+    /// let b = array![[1, 2, 3],
+    ///                [4, 5, 6]];
+    /// assert_eq!(b.mask_select(&array![true, false, true]), array![1, 3, 4, 6]);
+    /// ```
+    #[track_caller]
+    pub fn mask_select<E>(&self, mask: &ArrayRef<bool, E>) -> Array1<A>
+    where
+        A: Clone,
+        D: DimMax<E>,
+        E: Dimension,
+    {
+        let (view, mask) = match self.broadcast_with(mask) {
+            Ok(views) => views,
+            Err(_) => panic!(
+                "ndarray: could not broadcast array of shape {:?} and mask of shape {:?} together",
+                self.shape(),
+                mask.shape()
+            ),
+        };
+        view.iter()
+            .zip(mask.iter())
+            .filter(|&(_, &m)| m)
+            .map(|(elt, _)| elt.clone())
+            .collect()
+    }
+
+    /// Set the elements where `mask` is `true` to `value`.
+    ///
+    /// The mask is broadcast to the shape of the array if needed; the
+    /// equivalent of NumPy’s `a[mask] = value`.
+    ///
+    /// **Panics** if broadcasting isn’t possible.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let mut a = array![[1., f64::NAN],
+    ///                    [f64::INFINITY, 4.]];
+    /// let not_finite = a.mapv(|x| !x.is_finite());
+    /// a.mask_fill(&not_finite, 0.);
+    /// assert_eq!(a, array![[1., 0.], [0., 4.]]);
+    /// ```
+    #[track_caller]
+    pub fn mask_fill<E>(&mut self, mask: &ArrayRef<bool, E>, value: A)
+    where
+        A: Clone,
+        E: Dimension,
+    {
+        Zip::from(self).and_broadcast(mask).for_each(|elt, &m| {
+            if m {
+                *elt = value.clone();
+            }
+        });
+    }
+
+    /// Assign the elements of `source`, in order, to the positions where
+    /// `mask` is `true`.
+    ///
+    /// The positions are visited in logical order and the mask is broadcast
+    /// to the shape of the array if needed; the equivalent of NumPy’s
+    /// `a[mask] = source`. This is the inverse of
+    /// [`.mask_select()`](Self::mask_select).
+    ///
+    /// **Panics** if broadcasting isn’t possible, or if the length of
+    /// `source` is not the number of `true` positions in the broadcast mask.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let mut a = array![[1, 2, 3],
+    ///                    [4, 5, 6]];
+    /// let odd = a.mapv(|x| x % 2 == 1);
+    /// a.mask_assign(&odd, &array![10, 30, 50]);
+    /// assert_eq!(a, array![[10, 2, 30],
+    ///                      [4, 50, 6]]);
+    /// ```
+    #[track_caller]
+    pub fn mask_assign<E>(&mut self, mask: &ArrayRef<bool, E>, source: &ArrayRef<A, Ix1>)
+    where
+        A: Clone,
+        E: Dimension,
+    {
+        let mask = mask.broadcast_unwrap(self.raw_dim());
+        let n_selected = mask.iter().filter(|&&m| m).count();
+        assert_eq!(
+            n_selected,
+            source.len(),
+            "ndarray: the mask selects {} elements, but the source has length {}",
+            n_selected,
+            source.len()
+        );
+        let mut source = source.iter();
+        for (elt, _) in self.iter_mut().zip(mask.iter()).filter(|(_, &m)| m) {
+            *elt = source.next().unwrap().clone();
+        }
+    }
+
+    /// Along `axis`, write the subviews of `source` into the subviews at
+    /// `indices`, combining them with the existing elements according to
+    /// `mode`.
+    ///
+    /// This is the inverse of [`.select()`](Self::select): the subview
+    /// `source.index_axis(axis, k)` is written to
+    /// `self.index_axis_mut(axis, indices[k])`. Repeated indices accumulate,
+    /// see [`ScatterMode`].
+    ///
+    /// **Panics** if `axis` or an element of `indices` is out of bounds, or if
+    /// the shape of `source` is not the shape of `self` with the length of
+    /// `axis` replaced by `indices.len()`.
+    ///
+    /// ```
+    /// use ndarray::{array, Array1, Axis, ScatterMode};
+    ///
+    /// // Segment sums: add each row into the row of its segment
+    /// let values = array![[1., 2.],
+    ///                     [3., 4.],
+    ///                     [5., 6.]];
+    /// let mut sums = ndarray::Array2::zeros((2, 2));
+    /// sums.scatter(Axis(0), &[1, 0, 1], &values, ScatterMode::Add);
+    /// assert_eq!(sums, array![[3., 4.],
+    ///                         [6., 8.]]);
+    ///
+    /// // Histogram of bin indices
+    /// let bins = [0, 2, 2, 3, 2];
+    /// let mut counts = Array1::<u32>::zeros(4);
+    /// counts.scatter(Axis(0), &bins, &Array1::ones(bins.len()), ScatterMode::Add);
+    /// assert_eq!(counts, array![1, 0, 3, 1]);
+    /// ```
+    #[track_caller]
+    pub fn scatter(&mut self, axis: Axis, indices: &[Ix], source: &ArrayRef<A, D>, mode: ScatterMode)
+    where
+        A: Clone + Add<Output = A> + PartialOrd,
+        D: RemoveAxis,
+    {
+        self.scatter_with(axis, indices, source, |elt, value| mode.combine(elt, value))
+    }
+
+    /// Along `axis`, combine the subviews of `source` into the subviews at
+    /// `indices` using the closure `f`.
+    ///
+    /// `f` is called as `f(element, value)` for each element of
+    /// `source.index_axis(axis, k)` and the corresponding element of
+    /// `self.index_axis_mut(axis, indices[k])`, for each `k` in order.
+    ///
+    /// See [`.scatter()`](Self::scatter) for the common ways to combine
+    /// elements.
+    ///
+    /// **Panics** if `axis` or an element of `indices` is out of bounds, or if
+    /// the shape of `source` is not the shape of `self` with the length of
+    /// `axis` replaced by `indices.len()`.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let mut a = array![[1, 2], [3, 4]];
+    /// a.scatter_with(Axis(1), &[1, 1], &array![[10, 20], [30, 40]], |x, &y| *x *= y);
+    /// assert_eq!(a, array![[1, 400], [3, 4800]]);
+    /// ```
+    #[track_caller]
+    pub fn scatter_with<F>(&mut self, axis: Axis, indices: &[Ix], source: &ArrayRef<A, D>, mut f: F)
+    where
+        F: FnMut(&mut A, &A),
+        D: RemoveAxis,
+    {
+        let axis_len = self.len_of(axis);
+        if let Some(&max_index) = indices.iter().max() {
+            if max_index >= axis_len {
+                panic!("ndarray: index {} is out of bounds in array of len {}", max_index, axis_len);
+            }
+        }
+        let mut expected_dim = self.raw_dim();
+        expected_dim.set_axis(axis, indices.len());
+        if source.raw_dim() != expected_dim {
+            panic!(
+                "ndarray: source of shape {:?} does not match array of shape {:?} with {} indices along axis {}",
+                source.shape(),
+                self.shape(),
+                indices.len(),
+                axis.index()
+            );
+        }
+        for (&index, src) in indices.iter().zip(source.axis_iter(axis)) {
+            Zip::from(self.index_axis_mut(axis, index))
+                .and(&src)
+                .for_each(&mut f);
+        }
+    }
+
+    /// Write `values` along `axis` at the positions given by `indices`,
+    /// combining them with the existing elements according to `mode`.
+    ///
+    /// For each lane along `axis`, the value at position `k` of the lane in
+    /// `values` is written to position `indices[k]` of the lane in `self`
+    /// (using the indices in the corresponding lane of `indices`). In two
+    /// dimensions, with `axis` 1, element `[i, indices[[i, k]]]` is combined
+    /// with `values[[i, k]]`. The equivalent of NumPy’s `np.put_along_axis`.
+    ///
+    /// `indices` and `values` must have the same shape, which must be the
+    /// shape of `self` apart from along `axis`. Repeated indices accumulate,
+    /// see [`ScatterMode`].
+    ///
+    /// **Panics** if `axis` or an index is out of bounds, or if the shapes do
+    /// not match.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis, ScatterMode};
+    ///
+    /// let mut a = array![[0, 0, 0],
+    ///                    [0, 0, 0]];
+    /// let indices = array![[2, 0], [1, 1]];
+    /// a.put_along_axis(Axis(1), &indices, &array![[5, 6], [7, 8]], ScatterMode::Add);
+    /// assert_eq!(a, array![[6, 0, 5],
+    ///                      [0, 15, 0]]);
+    /// ```
+    #[track_caller]
+    pub fn put_along_axis(&mut self, axis: Axis, indices: &ArrayRef<Ix, D>, values: &ArrayRef<A, D>, mode: ScatterMode)
+    where
+        A: Clone + Add<Output = A> + PartialOrd,
+        D: RemoveAxis,
+    {
+        self.put_along_axis_with(axis, indices, values, |elt, value| mode.combine(elt, value))
+    }
+
+    /// Combine `values` along `axis` into the positions given by `indices`
+    /// using the closure `f`.
+    ///
+    /// `f` is called as `f(element, value)`; otherwise this is the same as
+    /// [`.put_along_axis()`](Self::put_along_axis).
+    ///
+    /// **Panics** if `axis` or an index is out of bounds, or if the shapes do
+    /// not match.
+    #[track_caller]
+    pub fn put_along_axis_with<F>(&mut self, axis: Axis, indices: &ArrayRef<Ix, D>, values: &ArrayRef<A, D>, mut f: F)
+    where
+        F: FnMut(&mut A, &A),
+        D: RemoveAxis,
+    {
+        let axis_len = self.len_of(axis);
+        if indices.shape() != values.shape() || indices.raw_dim().remove_axis(axis) != self.raw_dim().remove_axis(axis)
+        {
+            panic!(
+                "ndarray: indices of shape {:?} and values of shape {:?} do not match array of shape {:?} along axis {}",
+                indices.shape(),
+                values.shape(),
+                self.shape(),
+                axis.index()
+            );
+        }
+        if let Some(&max_index) = indices.iter().max() {
+            if max_index >= axis_len {
+                panic!("ndarray: index {} is out of bounds in array of len {}", max_index, axis_len);
+            }
+        }
+        Zip::from(self.lanes_mut(axis))
+            .and(indices.lanes(axis))
+            .and(values.lanes(axis))
+            .for_each(|mut lane, lane_indices, lane_values| {
+                for (&index, value) in lane_indices.iter().zip(&lane_values) {
+                    f(&mut lane[index], value);
+                }
+            });
+    }
+}
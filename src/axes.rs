@@ -0,0 +1,362 @@
+// Copyright 2014-2024 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Moving, inserting and removing several axes at once.
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use crate::imp_prelude::*;
+
+/// A list of axes to insert into or remove from an array of dimension `D`.
+///
+/// This is the argument of [`.expand_dims()`](ArrayBase::expand_dims) and
+/// [`.squeeze_axes()`](ArrayBase::squeeze_axes). An array of axes,
+/// `[Axis; N]` for `N` up to 6, fixes the number of axes at compile time, so
+/// that the result of a fixed-dimensional array has a fixed dimension too. A
+/// slice or vector of axes gives a dynamic-dimensional result.
+pub trait AxesArg<D: Dimension>: AsRef<[Axis]>
+{
+    /// Dimensionality of the array with the axes inserted.
+    type Expanded: Dimension;
+    /// Dimensionality of the array with the axes removed.
+    type Squeezed: Dimension;
+
+    private_decl! {}
+}
+
+impl<T, D> AxesArg<D> for &T
+where
+    T: AxesArg<D> + ?Sized,
+    D: Dimension,
+{
+    type Expanded = T::Expanded;
+    type Squeezed = T::Squeezed;
+
+    private_impl! {}
+}
+
+impl<D: Dimension> AxesArg<D> for [Axis]
+{
+    type Expanded = IxDyn;
+    type Squeezed = IxDyn;
+
+    private_impl! {}
+}
+
+impl<D: Dimension> AxesArg<D> for Vec<Axis>
+{
+    type Expanded = IxDyn;
+    type Squeezed = IxDyn;
+
+    private_impl! {}
+}
+
+macro_rules! impl_axesarg_array {
+    ($n:expr, $expanded:ty, $squeezed:ty) => {
+        impl<D: Dimension> AxesArg<D> for [Axis; $n]
+        {
+            type Expanded = $expanded;
+            type Squeezed = $squeezed;
+
+            private_impl! {}
+        }
+    };
+}
+
+impl_axesarg_array!(0, D, D);
+impl_axesarg_array!(1, D::Larger, D::Smaller);
+impl_axesarg_array!(2, <D::Larger as Dimension>::Larger, <D::Smaller as Dimension>::Smaller);
+impl_axesarg_array!(
+    3,
+    <<D::Larger as Dimension>::Larger as Dimension>::Larger,
+    <<D::Smaller as Dimension>::Smaller as Dimension>::Smaller
+);
+impl_axesarg_array!(
+    4,
+    <<<D::Larger as Dimension>::Larger as Dimension>::Larger as Dimension>::Larger,
+    <<<D::Smaller as Dimension>::Smaller as Dimension>::Smaller as Dimension>::Smaller
+);
+impl_axesarg_array!(
+    5,
+    <<<<D::Larger as Dimension>::Larger as Dimension>::Larger as Dimension>::Larger as Dimension>::Larger,
+    <<<<D::Smaller as Dimension>::Smaller as Dimension>::Smaller as Dimension>::Smaller as Dimension>::Smaller
+);
+impl_axesarg_array!(
+    6,
+    <<<<<D::Larger as Dimension>::Larger as Dimension>::Larger as Dimension>::Larger as Dimension>::Larger as Dimension>::Larger,
+    <<<<<D::Smaller as Dimension>::Smaller as Dimension>::Smaller as Dimension>::Smaller as Dimension>::Smaller as Dimension>::Smaller
+);
+
+/// The dimensionality of an array after
+/// [`.atleast_1d()`](ArrayBase::atleast_1d),
+/// [`.atleast_2d()`](ArrayBase::atleast_2d) and
+/// [`.atleast_3d()`](ArrayBase::atleast_3d).
+///
+/// This trait is implemented for all the dimension types of the crate.
+pub trait AtLeastDim: Dimension
+{
+    /// Dimensionality with at least one axis.
+    type AtLeast1: Dimension;
+    /// Dimensionality with at least two axes.
+    type AtLeast2: Dimension;
+    /// Dimensionality with at least three axes.
+    type AtLeast3: Dimension;
+
+    private_decl! {}
+}
+
+macro_rules! impl_atleastdim {
+    ($dim:ty, $d1:ty, $d2:ty, $d3:ty) => {
+        impl AtLeastDim for $dim
+        {
+            type AtLeast1 = $d1;
+            type AtLeast2 = $d2;
+            type AtLeast3 = $d3;
+
+            private_impl! {}
+        }
+    };
+}
+
+impl_atleastdim!(Ix0, Ix1, Ix2, Ix3);
+impl_atleastdim!(Ix1, Ix1, Ix2, Ix3);
+impl_atleastdim!(Ix2, Ix2, Ix2, Ix3);
+impl_atleastdim!(Ix3, Ix3, Ix3, Ix3);
+impl_atleastdim!(Ix4, Ix4, Ix4, Ix4);
+impl_atleastdim!(Ix5, Ix5, Ix5, Ix5);
+impl_atleastdim!(Ix6, Ix6, Ix6, Ix6);
+impl_atleastdim!(IxDyn, IxDyn, IxDyn, IxDyn);
+
+/// Check that each of `axes` is less than `ndim` and listed only once.
+#[track_caller]
+fn check_axes(axes: &[Axis], ndim: usize)
+{
+    for (i, &axis) in axes.iter().enumerate() {
+        assert!(
+            axis.index() < ndim,
+            "ndarray: axis {} is out of bounds for array of dimension {}",
+            axis.index(),
+            ndim
+        );
+        assert!(!axes[..i].contains(&axis), "ndarray: axis {} is repeated", axis.index());
+    }
+}
+
+/// # Moving, inserting and removing axes
+///
+/// These methods work on several axes at once, like their NumPy
+/// namesakes, where [`.permuted_axes()`](Self::permuted_axes),
+/// [`.insert_axis()`](Self::insert_axis) and
+/// [`.remove_axis()`](Self::remove_axis) work on one. None of them moves any
+/// data, they just adjust the array's dimensions and strides.
+impl<A, S, D> ArrayBase<S, D>
+where
+    S: RawData<Elem = A>,
+    D: Dimension,
+{
+    /// Move each of the `source` axes to the position of the matching
+    /// `destination` axis, keeping the other axes in their original order.
+    ///
+    /// ```
+    /// use ndarray::{Array, Axis};
+    ///
+    /// let a = Array::<f64, _>::zeros((2, 3, 4, 5));
+    /// assert_eq!(a.view().moveaxis(&[Axis(0)], &[Axis(3)]).shape(), &[3, 4, 5, 2]);
+    /// assert_eq!(a.view().moveaxis(&[Axis(3)], &[Axis(0)]).shape(), &[5, 2, 3, 4]);
+    /// assert_eq!(a.moveaxis(&[Axis(0), Axis(1)], &[Axis(3), Axis(2)]).shape(), &[4, 5, 3, 2]);
+    /// ```
+    ///
+    /// **Panics** if `source` and `destination` have different lengths, or if
+    /// an axis is out of bounds or repeated in either of them.
+    #[track_caller]
+    pub fn moveaxis(self, source: &[Axis], destination: &[Axis]) -> ArrayBase<S, D>
+    {
+        assert_eq!(
+            source.len(),
+            destination.len(),
+            "ndarray: expected as many destination axes as source axes, but got {} and {}",
+            destination.len(),
+            source.len()
+        );
+        let ndim = self.ndim();
+        check_axes(source, ndim);
+        check_axes(destination, ndim);
+        let mut rest = (0..ndim).filter(|&i| !source.contains(&Axis(i)));
+        let mut axes = D::zeros(ndim);
+        for (new_axis, axis) in axes.slice_mut().iter_mut().enumerate() {
+            *axis = match destination.iter().position(|&d| d.index() == new_axis) {
+                Some(i) => source[i].index(),
+                None => rest.next().unwrap(),
+            };
+        }
+        self.permuted_axes(axes)
+    }
+
+    /// Insert new axes of length 1 at each of `axes` and return the result.
+    ///
+    /// The axes are positions in the result, so the new axes end up at exactly
+    /// those indices and the existing axes fill the others in order.
+    ///
+    /// With an array of axes the result has a fixed dimension, one larger for
+    /// each axis; with a slice or vector of axes it is dynamic-dimensional. See
+    /// [`AxesArg`].
+    ///
+    /// ```
+    /// use ndarray::{array, Array2, Array4, Axis};
+    ///
+    /// let a = array![1, 2, 3];
+    /// let b: Array2<i32> = a.clone().expand_dims([Axis(1)]);
+    /// assert_eq!(b, array![[1], [2], [3]]);
+    ///
+    /// let c = Array2::<f64>::zeros((3, 4));
+    /// let d: Array4<f64> = c.view().expand_dims([Axis(0), Axis(3)]).to_owned();
+    /// assert_eq!(d.shape(), &[1, 3, 4, 1]);
+    ///
+    /// let axes = vec![Axis(0), Axis(2)];
+    /// assert_eq!(c.expand_dims(&axes).shape(), &[1, 3, 1, 4]);
+    /// ```
+    ///
+    /// **Panics** if an axis is out of bounds for the result or is repeated.
+    #[track_caller]
+    pub fn expand_dims<T>(self, axes: T) -> ArrayBase<S, T::Expanded>
+    where T: AxesArg<D>
+    {
+        self.insert_unit_axes(axes.as_ref())
+    }
+
+    /// Remove each of `axes`, which must have length 1, and return the result.
+    ///
+    /// With an array of axes the result has a fixed dimension, one smaller for
+    /// each axis; with a slice or vector of axes it is dynamic-dimensional. See
+    /// [`AxesArg`]. For dynamic-dimensional arrays,
+    /// [`.squeeze()`](ArrayBase::squeeze) removes all the axes of length 1.
+    ///
+    /// ```
+    /// use ndarray::{Array, Array2, Axis};
+    ///
+    /// let a = Array::<f64, _>::zeros((1, 3, 1, 4));
+    /// let b: Array2<f64> = a.view().squeeze_axes([Axis(0), Axis(2)]).to_owned();
+    /// assert_eq!(b.shape(), &[3, 4]);
+    /// assert_eq!(a.squeeze_axes(&[Axis(2)][..]).shape(), &[1, 3, 4]);
+    /// ```
+    ///
+    /// **Panics** if an axis is out of bounds, is repeated, or does not have
+    /// length 1.
+    #[track_caller]
+    pub fn squeeze_axes<T>(self, axes: T) -> ArrayBase<S, T::Squeezed>
+    where T: AxesArg<D>
+    {
+        let axes = axes.as_ref();
+        check_axes(axes, self.ndim());
+        for &axis in axes {
+            let len = self.len_of(axis);
+            assert_eq!(len, 1, "ndarray: cannot squeeze axis {} of length {}", axis.index(), len);
+        }
+        let ndim = self.ndim() - axes.len();
+        let mut dim = T::Squeezed::zeros(ndim);
+        let mut strides = T::Squeezed::zeros(ndim);
+        let kept = (0..self.ndim()).filter(|&i| !axes.contains(&Axis(i)));
+        for (new_axis, axis) in kept.enumerate() {
+            dim[new_axis] = self.parts.dim[axis];
+            strides[new_axis] = self.parts.strides[axis];
+        }
+        // safe because removing axes of length one does not affect memory layout
+        unsafe { self.with_strides_dim(strides, dim) }
+    }
+
+    /// Return the array with at least one axis: a zero-dimensional array
+    /// becomes a one-dimensional array of length 1, other arrays are
+    /// returned unchanged.
+    ///
+    /// ```
+    /// use ndarray::{arr0, array};
+    ///
+    /// assert_eq!(arr0(5).atleast_1d(), array![5]);
+    /// assert_eq!(array![[1, 2]].atleast_1d(), array![[1, 2]]);
+    /// ```
+    pub fn atleast_1d(self) -> ArrayBase<S, D::AtLeast1>
+    where D: AtLeastDim
+    {
+        let axes: &[Axis] = match self.ndim() {
+            0 => &[Axis(0)],
+            _ => &[],
+        };
+        self.insert_unit_axes(axes)
+    }
+
+    /// Return the array with at least two axes: an array with fewer axes gets
+    /// new leading axes of length 1, so a one-dimensional array of length `n`
+    /// becomes a row of shape `(1, n)`.
+    ///
+    /// ```
+    /// use ndarray::{arr0, array};
+    ///
+    /// assert_eq!(arr0(5).atleast_2d(), array![[5]]);
+    /// assert_eq!(array![1, 2, 3].atleast_2d(), array![[1, 2, 3]]);
+    /// ```
+    pub fn atleast_2d(self) -> ArrayBase<S, D::AtLeast2>
+    where D: AtLeastDim
+    {
+        let axes: &[Axis] = match self.ndim() {
+            0 => &[Axis(0), Axis(1)],
+            1 => &[Axis(0)],
+            _ => &[],
+        };
+        self.insert_unit_axes(axes)
+    }
+
+    /// Return the array with at least three axes, like NumPy's
+    /// `atleast_3d`: a one-dimensional array of length `n` becomes shape
+    /// `(1, n, 1)` and a two-dimensional array of shape `(m, n)` becomes
+    /// `(m, n, 1)`.
+    ///
+    /// ```
+    /// use ndarray::{arr0, array};
+    ///
+    /// assert_eq!(arr0(5).atleast_3d().shape(), &[1, 1, 1]);
+    /// assert_eq!(array![1, 2, 3].atleast_3d().shape(), &[1, 3, 1]);
+    /// assert_eq!(array![[1, 2, 3]].atleast_3d(), array![[[1], [2], [3]]]);
+    /// ```
+    pub fn atleast_3d(self) -> ArrayBase<S, D::AtLeast3>
+    where D: AtLeastDim
+    {
+        let axes: &[Axis] = match self.ndim() {
+            0 => &[Axis(0), Axis(1), Axis(2)],
+            1 => &[Axis(0), Axis(2)],
+            2 => &[Axis(2)],
+            _ => &[],
+        };
+        self.insert_unit_axes(axes)
+    }
+
+    /// Insert new axes of length 1 at `axes`, which are positions in the
+    /// result, whose dimensionality must be `E`.
+    #[track_caller]
+    fn insert_unit_axes<E>(self, axes: &[Axis]) -> ArrayBase<S, E>
+    where E: Dimension
+    {
+        let ndim = self.ndim() + axes.len();
+        check_axes(axes, ndim);
+        let mut dim = E::zeros(ndim);
+        let mut strides = E::zeros(ndim);
+        let mut old_axis = 0;
+        for new_axis in 0..ndim {
+            if axes.contains(&Axis(new_axis)) {
+                dim[new_axis] = 1;
+                strides[new_axis] = 1;
+            } else {
+                dim[new_axis] = self.parts.dim[old_axis];
+                strides[new_axis] = self.parts.strides[old_axis];
+                old_axis += 1;
+            }
+        }
+        // safe because new axes of length one do not affect memory layout
+        unsafe { self.with_strides_dim(strides, dim) }
+    }
+}
@@ -6,6 +6,7 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 use super::Dimension;
+use crate::dimension::size_of_shape_checked;
 #[cfg(feature = "std")]
 use std::error::Error;
 use std::fmt;
@@ -16,6 +17,49 @@ pub struct ShapeError
 {
     // we want to be able to change this representation later
     repr: ErrorKind,
+    detail: Option<Detail>,
+}
+
+/// The sizes involved in an error, for the error message.
+#[derive(Copy, Clone, Debug)]
+enum Detail
+{
+    /// An array of `len` elements does not fit a shape of `size` elements,
+    /// or `None` if the size of the shape overflows.
+    Size
+    {
+        len: usize, size: Option<usize>
+    },
+    /// The length of `axis` can't be inferred for an array of `len`
+    /// elements, because the other axes have `size` elements.
+    Infer
+    {
+        len: usize, size: usize, axis: usize
+    },
+}
+
+impl fmt::Display for Detail
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        match *self {
+            Detail::Size { len, size: Some(size) } => {
+                write!(f, "an array of {} elements can't have a shape of {} elements", len, size)
+            }
+            Detail::Size { len, size: None } => {
+                write!(f, "an array of {} elements can't have a shape with too many elements", len)
+            }
+            Detail::Infer { axis, size: 0, .. } => {
+                write!(f, "the length of axis {} can't be inferred when the other axes have no elements", axis)
+            }
+            Detail::Infer { len, size, axis } => write!(
+                f,
+                "the length of axis {} can't be inferred, because {} elements are not a multiple of {}, \
+                 the number of elements of the other axes",
+                axis, len, size
+            ),
+        }
+    }
 }
 
 impl ShapeError
@@ -59,7 +103,7 @@ pub enum ErrorKind
 #[inline(always)]
 pub fn from_kind(k: ErrorKind) -> ShapeError
 {
-    ShapeError { repr: k }
+    ShapeError { repr: k, detail: None }
 }
 
 impl PartialEq for ErrorKind
@@ -95,7 +139,11 @@ impl fmt::Display for ShapeError
             ErrorKind::Unsupported => "unsupported operation",
             ErrorKind::Overflow => "arithmetic overflow",
         };
-        write!(f, "ShapeError/{:?}: {}", self.kind(), description)
+        write!(f, "ShapeError/{:?}: {}", self.kind(), description)?;
+        if let Some(detail) = &self.detail {
+            write!(f, ": {}", detail)?;
+        }
+        Ok(())
     }
 }
 
@@ -107,10 +155,30 @@ impl fmt::Debug for ShapeError
     }
 }
 
-pub fn incompatible_shapes<D, E>(_a: &D, _b: &E) -> ShapeError
+pub fn incompatible_shapes<D, E>(a: &D, b: &E) -> ShapeError
 where
     D: Dimension,
     E: Dimension,
 {
-    from_kind(ErrorKind::IncompatibleShape)
+    let len = a.size();
+    let size = size_of_shape_checked(b).ok();
+    let detail = if size != Some(len) {
+        Some(Detail::Size { len, size })
+    } else {
+        None
+    };
+    ShapeError {
+        repr: ErrorKind::IncompatibleShape,
+        detail,
+    }
+}
+
+/// Return the error for a shape that infers the length of `axis` for an
+/// array of `len` elements, when the other axes have `size` elements.
+pub fn incompatible_inferred_shape(len: usize, size: usize, axis: usize) -> ShapeError
+{
+    ShapeError {
+        repr: ErrorKind::IncompatibleShape,
+        detail: Some(Detail::Infer { len, size, axis }),
+    }
 }
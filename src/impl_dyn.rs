@@ -112,6 +112,9 @@ where S: Data<Elem = A>
     /// If the array has more the one dimension, the result array will always
     /// have at least one dimension, even if it has a length of 1.
     ///
+    /// See also [`.squeeze_axes()`](ArrayBase::squeeze_axes), which removes
+    /// only the given axes and works for arrays of any dimension.
+    ///
     /// ```
     /// use ndarray::{arr1, arr2, arr3};
     ///
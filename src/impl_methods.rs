@@ -12,6 +12,7 @@ use alloc::vec;
 use alloc::vec::Vec;
 #[allow(unused_imports)]
 use rawpointer::PointerExt;
+use std::cmp::Ordering;
 use std::mem::{size_of, ManuallyDrop};
 
 use crate::imp_prelude::*;
@@ -1905,10 +1906,11 @@ impl<A, D: Dimension> ArrayRef<A, D>
     /// ((3, 4), Order::RowMajor))      // use specific order
     /// ((3, 4), Order::ColumnMajor))   // use specific order
     /// ((3, 4), Order::C))             // use shorthand for order - shorthands C and F
+    /// ShapeSpec::from((3, -1))        // infer the length of the last axis, see ShapeSpec
     /// ```
     ///
     /// **Errors** if the new shape doesn't have the same number of elements as the array's current
-    /// shape.
+    /// shape, or if the length of an inferred axis can't be found.
     ///
     /// # Example
     ///
@@ -1933,7 +1935,7 @@ impl<A, D: Dimension> ArrayRef<A, D>
         E: ShapeArg,
         A: Clone,
     {
-        let (shape, order) = new_shape.into_shape_and_order();
+        let (shape, order) = new_shape.into_shape_and_order_for_len(self.len())?;
         self.to_shape_order(shape, order.unwrap_or(Order::RowMajor))
     }
 
@@ -1991,7 +1993,8 @@ where
     /// If required use `.to_shape()` or `.into_shape_clone` instead for more flexible reshaping of
     /// arrays, which allows copying elements if required.
     ///
-    /// **Errors** if the shapes don't have the same number of elements.<br>
+    /// **Errors** if the shapes don't have the same number of elements, or if the length of an
+    /// inferred axis can't be found.<br>
     /// **Errors** if order RowMajor is given but input is not c-contiguous.
     /// **Errors** if order ColumnMajor is given but input is not f-contiguous.
     ///
@@ -2006,6 +2009,7 @@ where
     /// ((3, 4), Order::RowMajor))      // use specific order
     /// ((3, 4), Order::ColumnMajor))   // use specific order
     /// ((3, 4), Order::C))             // use shorthand for order - shorthands C and F
+    /// ShapeSpec::from((3, -1))        // infer the length of the last axis, see ShapeSpec
     /// ```
     ///
     /// # Example
@@ -2029,7 +2033,7 @@ where
     pub fn into_shape_with_order<E>(self, shape: E) -> Result<ArrayBase<S, E::Dim>, ShapeError>
     where E: ShapeArg
     {
-        let (shape, order) = shape.into_shape_and_order();
+        let (shape, order) = shape.into_shape_and_order_for_len(self.len())?;
         self.into_shape_with_order_impl(shape, order.unwrap_or(Order::RowMajor))
     }
 
@@ -2110,14 +2114,17 @@ where
     /// - `to_shape` borrows the original array, `into_shape_clone` consumes the original
     /// - `into_shape_clone` preserves array type (Array vs ArcArray), but does not support views.
     ///
-    /// **Errors** if the shapes don't have the same number of elements.<br>
+    /// The shape may also be a [`ShapeSpec`](crate::ShapeSpec) that infers the length of an axis.
+    ///
+    /// **Errors** if the shapes don't have the same number of elements, or if the length of an
+    /// inferred axis can't be found.<br>
     pub fn into_shape_clone<E>(self, shape: E) -> Result<ArrayBase<S, E::Dim>, ShapeError>
     where
         S: DataOwned,
         A: Clone,
         E: ShapeArg,
     {
-        let (shape, order) = shape.into_shape_and_order();
+        let (shape, order) = shape.into_shape_and_order_for_len(self.len())?;
         let order = order.unwrap_or(Order::RowMajor);
         self.into_shape_clone_order(shape, order)
     }
@@ -2429,6 +2436,35 @@ impl<A, D: Dimension> ArrayRef<A, D>
         unsafe { Some(ArrayView::new(*self._ptr(), dim, broadcast_strides)) }
     }
 
+    /// Return a new array with the elements of the array *broadcast* into
+    /// the shape `dim`: the owned counterpart of
+    /// [`.broadcast()`](Self::broadcast).
+    ///
+    /// The result is in standard layout, and each of its elements is cloned
+    /// once, in a single pass over it.
+    ///
+    /// Return `None` if shapes can not be broadcast together.
+    ///
+    /// ```
+    /// use ndarray::{array, Array2};
+    ///
+    /// let row = array![1., 0.];
+    /// let a: Array2<f64> = row.broadcast_owned((3, 2)).unwrap();
+    /// assert_eq!(a, array![[1., 0.], [1., 0.], [1., 0.]]);
+    /// assert!(a.is_standard_layout());
+    /// ```
+    pub fn broadcast_owned<E>(&self, dim: E) -> Option<Array<A, E::Dim>>
+    where
+        E: IntoDimension,
+        A: Clone,
+    {
+        let view = self.broadcast(dim)?;
+        let mut res = Array::uninit(view.raw_dim());
+        view.assign_to(&mut res);
+        // Safety: every element of `res` was assigned above
+        unsafe { Some(res.assume_init()) }
+    }
+
     /// For two arrays or views, find their common shape if possible and
     /// broadcast them as array views into that shape.
     ///
@@ -3140,6 +3176,78 @@ impl<A, D: Dimension> ArrayRef<A, D>
         res
     }
 
+    /// Fold along several axes at once.
+    ///
+    /// Combine the elements of each subview along `axes` with the previous
+    /// using the `fold` function and initial value `init`. The subviews are
+    /// visited in logical order over the reduced axes.
+    ///
+    /// If `keepdims` is `true`, the reduced axes are kept with length one, so
+    /// that the result broadcasts against `self`; otherwise they are removed.
+    ///
+    /// Return the result as an `ArrayD`.
+    ///
+    /// **Panics** if an axis is out of bounds or is repeated in `axes`.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![[[1, 2], [3, 4]],
+    ///                [[5, 6], [7, 8]]];
+    /// let max = a.fold_axes(&[Axis(0), Axis(2)], i32::MIN, false, |&m, &x| m.max(x));
+    /// assert_eq!(max, array![6, 8].into_dyn());
+    ///
+    /// let max = a.fold_axes(&[Axis(0), Axis(2)], i32::MIN, true, |&m, &x| m.max(x));
+    /// assert_eq!(max.shape(), &[1, 2, 1]);
+    /// ```
+    #[track_caller]
+    pub fn fold_axes<B, F>(&self, axes: &[Axis], init: B, keepdims: bool, mut fold: F) -> ArrayD<B>
+    where
+        F: FnMut(&B, &A) -> B,
+        B: Clone,
+    {
+        let mut res = Array::from_elem(self.reduced_dim(axes), init);
+        self.for_each_reduced_subview(axes, |subview| {
+            res.zip_mut_with(&subview, |x, y| *x = fold(x, y));
+        });
+        remove_reduced_axes(res, axes, keepdims)
+    }
+
+    /// Return the shape of `self` with the length of each of `axes` set to one.
+    ///
+    /// **Panics** if an axis is out of bounds or is repeated in `axes`.
+    #[track_caller]
+    pub(crate) fn reduced_dim(&self, axes: &[Axis]) -> D
+    {
+        let mut dim = self.raw_dim();
+        for (i, &axis) in axes.iter().enumerate() {
+            assert!(
+                axis.index() < self.ndim(),
+                "ndarray: axis {} is out of bounds for array of dimension {}",
+                axis.index(),
+                self.ndim()
+            );
+            assert!(!axes[..i].contains(&axis), "ndarray: axis {} is repeated", axis.index());
+            dim[axis.index()] = 1;
+        }
+        dim
+    }
+
+    /// Call `f` with each subview of `self` that has all of `axes` collapsed
+    /// to length one, in logical order over the collapsed axes.
+    pub(crate) fn for_each_reduced_subview<F>(&self, axes: &[Axis], mut f: F)
+    where F: FnMut(ArrayView<'_, A, D>)
+    {
+        let reduced_shape: Vec<Ix> = axes.iter().map(|&axis| self.len_of(axis)).collect();
+        for index in crate::indices(reduced_shape) {
+            let mut subview = self.view();
+            for (&axis, &i) in axes.iter().zip(index.slice()) {
+                subview.collapse_axis(axis, i);
+            }
+            f(subview);
+        }
+    }
+
     /// Reduce the values along an axis into just one value, producing a new
     /// array with one less dimension.
     ///
@@ -3302,6 +3410,33 @@ impl<A, D: Dimension> ArrayRef<A, D>
     where
         A: Clone + Ord + num_traits::Zero,
         D: Dimension,
+    {
+        self.partition_by(kth, axis, Ord::cmp)
+    }
+
+    /// Return a partitioned copy of the array, with the elements ordered by
+    /// the comparator function `compare`.
+    ///
+    /// This is like [`.partition()`](Self::partition), but it also works for
+    /// elements without a total order, like floating point numbers, as long as
+    /// `compare` is a total order of the elements in the array.
+    ///
+    /// **Panics** if `k` is out of bounds for a non-zero axis length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ndarray::prelude::*;
+    ///
+    /// let a = array![[2.5, -1., 0.5], [3., 1., 2.]];
+    /// let p = a.partition_by(0, Axis(1), f64::total_cmp);
+    /// assert_eq!(p.column(0), array![-1., 1.]);
+    /// ```
+    pub fn partition_by<F>(&self, kth: usize, axis: Axis, mut compare: F) -> Array<A, D>
+    where
+        A: Clone,
+        D: Dimension,
+        F: FnMut(&A, &A) -> Ordering,
     {
         let mut result = self.to_owned();
 
@@ -3316,39 +3451,44 @@ impl<A, D: Dimension> ArrayRef<A, D>
             panic!("Partition index {} is out of bounds for axis {} of length {}", kth, axis.0, axis_len);
         }
 
-        // Check if the first lane is contiguous
-        let is_contiguous = result
-            .lanes_mut(axis)
-            .into_iter()
-            .next()
-            // This unwrap shouldn't cause panics because the array isn't empty
-            .unwrap()
-            .is_contiguous();
-
-        if is_contiguous {
-            result.lanes_mut(axis).into_iter().for_each(|mut lane| {
-                lane.as_slice_mut().unwrap().select_nth_unstable(kth);
-            });
-        } else {
-            let mut temp_vec = vec![A::zero(); axis_len];
-
-            Zip::from(result.lanes_mut(axis)).for_each(|mut lane| {
-                Zip::from(&mut temp_vec).and(&lane).for_each(|dest, src| {
-                    *dest = src.clone();
-                });
+        // Partition contiguous lanes in place, and the others through a buffer
+        let mut temp_vec = Vec::new();
+        Zip::from(result.lanes_mut(axis)).for_each(|mut lane| match lane.as_slice_mut() {
+            Some(xs) => {
+                xs.select_nth_unstable_by(kth, &mut compare);
+            }
+            None => {
+                temp_vec.clear();
+                temp_vec.extend(lane.iter().cloned());
 
-                temp_vec.select_nth_unstable(kth);
+                temp_vec.select_nth_unstable_by(kth, &mut compare);
 
                 Zip::from(&mut lane).and(&temp_vec).for_each(|dest, src| {
                     *dest = src.clone();
                 });
-            });
-        }
+            }
+        });
 
         result
     }
 }
 
+/// Turn the result of a reduction over `axes`, where the reduced axes have
+/// length one, into an `ArrayD` with the reduced axes kept or removed.
+pub(crate) fn remove_reduced_axes<B, D>(res: Array<B, D>, axes: &[Axis], keepdims: bool) -> ArrayD<B>
+where D: Dimension
+{
+    let mut res = res.into_dyn();
+    if !keepdims {
+        let mut axes = axes.to_vec();
+        axes.sort_unstable_by(|a, b| b.cmp(a));
+        for axis in axes {
+            res = res.index_axis_move(axis, 0);
+        }
+    }
+    res
+}
+
 /// Transmute from A to B.
 ///
 /// Like transmute, but does not have the compile-time size check which blocks
@@ -3566,6 +3706,27 @@ mod tests
         }
     }
 
+    #[test]
+    fn test_partition_by()
+    {
+        let a = array![[2.5, -1., 0.5, 7.], [3., 1., f64::NAN, -2.]];
+        for view in [a.view(), a.slice(s![.., ..;-1]), a.t().reversed_axes()] {
+            let p = view.partition_by(1, Axis(1), |x, y| x.total_cmp(y));
+            for (row, p_row) in view.rows().into_iter().zip(p.rows()) {
+                let mut sorted = row.to_vec();
+                sorted.sort_by(|x, y| x.total_cmp(y));
+                assert_eq!(p_row[1].to_bits(), sorted[1].to_bits());
+                assert!(p_row[0].total_cmp(&p_row[1]).is_le());
+                assert!(p_row.slice(s![2..]).iter().all(|x| x.total_cmp(&p_row[1]).is_ge()));
+            }
+        }
+
+        // The lanes of a copy of a reversed array have negative strides
+        let b = arr1(&[3, 1, 2]).slice(s![..;-1]).to_owned();
+        assert_eq!(b.partition(0, Axis(0))[0], 1);
+        assert_eq!(b.partition_by(0, Axis(0), |x, y| y.cmp(x))[0], 3);
+    }
+
     #[test]
     fn test_partition_empty()
     {
@@ -595,7 +595,7 @@ mod assign_ops
     use crate::imp_prelude::*;
 
     macro_rules! impl_assign_op {
-        ($trt:ident, $method:ident, $doc:expr) => {
+        ($trt:ident, $method:ident, $doc:expr $(, $blas_scalar:path)?) => {
             use std::ops::$trt;
 
             #[doc=$doc]
@@ -653,6 +653,12 @@ mod assign_ops
                 D: Dimension,
             {
                 fn $method(&mut self, rhs: A) {
+                    $(
+                        #[cfg(feature = "blas")]
+                        if $blas_scalar(self, &rhs) {
+                            return;
+                        }
+                    )?
                     self.map_inplace(move |elt| {
                         elt.$method(rhs.clone());
                     });
@@ -674,7 +680,8 @@ mod assign_ops
     impl_assign_op!(
         MulAssign,
         mul_assign,
-        "Perform `self *= rhs` as elementwise multiplication (in place).\n"
+        "Perform `self *= rhs` as elementwise multiplication (in place).\n",
+        crate::linalg::blas_scal
     );
     impl_assign_op!(
         DivAssign,
@@ -138,7 +138,7 @@ where D: Dimension
 #[derive(Copy, Clone, Debug)]
 pub struct IndexPtr<D>
 {
-    index: D,
+    pub(crate) index: D,
 }
 
 impl<D> Offset for IndexPtr<D>
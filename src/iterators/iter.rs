@@ -29,3 +29,4 @@ pub use crate::iterators::{
     LanesMut,
     Windows,
 };
+pub use crate::pad::{PaddedView, PaddedWindows};
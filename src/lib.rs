@@ -160,19 +160,26 @@ pub use crate::indexes::{indices, indices_of};
 pub use crate::order::Order;
 pub use crate::slice::{MultiSliceArg, NewAxis, Slice, SliceArg, SliceInfo, SliceInfoElem, SliceNextDim};
 
+pub use crate::advanced_indexing::{GatherElem, ScatterMode};
+pub use crate::axes::{AtLeastDim, AxesArg};
+pub use crate::pad::PadMode;
+pub use crate::tile::Repeats;
+
 use crate::iterators::Baseiter;
 use crate::iterators::{ElementsBase, ElementsBaseMut};
 
 pub use crate::arraytraits::AsArray;
 pub use crate::linalg_traits::LinalgScalar;
 #[cfg(feature = "std")]
-pub use crate::linalg_traits::NdFloat;
+pub use crate::linalg_traits::{LinalgField, NdFloat};
+pub use crate::numeric::{Compensated, Naive, Pairwise, Summation};
+pub use crate::numeric::{MinMaxError, NanPolicy, QuantileMethod, ScanMode};
 
 pub use crate::stacking::{concatenate, stack};
 
 pub use crate::impl_views::IndexLonger;
 pub use crate::math_cell::MathCell;
-pub use crate::shape_builder::{Shape, ShapeArg, ShapeBuilder, StrideShape};
+pub use crate::shape_builder::{Shape, ShapeArg, ShapeBuilder, ShapeSpec, StrideShape};
 
 #[macro_use]
 mod macro_utils;
@@ -1793,6 +1800,27 @@ mod alias_asref;
 mod impl_owned_array;
 mod impl_special_element_types;
 
+// Advanced indexing methods
+mod advanced_indexing;
+
+// Sorting methods
+mod sort;
+
+// Unique elements
+mod unique;
+
+// Flipping, rotating and rolling
+mod rearrange;
+
+// Padding
+mod pad;
+
+// Tiling and repeating
+mod tile;
+
+// Moving, inserting and removing several axes
+mod axes;
+
 /// Private Methods
 impl<A, D: Dimension> ArrayRef<A, D>
 {
@@ -0,0 +1,574 @@
+// Copyright 2014-2024 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Einstein summation (`einsum`) over any number of arrays.
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use std::error::Error;
+use std::fmt;
+
+use crate::imp_prelude::*;
+use crate::linalg::general_batched_mat_mul;
+use crate::{CowArray, LinalgScalar};
+
+/// An error from parsing or evaluating an [`einsum`] expression.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum EinsumError
+{
+    /// The subscripts contain a character that is not an ASCII letter, a
+    /// comma, `->` or whitespace. (Ellipses `...` are not supported.)
+    InvalidCharacter(char),
+    /// The subscripts contain more than one `->`.
+    MultipleArrows,
+    /// The number of operands is not the number of comma-separated input
+    /// subscripts.
+    OperandCount
+    {
+        /// The number of input subscripts.
+        expected: usize,
+        /// The number of operands.
+        found: usize,
+    },
+    /// The number of labels for an operand is not its number of axes.
+    OperandDimension
+    {
+        /// The index of the operand.
+        operand: usize,
+        /// The number of labels.
+        expected: usize,
+        /// The number of axes of the operand.
+        found: usize,
+    },
+    /// Axes with the same label have different lengths.
+    LengthMismatch
+    {
+        /// The label of the axes.
+        label: char,
+        /// The length of the first axis with the label.
+        first: usize,
+        /// The length of the other axis with the label.
+        second: usize,
+    },
+    /// A label appears more than once in the output subscripts.
+    RepeatedOutputLabel(char),
+    /// A label in the output subscripts does not appear in any input.
+    UnknownOutputLabel(char),
+    /// The number of output labels is not the dimensionality requested with
+    /// [`Einsum::compute_into`].
+    OutputDimension
+    {
+        /// The dimensionality requested.
+        expected: usize,
+        /// The number of output labels.
+        found: usize,
+    },
+}
+
+impl fmt::Display for EinsumError
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        write!(f, "EinsumError: ")?;
+        match *self {
+            EinsumError::InvalidCharacter(c) => write!(f, "invalid character {:?} in subscripts", c),
+            EinsumError::MultipleArrows => write!(f, "more than one `->` in subscripts"),
+            EinsumError::OperandCount { expected, found } => {
+                write!(f, "subscripts for {} operands, but {} operands given", expected, found)
+            }
+            EinsumError::OperandDimension {
+                operand,
+                expected,
+                found,
+            } => write!(f, "{} labels for operand {} with {} axes", expected, operand, found),
+            EinsumError::LengthMismatch { label, first, second } => {
+                write!(f, "label {:?} for axes of length {} and {}", label, first, second)
+            }
+            EinsumError::RepeatedOutputLabel(c) => write!(f, "label {:?} repeated in output", c),
+            EinsumError::UnknownOutputLabel(c) => write!(f, "output label {:?} not in any input", c),
+            EinsumError::OutputDimension { expected, found } => {
+                write!(f, "{} output labels for a result with {} axes", found, expected)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for EinsumError {}
+
+/// Parsed einsum subscripts: the labels of the axes of each operand and of
+/// the output.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Subscripts
+{
+    inputs: Vec<Vec<char>>,
+    output: Vec<char>,
+}
+
+impl Subscripts
+{
+    fn parse(subscripts: &str) -> Result<Self, EinsumError>
+    {
+        let mut parts = subscripts.split("->");
+        let inputs = parts.next().unwrap_or("");
+        let output = parts.next();
+        if parts.next().is_some() {
+            return Err(EinsumError::MultipleArrows);
+        }
+        let labels = |s: &str| {
+            s.chars()
+                .filter(|c| !c.is_whitespace())
+                .map(|c| {
+                    if c.is_ascii_alphabetic() {
+                        Ok(c)
+                    } else {
+                        Err(EinsumError::InvalidCharacter(c))
+                    }
+                })
+                .collect::<Result<Vec<_>, _>>()
+        };
+        let inputs = inputs
+            .split(',')
+            .map(labels)
+            .collect::<Result<Vec<_>, _>>()?;
+        let output = match output {
+            Some(output) => {
+                let output = labels(output)?;
+                for (i, &c) in output.iter().enumerate() {
+                    if output[..i].contains(&c) {
+                        return Err(EinsumError::RepeatedOutputLabel(c));
+                    }
+                    if !inputs.iter().any(|input| input.contains(&c)) {
+                        return Err(EinsumError::UnknownOutputLabel(c));
+                    }
+                }
+                output
+            }
+            None => {
+                // implicit output: the labels that appear only once, sorted
+                let mut output = inputs
+                    .iter()
+                    .flatten()
+                    .copied()
+                    .filter(|c| inputs.iter().flatten().filter(|&d| d == c).count() == 1)
+                    .collect::<Vec<_>>();
+                output.sort_unstable();
+                output
+            }
+        };
+        Ok(Subscripts { inputs, output })
+    }
+}
+
+/// Evaluate the Einstein summation `subscripts` over `operands`.
+///
+/// The subscripts name the axes of each operand with a letter, the operands
+/// separated by commas, and optionally the axes of the output after `->`, like
+/// `"ij,jk->ik"` for matrix multiplication. Axes with the same label must have
+/// the same length. The result is the sum of the products of the operand
+/// elements, over all labels that are not in the output:
+///
+/// - A label repeated within an operand takes its diagonal, like `"ii->i"`.
+/// - A label that is in the inputs but not in the output is summed over, like
+///   `"ii->"` for the trace.
+/// - Without `->`, the output has the labels that appear exactly once in the
+///   inputs, in alphabetical order, like NumPy's `einsum`.
+///
+/// Operands are contracted two at a time, picking the pair with the smallest
+/// intermediate result first, and each pairwise contraction is computed as a
+/// batched matrix multiplication (see
+/// [`general_batched_mat_mul`](crate::linalg::general_batched_mat_mul)).
+///
+/// The operands must all have the same dimensionality type; use
+/// [`.into_dyn()`](ArrayBase::into_dyn) or the builder [`Einsum`] to combine
+/// arrays of different dimensionalities.
+///
+/// ***Errors*** if the subscripts can not be parsed or do not match the
+/// operands, see [`EinsumError`].
+///
+/// ```
+/// use ndarray::array;
+/// use ndarray::linalg::einsum;
+///
+/// let a = array![[1., 2.], [3., 4.]];
+/// let b = array![[0., 1.], [1., 0.]];
+/// assert_eq!(einsum("ij,jk->ik", &[a.view(), b.view()]).unwrap(), a.dot(&b).into_dyn());
+/// assert_eq!(einsum("ij,ij->", &[a.view(), b.view()]).unwrap()[[]], 5.);
+/// assert_eq!(einsum("ii", &[a.view()]).unwrap()[[]], 5.);
+/// assert_eq!(einsum("ji", &[a.view()]).unwrap(), a.t().into_dyn());
+/// ```
+pub fn einsum<A, D>(subscripts: &str, operands: &[ArrayView<'_, A, D>]) -> Result<ArrayD<A>, EinsumError>
+where
+    A: LinalgScalar,
+    D: Dimension,
+{
+    let subscripts = Subscripts::parse(subscripts)?;
+    let operands = operands.iter().map(|a| a.view().into_dyn()).collect();
+    contract(&subscripts, operands)
+}
+
+/// A builder for an Einstein summation, see [`einsum`].
+///
+/// The subscripts are parsed once in [`Einsum::new`], and operands of any
+/// dimensionality are added with [`.operand()`](Einsum::operand).
+/// [`.compute_into()`](Einsum::compute_into) returns the result with a fixed
+/// dimensionality.
+///
+/// ```
+/// use ndarray::{array, Array1, Array2};
+/// use ndarray::linalg::Einsum;
+///
+/// let m = array![[1., 2.], [3., 4.]];
+/// let x = array![1., -1.];
+/// let y: Array1<f64> = Einsum::new("ij,j->i").unwrap()
+///     .operand(&m)
+///     .operand(&x)
+///     .compute_into()
+///     .unwrap();
+/// assert_eq!(y, array![-1., -1.]);
+///
+/// // outer product
+/// let outer: Array2<f64> = Einsum::new("i,j").unwrap()
+///     .operand(&x)
+///     .operand(&x)
+///     .compute_into()
+///     .unwrap();
+/// assert_eq!(outer, array![[1., -1.], [-1., 1.]]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Einsum<'a, A>
+{
+    subscripts: Subscripts,
+    operands: Vec<ArrayViewD<'a, A>>,
+}
+
+impl<'a, A> Einsum<'a, A>
+{
+    /// Parse `subscripts` for an Einstein summation, see [`einsum`].
+    ///
+    /// ***Errors*** if the subscripts can not be parsed.
+    pub fn new(subscripts: &str) -> Result<Self, EinsumError>
+    {
+        Ok(Einsum {
+            subscripts: Subscripts::parse(subscripts)?,
+            operands: Vec::new(),
+        })
+    }
+
+    /// Add the next operand.
+    pub fn operand<D>(mut self, operand: &'a ArrayRef<A, D>) -> Self
+    where D: Dimension
+    {
+        self.operands.push(operand.view().into_dyn());
+        self
+    }
+
+    /// Evaluate the Einstein summation.
+    ///
+    /// ***Errors*** if the subscripts do not match the operands, see
+    /// [`EinsumError`].
+    pub fn compute(&self) -> Result<ArrayD<A>, EinsumError>
+    where A: LinalgScalar
+    {
+        contract(&self.subscripts, self.operands.clone())
+    }
+
+    /// Evaluate the Einstein summation, with a result of dimensionality `D`.
+    ///
+    /// ***Errors*** if the subscripts do not match the operands, or if the
+    /// number of output labels does not match `D`.
+    pub fn compute_into<D>(&self) -> Result<Array<A, D>, EinsumError>
+    where
+        A: LinalgScalar,
+        D: Dimension,
+    {
+        let found = self.subscripts.output.len();
+        match D::NDIM {
+            Some(expected) if expected != found => Err(EinsumError::OutputDimension { expected, found }),
+            _ => Ok(self.compute()?.into_dimensionality().unwrap()),
+        }
+    }
+}
+
+/// An intermediate operand: an array and the labels of its axes.
+struct Labeled<'a, A>
+{
+    labels: Vec<char>,
+    array: CowArray<'a, A, IxDyn>,
+}
+
+fn contract<A>(subscripts: &Subscripts, operands: Vec<ArrayViewD<'_, A>>) -> Result<ArrayD<A>, EinsumError>
+where A: LinalgScalar
+{
+    if subscripts.inputs.len() != operands.len() {
+        return Err(EinsumError::OperandCount {
+            expected: subscripts.inputs.len(),
+            found: operands.len(),
+        });
+    }
+    let mut lengths: Vec<(char, usize)> = Vec::new();
+    for (i, (labels, operand)) in subscripts.inputs.iter().zip(&operands).enumerate() {
+        if labels.len() != operand.ndim() {
+            return Err(EinsumError::OperandDimension {
+                operand: i,
+                expected: labels.len(),
+                found: operand.ndim(),
+            });
+        }
+        for (&label, &len) in labels.iter().zip(operand.shape()) {
+            match lengths.iter().find(|&&(l, _)| l == label) {
+                Some(&(_, first)) if first != len =>
+                    return Err(EinsumError::LengthMismatch {
+                        label,
+                        first,
+                        second: len,
+                    }),
+                Some(_) => {}
+                None => lengths.push((label, len)),
+            }
+        }
+    }
+    let length = |label: char| lengths.iter().find(|&&(l, _)| l == label).unwrap().1;
+    let size = |labels: &[char]| {
+        labels
+            .iter()
+            .fold(1usize, |acc, &l| acc.saturating_mul(length(l)))
+    };
+
+    let mut operands = subscripts
+        .inputs
+        .iter()
+        .zip(operands)
+        .map(|(labels, operand)| diagonal(operand, labels))
+        .collect::<Vec<_>>();
+
+    // Contract the pair of operands with the smallest result (and then the
+    // fewest multiplications) until one is left.
+    while operands.len() > 1 {
+        let mut best = None;
+        for i in 0..operands.len() {
+            for j in i + 1..operands.len() {
+                let kept = kept_labels(&operands, i, j, &subscripts.output);
+                let mut all = operands[i].labels.clone();
+                all.extend(
+                    operands[j]
+                        .labels
+                        .iter()
+                        .filter(|l| !operands[i].labels.contains(l)),
+                );
+                let key = (size(&kept), size(&all));
+                if best
+                    .as_ref()
+                    .map_or(true, |&(best_key, _, _)| key < best_key)
+                {
+                    best = Some((key, i, j));
+                }
+            }
+        }
+        let (_, i, j) = best.unwrap();
+        let kept = kept_labels(&operands, i, j, &subscripts.output);
+        let y = operands.swap_remove(j);
+        let x = operands.swap_remove(i);
+        operands.push(contract_pair(x, y, &kept, length));
+    }
+
+    let result = sum_unused(operands.pop().unwrap(), &subscripts.output);
+    let perm = subscripts
+        .output
+        .iter()
+        .map(|l| result.labels.iter().position(|m| m == l).unwrap())
+        .collect::<Vec<_>>();
+    let result = result.array.permuted_axes(perm);
+    if result.is_standard_layout() {
+        Ok(result.into_owned())
+    } else {
+        Ok(result.as_standard_layout().into_owned())
+    }
+}
+
+/// Take the diagonal of the axes of `operand` with the same label.
+fn diagonal<'a, A>(operand: ArrayViewD<'a, A>, labels: &[char]) -> Labeled<'a, A>
+{
+    let mut unique = Vec::new();
+    let mut dim = Vec::new();
+    let mut strides = Vec::new();
+    for (axis, &label) in labels.iter().enumerate() {
+        let stride = operand.strides()[axis];
+        match unique.iter().position(|&l| l == label) {
+            Some(i) => strides[i] += stride,
+            None => {
+                unique.push(label);
+                dim.push(operand.len_of(Axis(axis)));
+                strides.push(stride);
+            }
+        }
+    }
+    let array = if unique.len() == labels.len() {
+        operand
+    } else {
+        let strides = strides.iter().map(|&s| s as usize).collect::<Vec<_>>();
+        // Safety: the diagonal elements are elements of `operand`, since axes
+        // with the same label have the same length.
+        unsafe { ArrayView::new_(operand.as_ptr(), IxDyn(&dim), IxDyn(&strides)) }
+    };
+    Labeled {
+        labels: unique,
+        array: array.into(),
+    }
+}
+
+/// The labels of operands `i` and `j` that are needed after contracting them:
+/// those in the output or in any other operand.
+fn kept_labels<A>(operands: &[Labeled<'_, A>], i: usize, j: usize, output: &[char]) -> Vec<char>
+{
+    let mut kept = Vec::new();
+    for &label in operands[i].labels.iter().chain(&operands[j].labels) {
+        let needed = output.contains(&label)
+            || operands
+                .iter()
+                .enumerate()
+                .any(|(k, op)| k != i && k != j && op.labels.contains(&label));
+        if needed && !kept.contains(&label) {
+            kept.push(label);
+        }
+    }
+    kept
+}
+
+/// Sum over the axes whose labels are not in `kept`.
+fn sum_unused<'a, A>(operand: Labeled<'a, A>, kept: &[char]) -> Labeled<'a, A>
+where A: LinalgScalar
+{
+    let axes = (0..operand.labels.len())
+        .filter(|&i| !kept.contains(&operand.labels[i]))
+        .map(Axis)
+        .collect::<Vec<_>>();
+    if axes.is_empty() {
+        return operand;
+    }
+    Labeled {
+        labels: operand
+            .labels
+            .into_iter()
+            .filter(|l| kept.contains(l))
+            .collect(),
+        array: operand.array.sum_axes(&axes, false).into(),
+    }
+}
+
+/// Contract `x` and `y` to an operand with the labels `kept` (in some order),
+/// as a batched matrix multiplication.
+fn contract_pair<'a, A, F>(x: Labeled<'_, A>, y: Labeled<'_, A>, kept: &[char], length: F) -> Labeled<'a, A>
+where
+    A: LinalgScalar,
+    F: Fn(char) -> usize,
+{
+    // labels of only one operand are summed first
+    let x_kept = x
+        .labels
+        .iter()
+        .copied()
+        .filter(|l| kept.contains(l) || y.labels.contains(l))
+        .collect::<Vec<_>>();
+    let y_kept = y
+        .labels
+        .iter()
+        .copied()
+        .filter(|l| kept.contains(l) || x.labels.contains(l))
+        .collect::<Vec<_>>();
+    let x = sum_unused(x, &x_kept);
+    let y = sum_unused(y, &y_kept);
+
+    let shared = |l: &&char| y.labels.contains(l);
+    let batch = x
+        .labels
+        .iter()
+        .filter(shared)
+        .filter(|l| kept.contains(l))
+        .copied()
+        .collect::<Vec<_>>();
+    let summed = x
+        .labels
+        .iter()
+        .filter(shared)
+        .filter(|l| !kept.contains(l))
+        .copied()
+        .collect::<Vec<_>>();
+    let x_free = x
+        .labels
+        .iter()
+        .filter(|l| !y.labels.contains(l))
+        .copied()
+        .collect::<Vec<_>>();
+    let y_free = y
+        .labels
+        .iter()
+        .filter(|l| !x.labels.contains(l))
+        .copied()
+        .collect::<Vec<_>>();
+
+    let product = |labels: &[char]| labels.iter().map(|&l| length(l)).product::<usize>();
+    let (b, m, k, n) = (product(&batch), product(&x_free), product(&summed), product(&y_free));
+    let x3 = batched_matrices(&x, &[&batch, &x_free, &summed], (b, m, k));
+    let y3 = batched_matrices(&y, &[&batch, &summed, &y_free], (b, k, n));
+    let mut z = Array3::zeros((b, m, n));
+    general_batched_mat_mul(A::one(), &x3, &y3, A::zero(), &mut z);
+
+    let labels = [batch, x_free, y_free].concat();
+    let dim = labels.iter().map(|&l| length(l)).collect::<Vec<_>>();
+    Labeled {
+        array: z.into_shape_with_order(dim).unwrap().into(),
+        labels,
+    }
+}
+
+/// Arrange the axes of `operand` in the order of `groups` and merge each group
+/// into one axis of a three-dimensional array with `shape`.
+fn batched_matrices<'b, A>(
+    operand: &'b Labeled<'_, A>, groups: &[&[char]], shape: (usize, usize, usize),
+) -> CowArray<'b, A, Ix3>
+where A: Clone
+{
+    let perm = groups
+        .iter()
+        .flat_map(|group| group.iter())
+        .map(|l| operand.labels.iter().position(|m| m == l).unwrap())
+        .collect::<Vec<_>>();
+    let view = operand.array.view().permuted_axes(perm);
+    if view.is_standard_layout() {
+        view.into_shape_with_order(shape).unwrap().into()
+    } else {
+        view.as_standard_layout()
+            .into_owned()
+            .into_shape_with_order(shape)
+            .unwrap()
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+
+    #[test]
+    fn parse_subscripts()
+    {
+        let s = Subscripts::parse("ij, jk -> ik").unwrap();
+        assert_eq!(s.inputs, [['i', 'j'], ['j', 'k']]);
+        assert_eq!(s.output, ['i', 'k']);
+        assert_eq!(Subscripts::parse("ba,cb").unwrap().output, ['a', 'c']);
+        assert_eq!(Subscripts::parse("ii").unwrap().output, []);
+        assert_eq!(Subscripts::parse("i,j->").unwrap().output, []);
+        assert_eq!(Subscripts::parse("i...->i"), Err(EinsumError::InvalidCharacter('.')));
+        assert_eq!(Subscripts::parse("i->i->i"), Err(EinsumError::MultipleArrows));
+        assert_eq!(Subscripts::parse("ij->ii"), Err(EinsumError::RepeatedOutputLabel('i')));
+        assert_eq!(Subscripts::parse("ij->k"), Err(EinsumError::UnknownOutputLabel('k')));
+    }
+}
@@ -0,0 +1,519 @@
+// Copyright 2014-2024 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Dense matrix factorizations: LU, Cholesky and QR.
+//!
+//! The factorizations are blocked: they factor panels of `BLOCK` columns
+//! directly and update the rest of the matrix with [`general_mat_mul`], which
+//! does most of the work for large matrices.
+
+use num_traits::{Float, One, Zero};
+use std::error::Error;
+use std::fmt;
+
+use crate::imp_prelude::*;
+use crate::linalg::impl_linalg::{hermitian_rank_k_update, rank1_update, triangular_mat_vec_mul};
+use crate::linalg::solve::{as_columns, like_rhs, substitute, Diagonal, Triangle};
+use crate::linalg::{general_mat_mul, general_mat_mul_op, Transpose};
+use crate::{LinalgField, Zip};
+
+/// Number of columns of the panels that are factored directly.
+const BLOCK: usize = 64;
+
+/// An error from a matrix factorization, or from a function that uses one.
+#[non_exhaustive]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum LinalgError
+{
+    /// The matrix is not square.
+    NotSquare
+    {
+        /// Number of rows of the matrix.
+        rows: usize,
+        /// Number of columns of the matrix.
+        cols: usize,
+    },
+    /// The matrix is singular: in its LU factorization, the pivot for column
+    /// `index` is zero.
+    Singular
+    {
+        /// Index of the first zero pivot.
+        index: usize,
+    },
+    /// The matrix is not positive definite: its leading principal submatrix
+    /// of size `index + 1` is not.
+    NotPositiveDefinite
+    {
+        /// Index of the first diagonal element where the factorization failed.
+        index: usize,
+    },
+    /// An iterative algorithm did not converge; this happens when the matrix
+    /// has elements that are not finite.
+    NotConverged,
+}
+
+impl fmt::Display for LinalgError
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        write!(f, "LinalgError: ")?;
+        match *self {
+            LinalgError::NotSquare { rows, cols } => write!(f, "matrix of shape ({}, {}) is not square", rows, cols),
+            LinalgError::Singular { index } => write!(f, "matrix is singular, zero pivot in column {}", index),
+            LinalgError::NotPositiveDefinite { index } => {
+                write!(f, "matrix is not positive definite, failed at diagonal element {}", index)
+            }
+            LinalgError::NotConverged => write!(f, "iteration did not converge"),
+        }
+    }
+}
+
+impl Error for LinalgError {}
+
+/// LU factorization with partial pivoting, `P A = L U`; see [`lu`].
+#[derive(Clone, Debug)]
+pub struct Lu<A>
+{
+    /// `L` below the diagonal (its diagonal elements are one), `U` on and
+    /// above it.
+    pub(crate) lu: Array2<A>,
+    /// Row `i` of `P A` is row `perm[i]` of `A`.
+    pub(crate) perm: Vec<usize>,
+    /// Whether `P` is an odd permutation.
+    pub(crate) odd: bool,
+}
+
+impl<A> Lu<A>
+where A: LinalgField
+{
+    /// Return the unit lower triangular factor `L`.
+    pub fn l(&self) -> Array2<A>
+    {
+        let mut l = self.lu.tril(-1);
+        l.diag_mut().fill(A::one());
+        l
+    }
+
+    /// Return the upper triangular factor `U`.
+    pub fn u(&self) -> Array2<A>
+    {
+        self.lu.triu(0)
+    }
+
+    /// Return the row permutation: row `i` of `L U` is row
+    /// `permutation()[i]` of `A`.
+    pub fn permutation(&self) -> &[usize]
+    {
+        &self.perm
+    }
+
+    /// Return the permutation matrix `P`.
+    pub fn p(&self) -> Array2<A>
+    {
+        let n = self.perm.len();
+        let mut p = Array2::zeros((n, n));
+        for (i, &j) in self.perm.iter().enumerate() {
+            p[[i, j]] = A::one();
+        }
+        p
+    }
+
+    /// Solve the linear system `A x = b` for `x`, where `b` is a vector or a
+    /// matrix with one column per right-hand side.
+    ///
+    /// **Panics** if `b` is not a vector or a matrix, or if its number of
+    /// rows is not the size of `A`.
+    #[track_caller]
+    pub fn solve<D>(&self, b: &ArrayRef<A, D>) -> Array<A, D>
+    where D: Dimension
+    {
+        let b2 = as_columns(b);
+        let n = self.perm.len();
+        assert_eq!(b2.nrows(), n, "ndarray: right-hand side has {} rows, expected {}", b2.nrows(), n);
+        // x = U⁻¹ L⁻¹ P b
+        let mut x = b2.select(Axis(0), &self.perm);
+        substitute(&self.lu, &mut x, Triangle::Lower, Diagonal::Unit);
+        substitute(&self.lu, &mut x, Triangle::Upper, Diagonal::NonUnit);
+        like_rhs(x, b.ndim())
+    }
+
+    /// Return the determinant of `A`.
+    pub fn det(&self) -> A
+    {
+        let sign = if self.odd { -A::one() } else { A::one() };
+        self.lu.diag().fold(sign, |det, &d| det * d)
+    }
+}
+
+/// Cholesky factorization `A = L Lᴴ`; see [`cholesky`].
+#[derive(Clone, Debug)]
+pub struct Cholesky<A>
+{
+    pub(crate) l: Array2<A>,
+}
+
+impl<A> Cholesky<A>
+{
+    /// Return the lower triangular factor `L`.
+    pub fn l(&self) -> ArrayView2<'_, A>
+    {
+        self.l.view()
+    }
+
+    /// Return the lower triangular factor `L`, consuming the factorization.
+    pub fn into_l(self) -> Array2<A>
+    {
+        self.l
+    }
+}
+
+/// QR factorization `A = Q R` by Householder reflections; see [`qr`].
+#[derive(Clone, Debug)]
+pub struct Qr<A>
+{
+    /// `R` on and above the diagonal, the Householder vectors below it.
+    pub(crate) qr: Array2<A>,
+    /// The scalar factors of the Householder reflections.
+    pub(crate) tau: Vec<A>,
+}
+
+impl<A> Qr<A>
+where A: LinalgField
+{
+    /// Return the factor `Q` with orthonormal columns.
+    ///
+    /// For an *m* × *n* matrix, this is the thin *m* × min(*m*, *n*) factor.
+    pub fn q(&self) -> Array2<A>
+    {
+        let (m, n) = self.qr.dim();
+        self.q_columns(m.min(n))
+    }
+
+    /// Return the first `cols` columns of the full, square factor `Q`, where
+    /// `cols` is at least min(*m*, *n*) and at most *m*.
+    pub(crate) fn q_columns(&self, cols: usize) -> Array2<A>
+    {
+        let (m, n) = self.qr.dim();
+        let k = m.min(n);
+        debug_assert!(k <= cols && cols <= m);
+        let mut q = Array2::zeros((m, cols));
+        q.diag_mut().fill(A::one());
+        // Q = H₀ H₁ ⋯ Hₖ₋₁ applied to the first columns of the identity, one
+        // block of reflections at a time, starting with the last
+        for j in (0..k).step_by(BLOCK).rev() {
+            let jb = BLOCK.min(k - j);
+            let v = reflectors(&self.qr.slice(s![j.., j..j + jb]));
+            let t = triangular_factor(&v, &self.tau[j..j + jb]);
+            apply_block_reflector(&v, &t, &mut q.slice_mut(s![j.., j..]), false);
+        }
+        q
+    }
+
+    /// Return the upper triangular (or trapezoidal) factor `R`.
+    ///
+    /// For an *m* × *n* matrix, this is the min(*m*, *n*) × *n* factor.
+    pub fn r(&self) -> Array2<A>
+    {
+        let (m, n) = self.qr.dim();
+        self.qr.slice(s![..m.min(n), ..]).triu(0)
+    }
+}
+
+/// Compute the LU factorization of the square matrix `a` with partial
+/// (row) pivoting, `P A = L U`.
+///
+/// `L` is unit lower triangular, `U` upper triangular and `P` a permutation
+/// matrix that brings the element of largest absolute value of each column
+/// onto the diagonal.
+///
+/// ```
+/// use ndarray::array;
+/// use ndarray::linalg::lu;
+///
+/// let a = array![[1., 2.], [3., 4.]];
+/// let lu = lu(&a).unwrap();
+/// assert_eq!(lu.permutation(), &[1, 0]);
+/// assert_eq!(lu.l(), array![[1., 0.], [1. / 3., 1.]]);
+/// assert_eq!(lu.p().dot(&a), lu.l().dot(&lu.u()));
+/// ```
+///
+/// ***Errors*** if `a` is not square ([`LinalgError::NotSquare`]), or if it
+/// is singular ([`LinalgError::Singular`]).
+pub fn lu<A>(a: &ArrayRef2<A>) -> Result<Lu<A>, LinalgError>
+where A: LinalgField
+{
+    let n = square(a)?;
+    let mut lu = a.to_owned();
+    let mut perm = (0..n).collect::<Vec<_>>();
+    let mut odd = false;
+    for k in (0..n).step_by(BLOCK) {
+        let kb = BLOCK.min(n - k);
+        // factor the panel of columns k..k + kb, swapping whole rows
+        for j in k..k + kb {
+            let p = j + lu
+                .slice(s![j.., j])
+                .iter()
+                .enumerate()
+                .fold((0, A::Real::zero()), |(p, max), (i, x)| if x.abs() > max { (i, x.abs()) } else { (p, max) })
+                .0;
+            if lu[[p, j]] == A::zero() {
+                return Err(LinalgError::Singular { index: j });
+            }
+            if p != j {
+                swap_rows(&mut lu, p, j);
+                perm.swap(p, j);
+                odd = !odd;
+            }
+            let (pivot_row, below) = lu.slice_mut(s![j.., j..k + kb]).split_at(Axis(0), 1);
+            let pivot_row = pivot_row.row(0);
+            let inv = A::one() / pivot_row[0];
+            let (mut l, mut rest) = below.split_at(Axis(1), 1);
+            let mut l = l.column_mut(0);
+            l.mapv_inplace(|x| x * inv);
+            rank1_update(-A::one(), &l, &pivot_row.slice(s![1..]), &mut rest);
+        }
+        if k + kb == n {
+            break;
+        }
+
+        let (left, right) = lu.view_mut().split_at(Axis(1), k + kb);
+        let l11 = left.slice(s![k..k + kb, k..]);
+        let l21 = left.slice(s![k + kb.., k..]);
+        let (mut a12, mut a22) = right.split_at(Axis(0), k + kb);
+        // U₁₂ = L₁₁⁻¹ A₁₂, by forward substitution
+        let mut u12 = a12.slice_mut(s![k.., ..]);
+        substitute(&l11, &mut u12, Triangle::Lower, Diagonal::Unit);
+        // A₂₂ -= L₂₁ U₁₂
+        general_mat_mul(-A::one(), &l21, &u12, A::one(), &mut a22);
+    }
+    Ok(Lu { lu, perm, odd })
+}
+
+/// Compute the Cholesky factorization of the Hermitian (for real elements,
+/// symmetric) positive definite matrix `a`, `A = L Lᴴ`.
+///
+/// `L` is lower triangular with a real, positive diagonal. Only the lower
+/// triangle of `a` is used; the elements above the diagonal are not read.
+///
+/// ```
+/// use ndarray::array;
+/// use ndarray::linalg::cholesky;
+///
+/// let a = array![[4., 2.], [2., 5.]];
+/// let l = cholesky(&a).unwrap().into_l();
+/// assert_eq!(l, array![[2., 0.], [1., 2.]]);
+/// assert_eq!(l.dot(&l.t()), a);
+/// ```
+///
+/// ***Errors*** if `a` is not square ([`LinalgError::NotSquare`]), or if it
+/// is not positive definite ([`LinalgError::NotPositiveDefinite`]).
+pub fn cholesky<A>(a: &ArrayRef2<A>) -> Result<Cholesky<A>, LinalgError>
+where A: LinalgField
+{
+    let n = square(a)?;
+    let mut l = a.to_owned();
+    for k in (0..n).step_by(BLOCK) {
+        let kb = BLOCK.min(n - k);
+        // factor the diagonal block
+        let mut l11 = l.slice_mut(s![k..k + kb, k..k + kb]);
+        for j in 0..kb {
+            let d = l11[[j, j]].re()
+                - l11
+                    .slice(s![j, ..j])
+                    .fold(A::Real::zero(), |acc, x| acc + x.abs_sqr());
+            if d.is_nan() || d <= A::Real::zero() {
+                return Err(LinalgError::NotPositiveDefinite { index: k + j });
+            }
+            let d = d.sqrt();
+            l11[[j, j]] = A::from_real(d);
+            for i in j + 1..kb {
+                let mut x = l11[[i, j]];
+                for p in 0..j {
+                    x = x - l11[[i, p]] * l11[[j, p]].conj();
+                }
+                l11[[i, j]] = x.scale(d.recip());
+            }
+        }
+        if k + kb == n {
+            break;
+        }
+
+        let (top, bottom) = l.view_mut().split_at(Axis(0), k + kb);
+        let l11 = top.slice(s![k.., k..]);
+        let (mut left, mut a22) = bottom.split_at(Axis(1), k + kb);
+        // L₂₁ = A₂₁ L₁₁⁻ᴴ, one column at a time
+        let mut l21 = left.slice_mut(s![.., k..]);
+        for j in 0..kb {
+            let (done, mut rest) = l21.view_mut().split_at(Axis(1), j);
+            let mut col = rest.column_mut(0);
+            for p in 0..j {
+                col.scaled_add(-l11[[j, p]].conj(), &done.column(p));
+            }
+            let d = l11[[j, j]].re().recip();
+            col.mapv_inplace(|x| x.scale(d));
+        }
+        // A₂₂ -= L₂₁ L₂₁ᴴ, of which only the lower triangle is used
+        hermitian_rank_k_update(-A::Real::one(), &l21, A::Real::one(), &mut a22, Triangle::Lower);
+    }
+    for i in 0..n {
+        l.slice_mut(s![i, i + 1..]).fill(A::zero());
+    }
+    Ok(Cholesky { l })
+}
+
+/// Compute the QR factorization of the matrix `a` by Householder
+/// reflections, `A = Q R`.
+///
+/// For an *m* × *n* matrix, `Q` is *m* × min(*m*, *n*) with orthonormal
+/// columns and `R` is min(*m*, *n*) × *n* upper triangular. The
+/// factorization exists for every matrix, singular or not.
+///
+/// ```
+/// use ndarray::array;
+/// use ndarray::linalg::qr;
+/// use approx::assert_abs_diff_eq;
+///
+/// let a = array![[3., -1.], [4., 7.], [0., 0.]];
+/// let qr = qr(&a);
+/// let (q, r) = (qr.q(), qr.r());
+/// assert_eq!(q.dim(), (3, 2));
+/// // R is unique up to the signs of its rows
+/// assert_abs_diff_eq!(r.mapv(f64::abs), array![[5., 5.], [0., 5.]], epsilon = 1e-12);
+/// assert_abs_diff_eq!(q.dot(&r), a, epsilon = 1e-12);
+/// ```
+pub fn qr<A>(a: &ArrayRef2<A>) -> Qr<A>
+where A: LinalgField
+{
+    let (m, n) = a.dim();
+    let kmax = m.min(n);
+    let mut qr = a.to_owned();
+    let mut tau = Vec::with_capacity(kmax);
+    for k in (0..kmax).step_by(BLOCK) {
+        let kb = BLOCK.min(kmax - k);
+        // factor the panel of columns k..k + kb
+        for j in k..k + kb {
+            let (mut v, mut rest) = qr.slice_mut(s![j.., j..k + kb]).split_at(Axis(1), 1);
+            let mut v = v.column_mut(0);
+            let t = householder(&mut v);
+            tau.push(t);
+            // apply Hⱼᴴ = I - conj(τ) v vᴴ to the rest of the panel
+            let v = v.slice(s![1..]);
+            for mut col in rest.columns_mut() {
+                let w = col[0]
+                    + Zip::from(&v)
+                        .and(col.slice(s![1..]))
+                        .fold(A::zero(), |acc, &v, &x| acc + v.conj() * x);
+                let f = t.conj() * w;
+                col[0] = col[0] - f;
+                col.slice_mut(s![1..]).scaled_add(-f, &v);
+            }
+        }
+        if k + kb == n {
+            break;
+        }
+
+        let v = reflectors(&qr.slice(s![k.., k..k + kb]));
+        let t = triangular_factor(&v, &tau[k..k + kb]);
+        apply_block_reflector(&v, &t, &mut qr.slice_mut(s![k.., k + kb..]), true);
+    }
+    Qr { qr, tau }
+}
+
+/// Return the size of the square matrix `a`.
+pub(crate) fn square<A>(a: &ArrayRef2<A>) -> Result<usize, LinalgError>
+{
+    let (rows, cols) = a.dim();
+    if rows == cols {
+        Ok(rows)
+    } else {
+        Err(LinalgError::NotSquare { rows, cols })
+    }
+}
+
+/// Return the conjugate transpose of `a`.
+pub(crate) fn conj_t<A>(a: &ArrayRef2<A>) -> Array2<A>
+where A: LinalgField
+{
+    a.t().mapv(A::conj)
+}
+
+fn swap_rows<A>(a: &mut ArrayRef2<A>, i: usize, j: usize)
+{
+    let (mut ri, mut rj) = a.multi_slice_mut((s![i, ..], s![j, ..]));
+    Zip::from(&mut ri).and(&mut rj).for_each(std::mem::swap);
+}
+
+/// Replace `x` by the vector `v` of the Householder reflection
+/// `H = I - τ v vᴴ` with `Hᴴ x = β e₀`, and return `τ`.
+///
+/// `v₀ = 1` is implicit: `x₀` is replaced by `β`, which is real.
+fn householder<A>(x: &mut ArrayRef1<A>) -> A
+where A: LinalgField
+{
+    let alpha = x[0];
+    let xnorm = x
+        .slice(s![1..])
+        .fold(A::Real::zero(), |acc, xi| acc.hypot(xi.abs()));
+    if xnorm == A::Real::zero() && alpha.conj() == alpha {
+        return A::zero();
+    }
+    let norm = alpha.abs().hypot(xnorm);
+    let beta = if alpha.re() >= A::Real::zero() { -norm } else { norm };
+    let inv = A::one() / (alpha - A::from_real(beta));
+    x.slice_mut(s![1..]).mapv_inplace(|xi| xi * inv);
+    x[0] = A::from_real(beta);
+    (A::from_real(beta) - alpha).scale(beta.recip())
+}
+
+/// Return the Householder vectors stored below the diagonal of `a`, with
+/// their implicit ones on the diagonal and zeros above it.
+fn reflectors<A>(a: &ArrayRef2<A>) -> Array2<A>
+where A: LinalgField
+{
+    let mut v = a.to_owned();
+    for j in 0..v.ncols() {
+        v.slice_mut(s![..j, j]).fill(A::zero());
+        v[[j, j]] = A::one();
+    }
+    v
+}
+
+/// Return the upper triangular `T` with `H₀ H₁ ⋯ Hₖ₋₁ = I - V T Vᴴ`, for the
+/// reflections with the vectors in the columns of `v` and factors `tau`.
+fn triangular_factor<A>(v: &ArrayRef2<A>, tau: &[A]) -> Array2<A>
+where A: LinalgField
+{
+    let k = tau.len();
+    // only the upper triangle of Vᴴ V is used
+    let mut vhv = Array2::zeros((k, k));
+    hermitian_rank_k_update(A::Real::one(), &conj_t(v), A::Real::zero(), &mut vhv, Triangle::Upper);
+    let mut t = Array2::zeros((k, k));
+    for i in 0..k {
+        t[[i, i]] = tau[i];
+        let mut z = vhv.slice(s![..i, i]).to_owned();
+        triangular_mat_vec_mul(&t.slice(s![..i, ..i]), &mut z, Triangle::Upper);
+        t.slice_mut(s![..i, i]).assign(&(z * -tau[i]));
+    }
+    t
+}
+
+/// Apply `I - V T Vᴴ`, or its conjugate transpose if `adjoint`, to `c` from
+/// the left.
+fn apply_block_reflector<A>(v: &ArrayRef2<A>, t: &ArrayRef2<A>, c: &mut ArrayRef2<A>, adjoint: bool)
+where A: LinalgField
+{
+    let mut w = Array2::zeros((v.ncols(), c.ncols()));
+    general_mat_mul_op(A::one(), v, Transpose::ConjTrans, c, Transpose::NoTrans, A::zero(), &mut w);
+    let t_op = if adjoint {
+        Transpose::ConjTrans
+    } else {
+        Transpose::NoTrans
+    };
+    let w = t.dot_op(t_op, &w, Transpose::NoTrans);
+    general_mat_mul(-A::one(), v, &w, A::one(), c);
+}
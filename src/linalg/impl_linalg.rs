@@ -8,13 +8,21 @@
 
 use crate::imp_prelude::*;
 
+use crate::dimension::broadcast::co_broadcast;
+use crate::dimension::size_of_shape_checked;
+
 #[cfg(feature = "blas")]
 use crate::dimension::offset_from_low_addr_ptr_to_logical_ptr;
 use crate::numeric_util;
 use crate::ArrayRef1;
 use crate::ArrayRef2;
 
-use crate::{LinalgScalar, Zip};
+use crate::linalg::mixed::mat_mul_blocked;
+#[cfg(all(feature = "blas", feature = "std"))]
+use crate::linalg::Diagonal;
+#[cfg(feature = "std")]
+use crate::{linalg::Triangle, LinalgField};
+use crate::{LinalgScalar, Summation, Zip};
 
 #[cfg(not(feature = "std"))]
 use alloc::vec;
@@ -23,17 +31,22 @@ use alloc::vec::Vec;
 
 use std::any::TypeId;
 use std::mem::MaybeUninit;
+use std::ops::Mul;
 
 use num_complex::Complex;
 use num_complex::{Complex32 as c32, Complex64 as c64};
 
 #[cfg(feature = "blas")]
 use libc::c_int;
+#[cfg(feature = "blas")]
+use num_traits::Zero;
 
 #[cfg(feature = "blas")]
 use cblas_sys as blas_sys;
 #[cfg(feature = "blas")]
-use cblas_sys::{CblasNoTrans, CblasTrans, CBLAS_LAYOUT, CBLAS_TRANSPOSE};
+use cblas_sys::{CblasConjTrans, CblasLower, CblasNoTrans, CblasTrans, CblasUpper, CBLAS_LAYOUT, CBLAS_TRANSPOSE};
+#[cfg(all(feature = "blas", feature = "std"))]
+use cblas_sys::{CblasLeft, CblasNonUnit, CblasUnit};
 
 /// len of vector before we use blas
 #[cfg(feature = "blas")]
@@ -71,6 +84,32 @@ impl<A> ArrayRef<A, Ix1>
         Dot::dot(self, rhs)
     }
 
+    /// Compute the dot product of the one-dimensional arrays `self` and
+    /// `rhs`, summing the elementwise products with the summation method
+    /// `method`.
+    ///
+    /// See [`Summation`] for the available methods; unlike
+    /// [`.dot()`](Self::dot), this never uses BLAS.
+    ///
+    /// **Panics** if the arrays are not of the same length.
+    ///
+    /// ```
+    /// use ndarray::{array, Compensated};
+    ///
+    /// let a = array![1e8f32, 1., -1e8];
+    /// let b = array![1f32, 1., 1.];
+    /// assert_eq!(a.dot_with(&b, Compensated), 1.);
+    /// ```
+    #[track_caller]
+    pub fn dot_with<S>(&self, rhs: &ArrayRef<A, Ix1>, method: S) -> A
+    where
+        A: Clone + Mul<Output = A>,
+        S: Summation<A>,
+    {
+        assert_eq!(self.len(), rhs.len(), "ndarray: dot_with of vectors of different lengths");
+        method.sum_iter(self.iter().zip(rhs).map(|(a, b)| a.clone() * b.clone()))
+    }
+
     fn dot_generic(&self, rhs: &ArrayRef<A, Ix1>) -> A
     where A: LinalgScalar
     {
@@ -133,6 +172,24 @@ impl<A> ArrayRef<A, Ix1>
     }
 }
 
+/// Pass the scalar `$var` of type `$ty` to BLAS: by value for real types, by
+/// pointer for complex types.
+#[cfg(feature = "blas")]
+macro_rules! blas_scalar_cast {
+    (f32, $var:ident) => {
+        cast_as(&$var)
+    };
+    (f64, $var:ident) => {
+        cast_as(&$var)
+    };
+    (c32, $var:ident) => {
+        &$var as *const _ as *const _
+    };
+    (c64, $var:ident) => {
+        &$var as *const _ as *const _
+    };
+}
+
 /// Return a pointer to the starting element in BLAS's view.
 ///
 /// BLAS wants a pointer to the element with lowest address,
@@ -277,9 +334,11 @@ impl<A> ArrayRef<A, Ix2>
     /// **Panics** if shapes are incompatible or the number of elements in the
     /// result would overflow `isize`.
     ///
-    /// *Note:* If enabled, uses blas `gemv/gemm` for elements of `f32, f64`
-    /// when memory layout allows. The default matrixmultiply backend
-    /// is otherwise used for `f32, f64` for all memory layouts.
+    /// *Note:* If enabled, uses blas `gemv/gemm` for elements of
+    /// `f32, f64, c32, c64` when memory layout allows. The default matrixmultiply backend
+    /// is otherwise used for `f32, f64` for all memory layouts. The product
+    /// of a matrix and its own transpose, like `a.t().dot(&a)`, is symmetric;
+    /// only half of it is computed (with blas `syrk` if enabled).
     ///
     /// ```
     /// use ndarray::arr2;
@@ -300,6 +359,45 @@ impl<A> ArrayRef<A, Ix2>
     {
         Dot::dot(self, rhs)
     }
+
+    /// Perform matrix multiplication of `self` and `rhs`, each of them
+    /// transposed or conjugate transposed as given by `trans` and `rhs_trans`.
+    ///
+    /// The array shapes must agree in the way that if op(`self`) is
+    /// *M* × *N*, then op(`rhs`) is *N* × *K*.
+    ///
+    /// Return a result array with shape *M* × *K*.
+    ///
+    /// **Panics** if shapes are incompatible or the number of elements in the
+    /// result would overflow `isize`.
+    ///
+    /// *Note:* Computed like [`general_mat_mul_op`]. The product of a matrix
+    /// and its own conjugate transpose, like
+    /// `a.dot_op(Transpose::NoTrans, &a, Transpose::ConjTrans)`, is Hermitian;
+    /// only half of it is computed (with blas `herk` if enabled).
+    ///
+    /// ```
+    /// use ndarray::linalg::Transpose;
+    /// use ndarray::arr2;
+    /// use num_complex::Complex64;
+    ///
+    /// let i = Complex64::i();
+    /// let a = arr2(&[[1. + i, 2. * i],
+    ///                [0. * i, 1. - i]]);
+    ///
+    /// let aah = a.dot_op(Transpose::NoTrans, &a, Transpose::ConjTrans);
+    /// assert_eq!(aah, arr2(&[[6. + 0. * i, -2. + 2. * i],
+    ///                        [-2. - 2. * i, 2. + 0. * i]]));
+    /// ```
+    #[cfg(feature = "std")]
+    #[track_caller]
+    pub fn dot_op(&self, trans: Transpose, rhs: &ArrayRef<A, Ix2>, rhs_trans: Transpose) -> Array2<A>
+    where A: LinalgField
+    {
+        let (a, conj_a) = trans.apply(self);
+        let (b, conj_b) = rhs_trans.apply(rhs);
+        dot_conj(&a, conj_a, &b, conj_b)
+    }
 }
 
 impl<A> Dot<ArrayRef<A, Ix2>> for ArrayRef<A, Ix2>
@@ -309,32 +407,60 @@ where A: LinalgScalar
 
     fn dot(&self, b: &ArrayRef<A, Ix2>) -> Array2<A>
     {
-        let a = self.view();
-        let b = b.view();
-        let ((m, k), (k2, n)) = (a.dim(), b.dim());
-        if k != k2 || m.checked_mul(n).is_none() {
-            dot_shape_error(m, k, k2, n);
-        }
+        dot_conj(self, false, b, false)
+    }
+}
 
-        let lhs_s0 = a.strides()[0];
-        let rhs_s0 = b.strides()[0];
-        let column_major = lhs_s0 == 1 && rhs_s0 == 1;
-        // A is Copy so this is safe
-        let mut v = Vec::with_capacity(m * n);
-        let mut c;
-        unsafe {
-            v.set_len(m * n);
-            c = Array::from_shape_vec_unchecked((m, n).set_f(column_major), v);
+/// Compute op(A) op(B) in a new array, where op conjugates the elements of
+/// an operand if its `conj_` flag is set.
+#[track_caller]
+fn dot_conj<A>(a: &ArrayRef2<A>, conj_a: bool, b: &ArrayRef2<A>, conj_b: bool) -> Array2<A>
+where A: LinalgScalar
+{
+    let ((m, k), (k2, n)) = (a.dim(), b.dim());
+    if k != k2 || m.checked_mul(n).is_none() {
+        dot_shape_error(m, k, k2, n);
+    }
+
+    let lhs_s0 = a.strides()[0];
+    let rhs_s0 = b.strides()[0];
+    // blas conjugates operands that are stored transposed relative to C
+    let is_complex = same_type::<A, c32>() || same_type::<A, c64>();
+    let column_major = match (conj_a && is_complex, conj_b && is_complex) {
+        (true, _) => a.strides()[1] == 1,
+        (false, true) => b.strides()[1] == 1,
+        (false, false) => lhs_s0 == 1 && rhs_s0 == 1,
+    };
+    // A is Copy so this is safe
+    let mut v = Vec::with_capacity(m * n);
+    let mut c;
+    unsafe {
+        v.set_len(m * n);
+        c = Array::from_shape_vec_unchecked((m, n).set_f(column_major), v);
+    }
+    if m == n && a.as_ptr() == b.as_ptr() && a.strides() == [b.strides()[1], b.strides()[0]] {
+        // A Aᵀ is symmetric and A Aᴴ is Hermitian: compute the upper triangle
+        // and mirror it. The conjugate of the left operand conjugates both.
+        let hermitian = conj_a != conj_b;
+        rank_k_update(A::one(), a, A::zero(), &mut c, true, hermitian);
+        for i in 1..m {
+            for j in 0..i {
+                c[[i, j]] = if hermitian { conj_scalar(c[[j, i]]) } else { c[[j, i]] };
+            }
         }
-        mat_mul_impl(A::one(), &a, &b, A::zero(), &mut c.view_mut());
-        c
+        if conj_a {
+            c.map_inplace(|x| *x = conj_scalar(*x));
+        }
+    } else {
+        mat_mul_conj_impl(A::one(), a, conj_a, b, conj_b, A::zero(), &mut c);
     }
+    c
 }
 
 /// Assumes that `m` and `n` are ≤ `isize::MAX`.
 #[cold]
 #[inline(never)]
-fn dot_shape_error(m: usize, k: usize, k2: usize, n: usize) -> !
+pub(super) fn dot_shape_error(m: usize, k: usize, k2: usize, n: usize) -> !
 {
     match m.checked_mul(n) {
         Some(len) if len <= isize::MAX as usize => {}
@@ -348,7 +474,7 @@ fn dot_shape_error(m: usize, k: usize, k2: usize, n: usize) -> !
 
 #[cold]
 #[inline(never)]
-fn general_dot_shape_error(m: usize, k: usize, k2: usize, n: usize, c1: usize, c2: usize) -> !
+pub(super) fn general_dot_shape_error(m: usize, k: usize, k2: usize, n: usize, c1: usize, c2: usize) -> !
 {
     panic!("ndarray: inputs {} × {}, {} × {}, and output {} × {} are not compatible for matrix multiplication",
            m, k, k2, n, c1, c2);
@@ -379,7 +505,7 @@ where A: LinalgScalar
         // Avoid initializing the memory in vec -- set it during iteration
         unsafe {
             let mut c = Array1::uninit(m);
-            general_mat_vec_mul_impl(A::one(), self, rhs, A::zero(), c.raw_view_mut().cast::<A>());
+            general_mat_vec_mul_impl(A::one(), self, false, rhs, A::zero(), c.raw_view_mut().cast::<A>());
             c.assume_init()
         }
     }
@@ -388,37 +514,182 @@ where A: LinalgScalar
 impl<A, D> ArrayRef<A, D>
 where D: Dimension
 {
+    /// Perform matrix multiplication of `self` and `rhs`, where any leading
+    /// axes before the last two are batch axes, like NumPy's `matmul`.
+    ///
+    /// The last two axes of each array are the matrices, which must agree in
+    /// the way that if `self` has *M* × *N* matrices, then `rhs` has
+    /// *N* × *K* matrices. The batch axes are broadcast together, with the
+    /// same rules as for arithmetic operations, and the result has the
+    /// broadcast batch axes followed by *M* × *K*.
+    ///
+    /// A one-dimensional `self` is treated as a single row, and a
+    /// one-dimensional `rhs` as a single column; the axis is removed from the
+    /// result again.
+    ///
+    /// **Panics** if either array is zero-dimensional, if the shapes are
+    /// incompatible, or if the number of elements in the result would
+    /// overflow `isize`.
+    ///
+    /// *Note:* Each matrix multiplication is computed like
+    /// [`general_mat_mul`], see [`general_batched_mat_mul`].
+    ///
+    /// ```
+    /// use ndarray::{array, Array, Array2};
+    ///
+    /// let a = Array::from_shape_fn((2, 3, 4), |(b, i, j)| (b + i * j) as f64);
+    /// let w = Array2::from_shape_fn((4, 5), |(i, j)| (i + j) as f64);
+    /// let c = a.matmul(&w);
+    /// assert_eq!(c.shape(), &[2, 3, 5]);
+    /// for (c, a) in c.outer_iter().zip(a.outer_iter()) {
+    ///     assert_eq!(c, a.dot(&w).into_dyn());
+    /// }
+    ///
+    /// let v = array![1., 2., 3., 4.];
+    /// assert_eq!(a.matmul(&v).shape(), &[2, 3]);
+    /// ```
+    #[track_caller]
+    pub fn matmul<E>(&self, rhs: &ArrayRef<A, E>) -> ArrayD<A>
+    where
+        A: LinalgScalar,
+        E: Dimension,
+    {
+        assert!(self.ndim() >= 1 && rhs.ndim() >= 1, "ndarray: matmul of a zero-dimensional array");
+        let mut a = self.view().into_dyn();
+        let mut b = rhs.view().into_dyn();
+        let a_is_vector = a.ndim() == 1;
+        if a_is_vector {
+            a.insert_axis_inplace(Axis(0));
+        }
+        let b_is_vector = b.ndim() == 1;
+        if b_is_vector {
+            b.insert_axis_inplace(Axis(1));
+        }
+        let (na, nb) = (a.ndim(), b.ndim());
+        let (m, k) = (a.shape()[na - 2], a.shape()[na - 1]);
+        let (k2, n) = (b.shape()[nb - 2], b.shape()[nb - 1]);
+        if k != k2 {
+            dot_shape_error(m, k, k2, n);
+        }
+        let batch: IxDyn = match co_broadcast(&IxDyn(&a.shape()[..na - 2]), &IxDyn(&b.shape()[..nb - 2])) {
+            Ok(batch) => batch,
+            Err(_) => panic!(
+                "ndarray: batch shapes {:?} and {:?} are not compatible for matrix multiplication",
+                &a.shape()[..na - 2],
+                &b.shape()[..nb - 2]
+            ),
+        };
+        let mut shape = batch.slice().to_vec();
+        shape.extend([m, n]);
+        let len = match size_of_shape_checked(&IxDyn(&shape)) {
+            Ok(len) => len,
+            Err(_) => panic!("ndarray: shape {:?} overflows isize", shape),
+        };
+
+        // A is Copy so this is safe
+        let mut v = Vec::with_capacity(len);
+        let mut c;
+        unsafe {
+            v.set_len(len);
+            c = ArrayD::from_shape_vec_unchecked(shape, v);
+        }
+        general_batched_mat_mul(A::one(), &a, &b, A::zero(), &mut c);
+        if b_is_vector {
+            c.index_axis_inplace(Axis(c.ndim() - 1), 0);
+        }
+        if a_is_vector {
+            c.index_axis_inplace(Axis(c.ndim() - 1 - !b_is_vector as usize), 0);
+        }
+        c
+    }
+
     /// Perform the operation `self += alpha * rhs` efficiently, where
     /// `alpha` is a scalar and `rhs` is another array. This operation is
     /// also known as `axpy` in BLAS.
     ///
     /// If their shapes disagree, `rhs` is broadcast to the shape of `self`.
     ///
-    /// **Panics** if broadcasting isn't possible.
+    /// **Panics** if broadcasting isn't possible.<br>
+    /// *Note:* If enabled, uses blas `axpy` for vectors and matrices of
+    /// `f32, f64, c32, c64` of the same shape when memory layout allows.
     #[track_caller]
     pub fn scaled_add<E>(&mut self, alpha: A, rhs: &ArrayRef<A, E>)
     where
         A: LinalgScalar,
         E: Dimension,
     {
+        #[cfg(feature = "blas")]
+        if blas_axpy(alpha, rhs, self) {
+            return;
+        }
         self.zip_mut_with(rhs, move |y, &x| *y = *y + (alpha * x));
     }
 }
 
 // mat_mul_impl uses ArrayRef arguments to send all array kinds into
 // the same instantiated implementation.
-#[cfg(not(feature = "blas"))]
-use self::mat_mul_general as mat_mul_impl;
+fn mat_mul_impl<A>(alpha: A, a: &ArrayRef2<A>, b: &ArrayRef2<A>, beta: A, c: &mut ArrayRef2<A>)
+where A: LinalgScalar
+{
+    mat_mul_conj_impl(alpha, a, false, b, false, beta, c)
+}
 
+/// C ← α op(A) op(B) + β C, where op conjugates the elements of an operand
+/// if its `conj_` flag is set.
+///
+/// Conjugation is implemented for elements of `c32, c64` and is the identity
+/// for other types, so the flags must only be set for these and real types.
+/// Without blas, a single conjugated operand is copied.
+fn mat_mul_conj_impl<A>(
+    alpha: A, a: &ArrayRef2<A>, conj_a: bool, b: &ArrayRef2<A>, conj_b: bool, beta: A, c: &mut ArrayRef2<A>,
+) where A: LinalgScalar
+{
+    #[cfg(feature = "blas")]
+    if blas_gemm(alpha, a, conj_a, b, conj_b, beta, c) {
+        return;
+    }
+    let is_complex = same_type::<A, c32>() || same_type::<A, c64>();
+    let conj = |x: &A| conj_scalar(*x);
+    match (conj_a && is_complex, conj_b && is_complex) {
+        (false, false) => mat_mul_general(alpha, a, b, beta, c),
+        (true, false) => mat_mul_general(alpha, &a.map(conj), b, beta, c),
+        (false, true) => mat_mul_general(alpha, a, &b.map(conj), beta, c),
+        (true, true) => {
+            // conj(A) conj(B) = conj(A B)
+            if !beta.is_zero() {
+                c.map_inplace(|x| *x = conj(x));
+            }
+            mat_mul_general(conj(&alpha), a, b, conj(&beta), c);
+            c.map_inplace(|x| *x = conj(x));
+        }
+    }
+}
+
+/// Compute C ← α op(A) op(B) + β C with BLAS `gemm`, where op conjugates the
+/// elements of an operand if its `conj_` flag is set.
+///
+/// Return `false` if BLAS can't be used for the element type or the
+/// layouts.
 #[cfg(feature = "blas")]
-fn mat_mul_impl<A>(alpha: A, a: &ArrayRef2<A>, b: &ArrayRef2<A>, beta: A, c: &mut ArrayRef2<A>)
+fn blas_gemm<A>(
+    alpha: A, a: &ArrayRef2<A>, conj_a: bool, b: &ArrayRef2<A>, conj_b: bool, beta: A, c: &mut ArrayRef2<A>,
+) -> bool
 where A: LinalgScalar
 {
     let ((m, k), (k2, n)) = (a.dim(), b.dim());
     debug_assert_eq!(k, k2);
-    if (m > GEMM_BLAS_CUTOFF || n > GEMM_BLAS_CUTOFF || k > GEMM_BLAS_CUTOFF)
-        && (same_type::<A, f32>() || same_type::<A, f64>() || same_type::<A, c32>() || same_type::<A, c64>())
+    if !(m > GEMM_BLAS_CUTOFF || n > GEMM_BLAS_CUTOFF || k > GEMM_BLAS_CUTOFF)
+        || !(same_type::<A, f32>() || same_type::<A, f64>() || same_type::<A, c32>() || same_type::<A, c64>())
     {
+        return false;
+    }
+    let is_complex = same_type::<A, c32>() || same_type::<A, c64>();
+    let (conj_a, conj_b) = (conj_a && is_complex, conj_b && is_complex);
+
+    for c_layout in [BlasOrder::C, BlasOrder::F] {
+        if !is_blas_2d(c._dim(), c._strides(), c_layout) {
+            continue;
+        }
         // Compute A B -> C
         // We require for BLAS compatibility that:
         // A, B, C are contiguous (stride=1) in their fastest dimension,
@@ -429,34 +700,17 @@ where A: LinalgScalar
         //
         // Apply transpose to A, B as needed if they differ from the row major case.
         // If C is CblasColMajor then transpose both A, B (again!)
-
-        if let (Some(a_layout), Some(b_layout), Some(c_layout)) =
-            (get_blas_compatible_layout(a), get_blas_compatible_layout(b), get_blas_compatible_layout(c))
+        //
+        // A conjugated operand needs to be transposed, since BLAS only
+        // conjugates together with the transpose; try both layouts of C.
+        let cblas_layout = c_layout.to_cblas_layout();
+        if let (Some((a_layout, a_trans)), Some((b_layout, b_trans))) =
+            (blas_gemm_operand(a, conj_a, cblas_layout), blas_gemm_operand(b, conj_b, cblas_layout))
         {
-            let cblas_layout = c_layout.to_cblas_layout();
-            let a_trans = a_layout.to_cblas_transpose_for(cblas_layout);
             let lda = blas_stride(a, a_layout);
-
-            let b_trans = b_layout.to_cblas_transpose_for(cblas_layout);
             let ldb = blas_stride(b, b_layout);
-
             let ldc = blas_stride(c, c_layout);
 
-            macro_rules! gemm_scalar_cast {
-                (f32, $var:ident) => {
-                    cast_as(&$var)
-                };
-                (f64, $var:ident) => {
-                    cast_as(&$var)
-                };
-                (c32, $var:ident) => {
-                    &$var as *const A as *const _
-                };
-                (c64, $var:ident) => {
-                    &$var as *const A as *const _
-                };
-            }
-
             macro_rules! gemm {
                 ($ty:tt, $gemm:ident) => {
                     if same_type::<A, $ty>() {
@@ -470,17 +724,17 @@ where A: LinalgScalar
                                 m as blas_index,                 // m, rows of Op(a)
                                 n as blas_index,                 // n, cols of Op(b)
                                 k as blas_index,                 // k, cols of Op(a)
-                                gemm_scalar_cast!($ty, alpha),   // alpha
+                                blas_scalar_cast!($ty, alpha),   // alpha
                                 a._ptr().as_ptr() as *const _,      // a
                                 lda,                             // lda
                                 b._ptr().as_ptr() as *const _,      // b
                                 ldb,                             // ldb
-                                gemm_scalar_cast!($ty, beta),    // beta
+                                blas_scalar_cast!($ty, beta),    // beta
                                 c._ptr().as_ptr() as *mut _,        // c
                                 ldc,                             // ldc
                             );
                         }
-                        return;
+                        return true;
                     }
                 };
             }
@@ -493,7 +747,26 @@ where A: LinalgScalar
             unreachable!() // we checked above that A is one of f32, f64, c32, c64
         }
     }
-    mat_mul_general(alpha, a, b, beta, c)
+    false
+}
+
+/// Return a BLAS compatible layout of the operand `a` of `gemm` with C in
+/// `for_layout`, and how BLAS should transpose it, or conjugate transpose it
+/// if `conj` is set.
+#[cfg(feature = "blas")]
+fn blas_gemm_operand<A>(a: &ArrayRef2<A>, conj: bool, for_layout: CBLAS_LAYOUT)
+    -> Option<(BlasOrder, CBLAS_TRANSPOSE)>
+{
+    for layout in [BlasOrder::C, BlasOrder::F] {
+        if is_blas_2d(a._dim(), a._strides(), layout) {
+            match layout.to_cblas_transpose_for(for_layout) {
+                trans if !conj => return Some((layout, trans)),
+                CblasTrans => return Some((layout, CblasConjTrans)),
+                _ => {}
+            }
+        }
+    }
+    None
 }
 
 /// C ← α A B + β C
@@ -588,33 +861,7 @@ where A: LinalgScalar
             );
         }
     } else {
-        // It's a no-op if `c` has zero length.
-        if c.is_empty() {
-            return;
-        }
-
-        // initialize memory if beta is zero
-        if beta.is_zero() {
-            c.fill(beta);
-        }
-
-        let mut i = 0;
-        let mut j = 0;
-        loop {
-            unsafe {
-                let elt = c.uget_mut((i, j));
-                *elt =
-                    *elt * beta + alpha * (0..k).fold(A::zero(), move |s, x| s + *lhs.uget((i, x)) * *rhs.uget((x, j)));
-            }
-            j += 1;
-            if j == n {
-                j = 0;
-                i += 1;
-                if i == m {
-                    break;
-                }
-            }
-        }
+        mat_mul_blocked(alpha, lhs, rhs, beta, c);
     }
 }
 
@@ -628,7 +875,9 @@ where A: LinalgScalar
 /// ***Panics*** if array shapes are not compatible<br>
 /// *Note:* If enabled, uses blas `gemm` for elements of `f32, f64` when memory
 /// layout allows.  The default matrixmultiply backend is otherwise used for
-/// `f32, f64` for all memory layouts.
+/// `f32, f64` for all memory layouts. Other element types use a cache blocked
+/// kernel; see also [`general_mat_mul_widening`](crate::linalg::general_mat_mul_widening)
+/// for accumulating small integers in a wider type.
 #[track_caller]
 pub fn general_mat_mul<A>(alpha: A, a: &ArrayRef2<A>, b: &ArrayRef2<A>, beta: A, c: &mut ArrayRef2<A>)
 where A: LinalgScalar
@@ -650,14 +899,230 @@ where A: LinalgScalar
 /// y an *M*-element column vector (one dimensional arrays).
 ///
 /// ***Panics*** if array shapes are not compatible<br>
-/// *Note:* If enabled, uses blas `gemv` for elements of `f32, f64` when memory
-/// layout allows.
+/// *Note:* If enabled, uses blas `gemv` for elements of `f32, f64, c32, c64`
+/// when memory layout allows.
 #[track_caller]
 #[allow(clippy::collapsible_if)]
 pub fn general_mat_vec_mul<A>(alpha: A, a: &ArrayRef2<A>, x: &ArrayRef1<A>, beta: A, y: &mut ArrayRef1<A>)
 where A: LinalgScalar
 {
-    unsafe { general_mat_vec_mul_impl(alpha, a, x, beta, y.raw_view_mut()) }
+    unsafe { general_mat_vec_mul_impl(alpha, a, false, x, beta, y.raw_view_mut()) }
+}
+
+/// How an operand enters a matrix product, see
+/// [`general_mat_mul_op`] and [`.dot_op()`](ArrayRef::dot_op).
+#[cfg(feature = "std")]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Transpose
+{
+    /// The matrix as it is.
+    NoTrans,
+    /// The transpose of the matrix.
+    Trans,
+    /// The conjugate transpose of the matrix, like `a.t().mapv(|x| x.conj())`.
+    ConjTrans,
+}
+
+#[cfg(feature = "std")]
+impl Transpose
+{
+    /// Return the transposed view of `a`, and whether its elements need to be
+    /// conjugated.
+    fn apply<A>(self, a: &ArrayRef2<A>) -> (ArrayView2<'_, A>, bool)
+    {
+        match self {
+            Transpose::NoTrans => (a.view(), false),
+            Transpose::Trans => (a.t(), false),
+            Transpose::ConjTrans => (a.t(), true),
+        }
+    }
+}
+
+/// General matrix-matrix multiplication with transposed operands.
+///
+/// Compute C ← α op(A) op(B) + β C, where op is the transpose or conjugate
+/// transpose of the operand as given by `trans_a` and `trans_b`.
+///
+/// The array shapes must agree in the way that
+/// if op(`a`) is *M* × *N*, then op(`b`) is *N* × *K* and `c` is *M* × *K*.
+///
+/// ***Panics*** if array shapes are not compatible<br>
+/// *Note:* If enabled, uses blas `gemm` when memory layout allows, like
+/// [`general_mat_mul`]. Blas conjugates an operand without copying it when
+/// the operand is stored transposed relative to the memory order of `c`.
+/// Otherwise, when just one of the operands is conjugated, it is copied.
+///
+/// ```
+/// use ndarray::linalg::{general_mat_mul_op, Transpose};
+/// use ndarray::{arr2, Array2};
+/// use num_complex::Complex64;
+///
+/// let i = Complex64::i();
+/// let a = arr2(&[[1. + i, 2. * i],
+///                [0. * i, 1. - i]]);
+/// let mut c = Array2::zeros((2, 2));
+///
+/// // c ← aᴴ a
+/// general_mat_mul_op(1. + 0. * i, &a, Transpose::ConjTrans, &a, Transpose::NoTrans, 0. * i, &mut c);
+/// assert_eq!(c, a.t().mapv(|x| x.conj()).dot(&a));
+/// ```
+#[cfg(feature = "std")]
+#[track_caller]
+pub fn general_mat_mul_op<A>(
+    alpha: A, a: &ArrayRef2<A>, trans_a: Transpose, b: &ArrayRef2<A>, trans_b: Transpose, beta: A, c: &mut ArrayRef2<A>,
+) where A: LinalgField
+{
+    let (a, conj_a) = trans_a.apply(a);
+    let (b, conj_b) = trans_b.apply(b);
+    let ((m, k), (k2, n)) = (a.dim(), b.dim());
+    let (m2, n2) = c.dim();
+    if k != k2 || m != m2 || n != n2 {
+        general_dot_shape_error(m, k, k2, n, m2, n2);
+    } else {
+        mat_mul_conj_impl(alpha, &a, conj_a, &b, conj_b, beta, c);
+    }
+}
+
+/// General matrix-vector multiplication with a transposed matrix.
+///
+/// Compute y ← α op(A) x + β y, where op is the transpose or conjugate
+/// transpose of A as given by `trans`. The matrix is not copied to
+/// transpose or conjugate it.
+///
+/// The array shapes must agree in the way that if op(`a`) is *M* × *N*,
+/// then `x` has *N* elements and `y` has *M* elements.
+///
+/// ***Panics*** if array shapes are not compatible<br>
+/// *Note:* If enabled, uses blas `gemv` for elements of `f32, f64, c32, c64`
+/// when memory layout allows.
+#[cfg(feature = "std")]
+#[track_caller]
+pub fn general_mat_vec_mul_op<A>(
+    alpha: A, a: &ArrayRef2<A>, trans: Transpose, x: &ArrayRef1<A>, beta: A, y: &mut ArrayRef1<A>,
+) where A: LinalgField
+{
+    let (a, conj) = trans.apply(a);
+    unsafe { general_mat_vec_mul_impl(alpha, &a, conj, x, beta, y.raw_view_mut()) }
+}
+
+/// Batched general matrix-matrix multiplication.
+///
+/// Compute C ← α A B + β C for each matrix in a batch, where the matrices are
+/// the last two axes of the arrays and any leading axes are batch axes.
+///
+/// The array shapes must agree in the way that if the matrices of `a` are
+/// *M* × *N*, then those of `b` are *N* × *K* and those of `c` are
+/// *M* × *K*. The batch axes of `a` and `b` are broadcast to the batch axes of
+/// `c`, with the same rules as [`.broadcast()`](ArrayRef::broadcast).
+///
+/// ```
+/// use ndarray::array;
+/// use ndarray::linalg::general_batched_mat_mul;
+///
+/// let a = array![[[1., 2.]], [[3., 4.]]];     // 2 × (1 × 2)
+/// let b = array![[1., 0., 1.], [0., 1., 1.]]; // 2 × 3, for every matrix of `a`
+/// let mut c = array![[[1., 1., 1.]], [[0., 0., 0.]]];
+/// general_batched_mat_mul(1., &a, &b, 2., &mut c);
+/// assert_eq!(c, array![[[3., 4., 5.]], [[3., 4., 7.]]]);
+/// ```
+///
+/// ***Panics*** if any of the arrays has fewer than two axes, if the matrix
+/// shapes are not compatible, or if the batch axes of `a` or `b` cannot be
+/// broadcast to those of `c`.<br>
+/// *Note:* Each matrix multiplication is computed like
+/// [`general_mat_mul`], which uses blas `gemm` when enabled. When `b` is the
+/// same matrix for the whole batch and the matrices of `a` and `c` are stacked
+/// row after row in memory, the batch is computed as one matrix
+/// multiplication.
+#[track_caller]
+pub fn general_batched_mat_mul<A, D, E, F>(
+    alpha: A, a: &ArrayRef<A, D>, b: &ArrayRef<A, E>, beta: A, c: &mut ArrayRef<A, F>,
+) where
+    A: LinalgScalar,
+    D: Dimension,
+    E: Dimension,
+    F: Dimension,
+{
+    let (na, nb, nc) = (a.ndim(), b.ndim(), c.ndim());
+    assert!(
+        na >= 2 && nb >= 2 && nc >= 2,
+        "ndarray: batched matrix multiplication needs arrays with at least two axes, got {}, {} and {}",
+        na,
+        nb,
+        nc
+    );
+    let (m, k) = (a.shape()[na - 2], a.shape()[na - 1]);
+    let (k2, n) = (b.shape()[nb - 2], b.shape()[nb - 1]);
+    let (m2, n2) = (c.shape()[nc - 2], c.shape()[nc - 1]);
+    if k != k2 || m != m2 || n != n2 {
+        general_dot_shape_error(m, k, k2, n, m2, n2);
+    }
+    let n_batch = nc - 2;
+    let broadcast_matrices = |shape: &[usize], rows: usize, cols: usize| {
+        let mut shape = shape.to_vec();
+        shape.extend([rows, cols]);
+        IxDyn(&shape)
+    };
+    let batch = &c.shape()[..n_batch];
+    let a = match a.broadcast(broadcast_matrices(batch, m, k)) {
+        Some(a) => a,
+        None => batch_shape_error(&a.shape()[..na - 2], batch),
+    };
+    let b = match b.broadcast(broadcast_matrices(batch, k, n)) {
+        Some(b) => b,
+        None => batch_shape_error(&b.shape()[..nb - 2], batch),
+    };
+    let mut c = c.view_mut().into_dyn();
+    if c.is_empty() {
+        return;
+    }
+
+    // If `b` is the same for all the batch and the batch axes of `a` and `c`
+    // merge into their rows, compute one (M * batch size) × N × K product.
+    if n_batch > 0 && (0..n_batch).all(|i| b.strides()[i] == 0 || b.len_of(Axis(i)) == 1) {
+        let (mut a_rows, mut c_rows) = (a.clone(), c.view_mut());
+        if (0..n_batch)
+            .rev()
+            .all(|i| a_rows.merge_axes(Axis(i), Axis(n_batch)) && c_rows.merge_axes(Axis(i), Axis(n_batch)))
+        {
+            let mut b = b.clone();
+            for _ in 0..n_batch {
+                a_rows.index_axis_inplace(Axis(0), 0);
+                b.index_axis_inplace(Axis(0), 0);
+                c_rows.index_axis_inplace(Axis(0), 0);
+            }
+            let a = a_rows.into_dimensionality::<Ix2>().unwrap();
+            let b = b.into_dimensionality::<Ix2>().unwrap();
+            let mut c = c_rows.into_dimensionality::<Ix2>().unwrap();
+            mat_mul_impl(alpha, &a, &b, beta, &mut c);
+            return;
+        }
+    }
+    batched_mat_mul_impl(alpha, a, b, beta, c);
+}
+
+/// C ← α A B + β C for each matrix of `c`, where all arrays have the same
+/// batch axes.
+fn batched_mat_mul_impl<A>(alpha: A, a: ArrayViewD<'_, A>, b: ArrayViewD<'_, A>, beta: A, mut c: ArrayViewMutD<'_, A>)
+where A: LinalgScalar
+{
+    if c.ndim() == 2 {
+        let a = a.into_dimensionality::<Ix2>().unwrap();
+        let b = b.into_dimensionality::<Ix2>().unwrap();
+        let mut c = c.into_dimensionality::<Ix2>().unwrap();
+        mat_mul_impl(alpha, &a, &b, beta, &mut c);
+    } else {
+        for ((a, b), c) in a.outer_iter().zip(b.outer_iter()).zip(c.outer_iter_mut()) {
+            batched_mat_mul_impl(alpha, a, b, beta, c);
+        }
+    }
+}
+
+#[cold]
+#[inline(never)]
+fn batch_shape_error(shape: &[usize], batch: &[usize]) -> !
+{
+    panic!("ndarray: batch shape {:?} can not be broadcast to {:?}", shape, batch);
 }
 
 /// General matrix-vector multiplication
@@ -670,7 +1135,7 @@ where A: LinalgScalar
 /// the destination may be uninitialized iff beta is zero.
 #[allow(clippy::collapsible_else_if)]
 unsafe fn general_mat_vec_mul_impl<A>(
-    alpha: A, a: &ArrayRef2<A>, x: &ArrayRef1<A>, beta: A, y: RawArrayViewMut<A, Ix1>,
+    alpha: A, a: &ArrayRef2<A>, conj_a: bool, x: &ArrayRef1<A>, beta: A, y: RawArrayViewMut<A, Ix1>,
 ) where A: LinalgScalar
 {
     let ((m, k), k2) = (a.dim(), x.dim());
@@ -678,18 +1143,21 @@ unsafe fn general_mat_vec_mul_impl<A>(
     if k != k2 || m != m2 {
         general_dot_shape_error(m, k, k2, 1, m2, 1);
     } else {
+        let conj_a = conj_a && (same_type::<A, c32>() || same_type::<A, c64>());
+
         #[cfg(feature = "blas")]
         macro_rules! gemv {
-            ($ty:ty, $gemv:ident) => {
+            ($ty:tt, $gemv:ident) => {
                 if same_type::<A, $ty>() {
+                    // BLAS only conjugates together with the transpose, so a
+                    // conjugated `a` is passed as the transpose of `a.t()`.
+                    let (a, a_trans) = if conj_a { (a.t(), CblasConjTrans) } else { (a.view(), CblasNoTrans) };
                     if let Some(layout) = get_blas_compatible_layout(&a) {
                         if blas_compat_1d::<$ty, _>(&x) && blas_compat_1d::<$ty, _>(&y.as_ref()) {
                             // Determine stride between rows or columns. Note that the stride is
                             // adjusted to at least `k` or `m` to handle the case of a matrix with a
                             // trivial (length 1) dimension, since the stride for the trivial dimension
                             // may be arbitrary.
-                            let a_trans = CblasNoTrans;
-
                             let a_stride = blas_stride(&a, layout);
                             let cblas_layout = layout.to_cblas_layout();
 
@@ -705,15 +1173,15 @@ unsafe fn general_mat_vec_mul_impl<A>(
                             blas_sys::$gemv(
                                 cblas_layout,
                                 a_trans,
-                                m as blas_index,            // m, rows of Op(a)
-                                k as blas_index,            // n, cols of Op(a)
-                                cast_as(&alpha),            // alpha
-                                a._ptr().as_ptr() as *const _, // a
-                                a_stride,                   // lda
-                                x_ptr as *const _,          // x
+                                a.nrows() as blas_index,       // m, rows of a
+                                a.ncols() as blas_index,       // n, cols of a
+                                blas_scalar_cast!($ty, alpha), // alpha
+                                a.as_ptr() as *const _,        // a
+                                a_stride,                      // lda
+                                x_ptr as *const _,             // x
                                 x_stride,
-                                cast_as(&beta),             // beta
-                                y_ptr as *mut _,            // y
+                                blas_scalar_cast!($ty, beta),  // beta
+                                y_ptr as *mut _,               // y
                                 y_stride,
                             );
                             return;
@@ -726,22 +1194,125 @@ unsafe fn general_mat_vec_mul_impl<A>(
         gemv!(f32, cblas_sgemv);
         #[cfg(feature = "blas")]
         gemv!(f64, cblas_dgemv);
+        #[cfg(feature = "blas")]
+        gemv!(c32, cblas_cgemv);
+        #[cfg(feature = "blas")]
+        gemv!(c64, cblas_zgemv);
 
         /* general */
 
+        let row_dot = |row: ArrayView1<'_, A>| {
+            if conj_a {
+                Zip::from(row)
+                    .and(x)
+                    .fold(A::zero(), |acc, &a, &x| acc + conj_scalar(a) * x)
+            } else {
+                row.dot(x)
+            }
+        };
         if beta.is_zero() {
             // when beta is zero, c may be uninitialized
             Zip::from(a.outer_iter()).and(y).for_each(|row, elt| {
-                elt.write(row.dot(x) * alpha);
+                elt.write(row_dot(row) * alpha);
             });
         } else {
             Zip::from(a.outer_iter()).and(y).for_each(|row, elt| {
-                *elt = *elt * beta + row.dot(x) * alpha;
+                *elt = *elt * beta + row_dot(row) * alpha;
             });
         }
     }
 }
 
+/// Side of the blocks of the triangle that the pure Rust rank-k update
+/// computes at a time.
+const RANK_K_BLOCK: usize = 64;
+
+/// Symmetric rank-k update: C ← α A Aᵀ + β C, where only the upper or lower
+/// triangle of the square matrix `c` is referenced.
+///
+/// With `hermitian`, compute the Hermitian rank-k update C ← α A Aᴴ + β C
+/// instead, where α and β are real. Like for [`mat_mul_conj_impl`], it may
+/// only be set for elements of `c32, c64` and real types.
+///
+/// The pure Rust version computes the triangle in blocks with
+/// [`general_mat_mul`], so it also updates the other triangle of the
+/// diagonal blocks.
+#[cfg_attr(not(feature = "blas"), allow(unused_variables))]
+fn rank_k_update<A>(alpha: A, a: &ArrayRef2<A>, beta: A, c: &mut ArrayRef2<A>, upper: bool, hermitian: bool)
+where A: LinalgScalar
+{
+    #[cfg(feature = "blas")]
+    if blas_rank_k_update(alpha, a, beta, c, upper, hermitian) {
+        return;
+    }
+    let n = c.nrows();
+    for start in (0..n).step_by(RANK_K_BLOCK) {
+        let end = n.min(start + RANK_K_BLOCK);
+        let cols = if upper { start..n } else { 0..end };
+        mat_mul_conj_impl(
+            alpha,
+            &a.slice(s![start..end, ..]),
+            false,
+            &a.t().slice(s![.., cols.clone()]),
+            hermitian,
+            beta,
+            &mut c.slice_mut(s![start..end, cols]),
+        );
+    }
+}
+
+/// Hermitian (for real elements, symmetric) rank-k update:
+/// C ← α A Aᴴ + β C, where only the `triangle` of the square matrix `c` is
+/// read. The other triangle may be overwritten.
+#[cfg(feature = "std")]
+pub(crate) fn hermitian_rank_k_update<A>(
+    alpha: A::Real, a: &ArrayRef2<A>, beta: A::Real, c: &mut ArrayRef2<A>, triangle: Triangle,
+) where A: LinalgField
+{
+    let upper = triangle == Triangle::Upper;
+    rank_k_update(A::from_real(alpha), a, A::from_real(beta), c, upper, true);
+}
+
+/// Rank-1 update: A ← A + α x yᵀ.
+#[cfg(feature = "std")]
+pub(crate) fn rank1_update<A>(alpha: A, x: &ArrayRef1<A>, y: &ArrayRef1<A>, a: &mut ArrayRef2<A>)
+where A: LinalgScalar
+{
+    #[cfg(feature = "blas")]
+    if blas_ger(alpha, x, y, a) {
+        return;
+    }
+    Zip::from(a.rows_mut())
+        .and(x)
+        .for_each(|mut row, &x| row.scaled_add(alpha * x, y));
+}
+
+/// Triangular matrix-vector multiplication: x ← A x, where `A` is the
+/// `triangle` of the square matrix `a`.
+#[cfg(feature = "std")]
+pub(crate) fn triangular_mat_vec_mul<A>(a: &ArrayRef2<A>, x: &mut ArrayRef1<A>, triangle: Triangle)
+where A: LinalgField
+{
+    #[cfg(feature = "blas")]
+    if blas_trmv(a, x, triangle) {
+        return;
+    }
+    let n = x.len();
+    // xᵢ depends on xⱼ for j ≥ i (upper) or j ≤ i (lower): update the
+    // elements in the order that reads each xⱼ before it is overwritten
+    let rows = match triangle {
+        Triangle::Upper => (0..n).collect::<Vec<_>>(),
+        Triangle::Lower => (0..n).rev().collect(),
+    };
+    for i in rows {
+        let cols = match triangle {
+            Triangle::Upper => i..n,
+            Triangle::Lower => 0..i + 1,
+        };
+        x[i] = a.slice(s![i, cols.clone()]).dot(&x.slice(s![cols]));
+    }
+}
+
 /// Kronecker product of 2D matrices.
 ///
 /// The kronecker product of a LxN matrix A and a MxR matrix B is a (L*M)x(N*R)
@@ -771,9 +1342,159 @@ where A: LinalgScalar
     unsafe { out.assume_init() }
 }
 
+/// Tensor dot product of arrays of any dimensionality.
+///
+/// Sum the products of the elements of `a` and `b` over each pair of axes
+/// `axes_a[i]` of `a` and `axes_b[i]` of `b`. The result has the other axes of
+/// `a` followed by the other axes of `b`, each in their original order.
+///
+/// With one pair of axes, the last axis of a matrix `a` and the first of a
+/// matrix `b`, this is the matrix product; with no axes it is the outer
+/// product, see [`outer`].
+///
+/// ```
+/// use ndarray::{Array, Axis};
+/// use ndarray::linalg::tensordot;
+///
+/// let a = Array::from_shape_fn((3, 4, 5), |(i, j, k)| (i + j * k) as f64);
+/// let b = Array::from_shape_fn((4, 3, 2), |(i, j, k)| (i * j + k) as f64);
+/// let c = tensordot(&a, &b, &[Axis(1), Axis(0)], &[Axis(0), Axis(1)]);
+/// assert_eq!(c.shape(), &[5, 2]);
+/// assert_eq!(c[[4, 1]], (0..3).flat_map(|i| (0..4).map(move |j| (i, j)))
+///                             .map(|(i, j)| a[[i, j, 4]] * b[[j, i, 1]])
+///                             .sum::<f64>());
+/// ```
+///
+/// ***Panics*** if `axes_a` and `axes_b` do not have the same length, if an
+/// axis is out of bounds or repeated, or if paired axes have different
+/// lengths.<br>
+/// *Note:* When the contracted and the remaining axes of `a` and `b` can be
+/// merged into matrices without copying, the product is computed with
+/// [`general_mat_mul`]; otherwise it is accumulated with [`Zip`].
+#[track_caller]
+pub fn tensordot<A, D, E>(a: &ArrayRef<A, D>, b: &ArrayRef<A, E>, axes_a: &[Axis], axes_b: &[Axis]) -> ArrayD<A>
+where
+    A: LinalgScalar,
+    D: Dimension,
+    E: Dimension,
+{
+    assert_eq!(
+        axes_a.len(),
+        axes_b.len(),
+        "ndarray: tensordot needs the same number of axes for both arrays"
+    );
+    let free_a = remaining_axes(a.ndim(), axes_a);
+    let free_b = remaining_axes(b.ndim(), axes_b);
+    for (&i, &j) in axes_a.iter().zip(axes_b) {
+        let (len_a, len_b) = (a.len_of(i), b.len_of(j));
+        assert!(
+            len_a == len_b,
+            "ndarray: tensordot axes {} and {} have different lengths {} and {}",
+            i.index(),
+            j.index(),
+            len_a,
+            len_b
+        );
+    }
+    let m = free_a.iter().map(|&i| a.len_of(Axis(i))).product::<usize>();
+    let n = free_b.iter().map(|&i| b.len_of(Axis(i))).product::<usize>();
+    let contracted = axes_a.iter().map(|&i| a.len_of(i)).collect::<Vec<_>>();
+    let k = contracted.iter().product::<usize>();
+    let shape = free_a
+        .iter()
+        .map(|&i| a.len_of(Axis(i)))
+        .chain(free_b.iter().map(|&i| b.len_of(Axis(i))))
+        .collect::<Vec<_>>();
+
+    // a as free × contracted axes, b as contracted × free axes
+    let a_perm = free_a
+        .iter()
+        .copied()
+        .chain(axes_a.iter().map(|ax| ax.index()))
+        .collect::<Vec<_>>();
+    let b_perm = axes_b
+        .iter()
+        .map(|ax| ax.index())
+        .chain(free_b.iter().copied())
+        .collect::<Vec<_>>();
+    let a = a.view().into_dyn().permuted_axes(a_perm);
+    let b = b.view().into_dyn().permuted_axes(b_perm);
+    let mut c = ArrayD::zeros(shape);
+    if let (Ok(a2), Ok(b2)) = (a.clone().into_shape_with_order((m, k)), b.clone().into_shape_with_order((k, n))) {
+        let mut c2 = c.view_mut().into_shape_with_order((m, n)).unwrap();
+        general_mat_mul(A::one(), &a2, &b2, A::zero(), &mut c2);
+        return c;
+    }
+
+    // Add up the outer products of the subviews of `a` and `b` for each index
+    // of the contracted axes.
+    let (n_free_a, n_free_b) = (free_a.len(), free_b.len());
+    for index in crate::indices(contracted) {
+        let mut a_sub = a.clone();
+        let mut b_sub = b.clone();
+        for &i in index.slice() {
+            a_sub.index_axis_inplace(Axis(n_free_a), i);
+            b_sub.index_axis_inplace(Axis(0), i);
+        }
+        for _ in 0..n_free_b {
+            a_sub.insert_axis_inplace(Axis(n_free_a));
+        }
+        for _ in 0..n_free_a {
+            b_sub.insert_axis_inplace(Axis(0));
+        }
+        Zip::from(&mut c)
+            .and_broadcast(&a_sub)
+            .and_broadcast(&b_sub)
+            .for_each(|c, &x, &y| *c = *c + x * y);
+    }
+    c
+}
+
+/// Outer product of arrays of any dimensionality.
+///
+/// The result has the shape of `a` followed by the shape of `b`, and the
+/// element at `[i.., j..]` is `a[i..] * b[j..]`; see [`tensordot`].
+///
+/// ```
+/// use ndarray::array;
+/// use ndarray::linalg::outer;
+///
+/// let a = array![1, 2];
+/// let b = array![[1, 0], [0, 3]];
+/// let c = outer(&a, &b);
+/// assert_eq!(c.shape(), &[2, 2, 2]);
+/// assert_eq!(c, array![[[1, 0], [0, 3]], [[2, 0], [0, 6]]].into_dyn());
+/// ```
+pub fn outer<A, D, E>(a: &ArrayRef<A, D>, b: &ArrayRef<A, E>) -> ArrayD<A>
+where
+    A: LinalgScalar,
+    D: Dimension,
+    E: Dimension,
+{
+    tensordot(a, b, &[], &[])
+}
+
+/// Return the axes of an array with `ndim` axes that are not in `axes`.
+#[track_caller]
+fn remaining_axes(ndim: usize, axes: &[Axis]) -> Vec<usize>
+{
+    let mut used = vec![false; ndim];
+    for &axis in axes {
+        assert!(
+            axis.index() < ndim,
+            "ndarray: axis {} is out of bounds for array of dimension {}",
+            axis.index(),
+            ndim
+        );
+        assert!(!used[axis.index()], "ndarray: axis {} is repeated", axis.index());
+        used[axis.index()] = true;
+    }
+    (0..ndim).filter(|&i| !used[i]).collect()
+}
+
 #[inline(always)]
 /// Return `true` if `A` and `B` are the same type
-fn same_type<A: 'static, B: 'static>() -> bool
+pub(super) fn same_type<A: 'static, B: 'static>() -> bool
 {
     TypeId::of::<A>() == TypeId::of::<B>()
 }
@@ -781,13 +1502,26 @@ fn same_type<A: 'static, B: 'static>() -> bool
 // Read pointer to type `A` as type `B`.
 //
 // **Panics** if `A` and `B` are not the same type
-fn cast_as<A: 'static + Copy, B: 'static + Copy>(a: &A) -> B
+pub(super) fn cast_as<A: 'static + Copy, B: 'static + Copy>(a: &A) -> B
 {
     assert!(same_type::<A, B>(), "expect type {} and {} to match",
             std::any::type_name::<A>(), std::any::type_name::<B>());
     unsafe { ::std::ptr::read(a as *const _ as *const B) }
 }
 
+/// Return the complex conjugate of elements of `c32, c64`, and other
+/// elements unchanged.
+fn conj_scalar<A: 'static + Copy>(a: A) -> A
+{
+    if same_type::<A, c32>() {
+        cast_as(&cast_as::<A, c32>(&a).conj())
+    } else if same_type::<A, c64>() {
+        cast_as(&cast_as::<A, c64>(&a).conj())
+    } else {
+        a
+    }
+}
+
 /// Return the complex in the form of an array [re, im]
 #[inline]
 fn complex_array<A: 'static + Copy>(z: Complex<A>) -> [A; 2]
@@ -942,6 +1676,518 @@ fn blas_stride<A>(a: &ArrayRef<A, Ix2>, order: BlasOrder) -> blas_index
     }) as blas_index
 }
 
+/// The lanes of a vector or a matrix, as passed to level 1 BLAS functions:
+/// the vector itself, or the rows or columns of the matrix.
+#[cfg(feature = "blas")]
+#[derive(Copy, Clone)]
+struct BlasLanes
+{
+    /// Number of lanes
+    count: usize,
+    /// Distance between the first elements of consecutive lanes
+    step: isize,
+    len: usize,
+    stride: isize,
+}
+
+#[cfg(feature = "blas")]
+impl BlasLanes
+{
+    /// Return the lanes of `a` with its BLAS compatible layout: the rows of
+    /// a matrix with C layout, or the columns of one with F layout.
+    ///
+    /// Return `None` if the array is too small to be worth it, or if it has a
+    /// layout that BLAS can't use.
+    fn of<A, D>(a: &ArrayRef<A, D>) -> Option<Self>
+    where
+        A: 'static,
+        D: Dimension,
+    {
+        if a.len() < DOT_BLAS_CUTOFF {
+            return None;
+        }
+        Self::along(a, blas_lane_axis(a)?)
+    }
+
+    /// Return the lanes of a vector, or of a matrix indexed along `outer`:
+    /// `Axis(0)` for its rows, `Axis(1)` for its columns.
+    fn along<A, D>(a: &ArrayRef<A, D>, outer: Axis) -> Option<Self>
+    where
+        A: 'static,
+        D: Dimension,
+    {
+        let lanes = match a.ndim() {
+            1 => BlasLanes {
+                count: 1,
+                step: 0,
+                len: a.len(),
+                stride: a.strides()[0],
+            },
+            2 => {
+                let inner = 1 - outer.index();
+                BlasLanes {
+                    count: a.len_of(outer),
+                    step: a.stride_of(outer),
+                    len: a.len_of(Axis(inner)),
+                    stride: a.stride_of(Axis(inner)),
+                }
+            }
+            _ => return None,
+        };
+        // all lanes have the same length and stride
+        let compatible = match a.ndim() {
+            1 => blas_compat_1d::<A, A>(&a.view().into_dimensionality::<Ix1>().unwrap()),
+            _ if lanes.count == 0 => true,
+            _ => blas_compat_1d::<A, A>(
+                &a.view()
+                    .into_dimensionality::<Ix2>()
+                    .unwrap()
+                    .index_axis_move(outer, 0),
+            ),
+        };
+        if compatible {
+            Some(lanes)
+        } else {
+            None
+        }
+    }
+
+    /// Call `f` with the BLAS pointer, length and increment of each lane,
+    /// where `ptr` is the pointer to the first element of the array.
+    unsafe fn for_each<A>(self, ptr: *const A, mut f: impl FnMut(*const A, blas_index, blas_index))
+    {
+        for i in 0..self.count {
+            let (ptr, n, inc) = blas_1d_params(ptr.offset(i as isize * self.step), self.len, self.stride);
+            f(ptr, n, inc);
+        }
+    }
+}
+
+/// Return the axis to index to split a matrix with a BLAS compatible layout
+/// into contiguous lanes: `Axis(0)` (rows) for C layout, `Axis(1)` (columns)
+/// for F layout. A vector is a single lane.
+#[cfg(feature = "blas")]
+fn blas_lane_axis<A, D>(a: &ArrayRef<A, D>) -> Option<Axis>
+where D: Dimension
+{
+    match a.ndim() {
+        1 => Some(Axis(0)),
+        2 => match get_blas_compatible_layout(&a.view().into_dimensionality::<Ix2>().unwrap())? {
+            BlasOrder::C => Some(Axis(0)),
+            BlasOrder::F => Some(Axis(1)),
+        },
+        _ => None,
+    }
+}
+
+/// Compute the Euclidean norm of a vector or a matrix with BLAS `nrm2`.
+///
+/// Return `None` if BLAS can't be used for the element type or the layout.
+#[cfg(all(feature = "blas", feature = "std"))]
+pub(crate) fn blas_nrm2<A, D>(a: &ArrayRef<A, D>) -> Option<A::Real>
+where
+    A: crate::LinalgField,
+    D: Dimension,
+{
+    macro_rules! nrm2 {
+        ($ty:ty, $real:ty, $func:ident) => {{
+            if same_type::<A, $ty>() {
+                let mut norm: $real = 0.;
+                unsafe {
+                    BlasLanes::of(a)?.for_each(a.as_ptr(), |ptr, n, inc| {
+                        norm = norm.hypot(blas_sys::$func(n, ptr as *const _, inc));
+                    });
+                }
+                return Some(cast_as::<$real, A::Real>(&norm));
+            }
+        }};
+    }
+
+    nrm2! {f32, f32, cblas_snrm2};
+    nrm2! {f64, f64, cblas_dnrm2};
+    nrm2! {c32, f32, cblas_scnrm2};
+    nrm2! {c64, f64, cblas_dznrm2};
+    None
+}
+
+/// Compute the sum of the absolute values of a real vector or matrix with
+/// BLAS `asum`.
+///
+/// Return `None` if BLAS can't be used for the element type or the layout;
+/// for complex elements, `asum` sums `|re| + |im|` rather than the moduli.
+#[cfg(all(feature = "blas", feature = "std"))]
+pub(crate) fn blas_asum<A, D>(a: &ArrayRef<A, D>) -> Option<A::Real>
+where
+    A: crate::LinalgField,
+    D: Dimension,
+{
+    macro_rules! asum {
+        ($ty:ty, $func:ident) => {{
+            if same_type::<A, $ty>() {
+                let mut sum: $ty = 0.;
+                unsafe {
+                    BlasLanes::of(a)?.for_each(a.as_ptr(), |ptr, n, inc| {
+                        sum += blas_sys::$func(n, ptr as *const $ty, inc);
+                    });
+                }
+                return Some(cast_as::<$ty, A::Real>(&sum));
+            }
+        }};
+    }
+
+    asum! {f32, cblas_sasum};
+    asum! {f64, cblas_dasum};
+    None
+}
+
+/// Compute `y ← α x + y` with BLAS `axpy`, for a vector or matrix `y` and
+/// `x` of the same shape.
+///
+/// Return `false` if BLAS can't be used for the element type, the shapes or
+/// the layouts.
+#[cfg(feature = "blas")]
+fn blas_axpy<A, D, E>(alpha: A, x: &ArrayRef<A, E>, y: &mut ArrayRef<A, D>) -> bool
+where
+    A: LinalgScalar,
+    D: Dimension,
+    E: Dimension,
+{
+    if x.shape() != y.shape() {
+        return false;
+    }
+    let y_lanes = match BlasLanes::of(y) {
+        Some(lanes) => lanes,
+        None => return false,
+    };
+    // the lanes of y decide the axis; any nonzero stride works for x
+    let x_lanes = match BlasLanes::along(x, blas_lane_axis(y).unwrap()) {
+        Some(lanes) => lanes,
+        None => return false,
+    };
+
+    macro_rules! axpy {
+        ($ty:tt, $axpy:ident) => {
+            if same_type::<A, $ty>() {
+                let y_ptr = y.as_mut_ptr();
+                unsafe {
+                    let mut x_params = Vec::with_capacity(x_lanes.count);
+                    x_lanes.for_each(x.as_ptr(), |ptr, _, inc| x_params.push((ptr, inc)));
+                    let mut x_params = x_params.into_iter();
+                    y_lanes.for_each(y_ptr, |ptr, n, incy| {
+                        let (x_ptr, incx) = x_params.next().unwrap();
+                        blas_sys::$axpy(
+                            n,
+                            blas_scalar_cast!($ty, alpha),
+                            x_ptr as *const _,
+                            incx,
+                            ptr as *mut _,
+                            incy,
+                        );
+                    });
+                }
+                return true;
+            }
+        };
+    }
+
+    axpy!(f32, cblas_saxpy);
+    axpy!(f64, cblas_daxpy);
+    axpy!(c32, cblas_caxpy);
+    axpy!(c64, cblas_zaxpy);
+    false
+}
+
+/// Compute `x ← α x` with BLAS `scal`, for a vector or matrix `x`.
+///
+/// Return `false` if BLAS can't be used for the element type or the layout.
+/// BLAS is not used for `α = 0`, since some implementations then set `x` to
+/// zero without multiplying, which would lose NaNs.
+#[cfg(feature = "blas")]
+pub(crate) fn blas_scal<A, D>(x: &mut ArrayRef<A, D>, alpha: &A) -> bool
+where
+    A: 'static,
+    D: Dimension,
+{
+    let lanes = match BlasLanes::of(x) {
+        Some(lanes) => lanes,
+        None => return false,
+    };
+
+    macro_rules! scal {
+        ($ty:tt, $scal:ident) => {
+            if same_type::<A, $ty>() {
+                let alpha: $ty = unsafe { *(alpha as *const A as *const $ty) };
+                if alpha.is_zero() {
+                    return false;
+                }
+                unsafe {
+                    lanes.for_each(x.as_mut_ptr(), |ptr, n, inc| {
+                        blas_sys::$scal(n, blas_scalar_cast!($ty, alpha), ptr as *mut _, inc);
+                    });
+                }
+                return true;
+            }
+        };
+    }
+
+    scal!(f32, cblas_sscal);
+    scal!(f64, cblas_dscal);
+    scal!(c32, cblas_cscal);
+    scal!(c64, cblas_zscal);
+    false
+}
+
+/// Compute C ← α A Aᵀ + β C (or C ← α A Aᴴ + β C with `hermitian`) with BLAS
+/// `syrk` (or `herk`), see `rank_k_update`.
+///
+/// Return `false` if BLAS can't be used for the element type or the
+/// layouts.
+#[cfg(feature = "blas")]
+fn blas_rank_k_update<A>(
+    alpha: A, a: &ArrayRef2<A>, beta: A, c: &mut ArrayRef2<A>, upper: bool, hermitian: bool,
+) -> bool
+where A: LinalgScalar
+{
+    let (n, k) = a.dim();
+    if !(n > GEMM_BLAS_CUTOFF || k > GEMM_BLAS_CUTOFF) {
+        return false;
+    }
+    let (a_layout, c_layout) = match (get_blas_compatible_layout(a), get_blas_compatible_layout(c)) {
+        (Some(a_layout), Some(c_layout)) => (a_layout, c_layout),
+        _ => return false,
+    };
+    let cblas_layout = c_layout.to_cblas_layout();
+    let a_trans = a_layout.to_cblas_transpose_for(cblas_layout);
+    let lda = blas_stride(a, a_layout);
+    let ldc = blas_stride(c, c_layout);
+    let uplo = if upper { CblasUpper } else { CblasLower };
+
+    macro_rules! syrk {
+        ($ty:tt, $syrk:ident) => {
+            if same_type::<A, $ty>() {
+                unsafe {
+                    blas_sys::$syrk(
+                        cblas_layout,
+                        uplo,
+                        a_trans,
+                        n as blas_index,
+                        k as blas_index,
+                        blas_scalar_cast!($ty, alpha),
+                        a.as_ptr() as *const _,
+                        lda,
+                        blas_scalar_cast!($ty, beta),
+                        c.as_mut_ptr() as *mut _,
+                        ldc,
+                    );
+                }
+                return true;
+            }
+        };
+    }
+
+    macro_rules! herk {
+        ($ty:tt, $herk:ident) => {
+            if same_type::<A, $ty>() {
+                // A Aᴴ of A stored transposed is not a Hermitian rank-k
+                // update of the stored matrix
+                if !matches!(a_trans, CblasNoTrans) {
+                    return false;
+                }
+                let (alpha, beta) = (cast_as::<A, $ty>(&alpha), cast_as::<A, $ty>(&beta));
+                unsafe {
+                    blas_sys::$herk(
+                        cblas_layout,
+                        uplo,
+                        CblasNoTrans,
+                        n as blas_index,
+                        k as blas_index,
+                        alpha.re,
+                        a.as_ptr() as *const _,
+                        lda,
+                        beta.re,
+                        c.as_mut_ptr() as *mut _,
+                        ldc,
+                    );
+                }
+                return true;
+            }
+        };
+    }
+
+    syrk!(f32, cblas_ssyrk);
+    syrk!(f64, cblas_dsyrk);
+    if hermitian {
+        herk!(c32, cblas_cherk);
+        herk!(c64, cblas_zherk);
+    } else {
+        syrk!(c32, cblas_csyrk);
+        syrk!(c64, cblas_zsyrk);
+    }
+    false
+}
+
+/// Compute A ← A + α x yᵀ with BLAS `ger` (`geru` for complex elements).
+///
+/// Return `false` if BLAS can't be used for the element type or the
+/// layouts.
+#[cfg(all(feature = "blas", feature = "std"))]
+fn blas_ger<A>(alpha: A, x: &ArrayRef1<A>, y: &ArrayRef1<A>, a: &mut ArrayRef2<A>) -> bool
+where A: LinalgScalar
+{
+    let (m, n) = a.dim();
+    if !(m > GEMM_BLAS_CUTOFF || n > GEMM_BLAS_CUTOFF) || !blas_compat_1d::<A, A>(x) || !blas_compat_1d::<A, A>(y) {
+        return false;
+    }
+    let layout = match get_blas_compatible_layout(a) {
+        Some(layout) => layout,
+        None => return false,
+    };
+    let lda = blas_stride(a, layout);
+
+    macro_rules! ger {
+        ($ty:tt, $ger:ident) => {
+            if same_type::<A, $ty>() {
+                unsafe {
+                    let (x_ptr, _, incx) = blas_1d_params(x.as_ptr(), m, x.strides()[0]);
+                    let (y_ptr, _, incy) = blas_1d_params(y.as_ptr(), n, y.strides()[0]);
+                    blas_sys::$ger(
+                        layout.to_cblas_layout(),
+                        m as blas_index,
+                        n as blas_index,
+                        blas_scalar_cast!($ty, alpha),
+                        x_ptr as *const _,
+                        incx,
+                        y_ptr as *const _,
+                        incy,
+                        a.as_mut_ptr() as *mut _,
+                        lda,
+                    );
+                }
+                return true;
+            }
+        };
+    }
+
+    ger!(f32, cblas_sger);
+    ger!(f64, cblas_dger);
+    ger!(c32, cblas_cgeru);
+    ger!(c64, cblas_zgeru);
+    false
+}
+
+/// Replace `x` by the solution of `A x = b`, where `b` is the initial `x` and
+/// `A` the triangle of `a`, with BLAS `trsm`.
+///
+/// Return `false` if BLAS can't be used for the element type or the
+/// layouts.
+#[cfg(all(feature = "blas", feature = "std"))]
+pub(crate) fn blas_trsm<A>(a: &ArrayRef2<A>, x: &mut ArrayRef2<A>, triangle: Triangle, diagonal: Diagonal) -> bool
+where A: LinalgField
+{
+    let (n, nrhs) = x.dim();
+    if !(n > GEMM_BLAS_CUTOFF || nrhs > GEMM_BLAS_CUTOFF) {
+        return false;
+    }
+    let a = a.slice(s![.., ..n]);
+    let (a_layout, x_layout) = match (get_blas_compatible_layout(&a), get_blas_compatible_layout(x)) {
+        (Some(a_layout), Some(x_layout)) => (a_layout, x_layout),
+        _ => return false,
+    };
+    let cblas_layout = x_layout.to_cblas_layout();
+    let a_trans = a_layout.to_cblas_transpose_for(cblas_layout);
+    // a transposed matrix is stored with the other triangle
+    let upper = (triangle == Triangle::Upper) == matches!(a_trans, CblasNoTrans);
+    let uplo = if upper { CblasUpper } else { CblasLower };
+    let diag = match diagonal {
+        Diagonal::NonUnit => CblasNonUnit,
+        Diagonal::Unit => CblasUnit,
+    };
+    let lda = blas_stride(&a, a_layout);
+    let ldb = blas_stride(x, x_layout);
+    let one = A::one();
+
+    macro_rules! trsm {
+        ($ty:tt, $trsm:ident) => {
+            if same_type::<A, $ty>() {
+                unsafe {
+                    blas_sys::$trsm(
+                        cblas_layout,
+                        CblasLeft,
+                        uplo,
+                        a_trans,
+                        diag,
+                        n as blas_index,
+                        nrhs as blas_index,
+                        blas_scalar_cast!($ty, one),
+                        a.as_ptr() as *const _,
+                        lda,
+                        x.as_mut_ptr() as *mut _,
+                        ldb,
+                    );
+                }
+                return true;
+            }
+        };
+    }
+
+    trsm!(f32, cblas_strsm);
+    trsm!(f64, cblas_dtrsm);
+    trsm!(c32, cblas_ctrsm);
+    trsm!(c64, cblas_ztrsm);
+    false
+}
+
+/// Compute x ← A x, where `A` is the `triangle` of `a`, with BLAS `trmv`.
+///
+/// Return `false` if BLAS can't be used for the element type or the
+/// layouts.
+#[cfg(all(feature = "blas", feature = "std"))]
+fn blas_trmv<A>(a: &ArrayRef2<A>, x: &mut ArrayRef1<A>, triangle: Triangle) -> bool
+where A: LinalgField
+{
+    let n = x.len();
+    if n <= GEMM_BLAS_CUTOFF || !blas_compat_1d::<A, A>(x) {
+        return false;
+    }
+    let layout = match get_blas_compatible_layout(a) {
+        Some(layout) => layout,
+        None => return false,
+    };
+    let uplo = match triangle {
+        Triangle::Upper => CblasUpper,
+        Triangle::Lower => CblasLower,
+    };
+    let lda = blas_stride(a, layout);
+
+    macro_rules! trmv {
+        ($ty:tt, $trmv:ident) => {
+            if same_type::<A, $ty>() {
+                unsafe {
+                    let (x_ptr, _, incx) = blas_1d_params(x.as_mut_ptr() as *const A, n, x.strides()[0]);
+                    blas_sys::$trmv(
+                        layout.to_cblas_layout(),
+                        uplo,
+                        CblasNoTrans,
+                        CblasNonUnit,
+                        n as blas_index,
+                        a.as_ptr() as *const _,
+                        lda,
+                        x_ptr as *mut _,
+                        incx,
+                    );
+                }
+                return true;
+            }
+        };
+    }
+
+    trmv!(f32, cblas_strmv);
+    trmv!(f64, cblas_dtrmv);
+    trmv!(c32, cblas_ctrmv);
+    trmv!(c64, cblas_ztrmv);
+    false
+}
+
 #[cfg(test)]
 #[cfg(feature = "blas")]
 fn blas_row_major_2d<A, B>(a: &ArrayRef2<B>) -> bool
@@ -0,0 +1,313 @@
+// Copyright 2014-2024 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Hermitian eigendecomposition and singular value decomposition by Jacobi
+//! rotations.
+//!
+//! Jacobi methods are slower than the reduction to tridiagonal or bidiagonal
+//! form used by LAPACK, but they are simple, and they compute small
+//! eigenvalues and singular values to high relative accuracy.
+
+use num_traits::{Float, One, Zero};
+use std::cmp::Ordering;
+
+use crate::imp_prelude::*;
+use crate::linalg::factorization::{conj_t, qr, square};
+use crate::linalg::LinalgError;
+use crate::{LinalgField, Zip};
+
+/// Maximum number of sweeps over all pairs of rows or columns; Jacobi
+/// methods converge quadratically, in much fewer sweeps for finite matrices.
+const MAX_SWEEPS: usize = 60;
+
+/// Which factors of the singular value decomposition to compute, see
+/// [`svd`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SvdMode
+{
+    /// Square `U` and `Vᴴ`: *m* × *m* and *n* × *n*.
+    Full,
+    /// Thin `U` and `Vᴴ`: *m* × *k* and *k* × *n*, where *k* = min(*m*, *n*).
+    Thin,
+    /// Only the singular values.
+    ValuesOnly,
+}
+
+/// Singular value decomposition `A = U Σ Vᴴ`; see [`svd`].
+#[derive(Clone, Debug)]
+pub struct Svd<A: LinalgField>
+{
+    /// The left singular vectors, in the columns; `None` for
+    /// [`SvdMode::ValuesOnly`].
+    pub u: Option<Array2<A>>,
+    /// The singular values, the diagonal of `Σ`, in decreasing order.
+    pub s: Array1<A::Real>,
+    /// The conjugate transposed right singular vectors, in the rows; `None`
+    /// for [`SvdMode::ValuesOnly`].
+    pub vt: Option<Array2<A>>,
+}
+
+/// Compute the eigenvalues and eigenvectors of the Hermitian (for real
+/// elements, symmetric) matrix `a`.
+///
+/// Return the eigenvalues in increasing order, and the matrix with the
+/// corresponding orthonormal eigenvectors in its columns, so that
+/// `A V = V diag(w)`. Only the lower triangle of `a` is used; the elements
+/// above the diagonal are not read.
+///
+/// ```
+/// use ndarray::array;
+/// use ndarray::linalg::eigh;
+/// use approx::assert_abs_diff_eq;
+///
+/// let a = array![[2., 1.], [1., 2.]];
+/// let (w, v) = eigh(&a).unwrap();
+/// assert_abs_diff_eq!(w, array![1., 3.], epsilon = 1e-12);
+/// assert_abs_diff_eq!(a.dot(&v), &v * &w, epsilon = 1e-12);
+/// ```
+///
+/// ***Errors*** if `a` is not square ([`LinalgError::NotSquare`]), or if
+/// the iteration does not converge ([`LinalgError::NotConverged`]).
+pub fn eigh<A>(a: &ArrayRef2<A>) -> Result<(Array1<A::Real>, Array2<A>), LinalgError>
+where A: LinalgField
+{
+    let mut v = Array2::eye(square(a)?);
+    let w = jacobi_eigh(a, Some(&mut v))?;
+    Ok((w, v))
+}
+
+/// Compute the eigenvalues of the Hermitian (for real elements, symmetric)
+/// matrix `a`, in increasing order.
+///
+/// This is [`eigh`] without the eigenvectors.
+///
+/// ***Errors*** if `a` is not square ([`LinalgError::NotSquare`]), or if
+/// the iteration does not converge ([`LinalgError::NotConverged`]).
+pub fn eigvalsh<A>(a: &ArrayRef2<A>) -> Result<Array1<A::Real>, LinalgError>
+where A: LinalgField
+{
+    jacobi_eigh(a, None)
+}
+
+/// Compute the singular value decomposition `A = U Σ Vᴴ` of the matrix `a`,
+/// with the factors chosen by `mode`.
+///
+/// For an *m* × *n* matrix, `Σ` holds the min(*m*, *n*) singular values in
+/// decreasing order, and `U` and `V` have orthonormal columns.
+///
+/// ```
+/// use ndarray::{array, Array2};
+/// use ndarray::linalg::{svd, SvdMode};
+/// use approx::assert_abs_diff_eq;
+///
+/// let a = array![[3., 0.], [4., 5.], [0., 0.]];
+/// let svd = svd(&a, SvdMode::Thin).unwrap();
+/// let (u, vt) = (svd.u.unwrap(), svd.vt.unwrap());
+/// assert_abs_diff_eq!(svd.s, array![45f64.sqrt(), 5f64.sqrt()], epsilon = 1e-12);
+/// assert_eq!((u.dim(), vt.dim()), ((3, 2), (2, 2)));
+/// assert_abs_diff_eq!(u.dot(&Array2::from_diag(&svd.s)).dot(&vt), a, epsilon = 1e-12);
+/// ```
+///
+/// ***Errors*** if the iteration does not converge
+/// ([`LinalgError::NotConverged`]).
+pub fn svd<A>(a: &ArrayRef2<A>, mode: SvdMode) -> Result<Svd<A>, LinalgError>
+where A: LinalgField
+{
+    let (m, n) = a.dim();
+    if m < n {
+        // A = (Aᴴ)ᴴ = V Σ Uᴴ, from the decomposition Aᴴ = U Σ Vᴴ
+        let svd = svd(&conj_t(a), mode)?;
+        return Ok(Svd {
+            u: svd.vt.map(|vt| conj_t(&vt)),
+            s: svd.s,
+            vt: svd.u.map(|u| conj_t(&u)),
+        });
+    }
+
+    // One-sided Jacobi: rotate pairs of columns of A V until they are
+    // orthogonal; then they are U Σ. The columns are kept in the rows of `g`
+    // and the columns of V in the rows of `vr`, so that they are contiguous.
+    // The matrix is scaled to elements of at most 1 in magnitude, so that the
+    // squared column norms neither overflow nor underflow.
+    let vectors = mode != SvdMode::ValuesOnly;
+    let scale = scale_factor(a);
+    let inv = scale.recip();
+    let mut g = Array2::from_shape_fn((n, m), |(i, j)| a[[j, i]].scale(inv));
+    let mut vr = if vectors { Some(Array2::<A>::eye(n)) } else { None };
+    let eps = A::Real::epsilon();
+    for sweep in 0.. {
+        if sweep == MAX_SWEEPS {
+            return Err(LinalgError::NotConverged);
+        }
+        let mut rotated = false;
+        for p in 0..n {
+            for q in p + 1..n {
+                let (gp, gq) = g.multi_slice_mut((s![p, ..], s![q, ..]));
+                let alpha = gp.fold(A::Real::zero(), |acc, x| acc + x.abs_sqr());
+                let beta = gq.fold(A::Real::zero(), |acc, x| acc + x.abs_sqr());
+                let gamma = Zip::from(&gp)
+                    .and(&gq)
+                    .fold(A::zero(), |acc, x, &y| acc + x.conj() * y);
+                let r = gamma.abs();
+                if r <= eps * alpha.sqrt() * beta.sqrt() {
+                    continue;
+                }
+                rotated = true;
+                let (c, s, phase) = jacobi_rotation(alpha, beta, gamma);
+                let (a_pq, a_qp) = (-phase.conj().scale(s), phase.scale(s));
+                rotate(gp, gq, c, a_pq, a_qp);
+                if let Some(vr) = &mut vr {
+                    let (vp, vq) = vr.multi_slice_mut((s![p, ..], s![q, ..]));
+                    rotate(vp, vq, c, a_pq, a_qp);
+                }
+            }
+        }
+        if !rotated {
+            break;
+        }
+    }
+
+    let norms = g
+        .rows()
+        .into_iter()
+        .map(|row| row.fold(A::Real::zero(), |acc, x| acc + x.abs_sqr()).sqrt())
+        .collect::<Vec<_>>();
+    let mut order = (0..n).collect::<Vec<_>>();
+    order.sort_by(|&i, &j| norms[j].partial_cmp(&norms[i]).unwrap_or(Ordering::Equal));
+    let s = order.iter().map(|&i| norms[i]).collect::<Array1<_>>();
+    if !vectors {
+        return Ok(Svd {
+            u: None,
+            s: s * scale,
+            vt: None,
+        });
+    }
+
+    let cols = if mode == SvdMode::Full { m } else { n };
+    let mut u = Array2::zeros((m, cols));
+    for (k, &i) in order.iter().enumerate() {
+        let inv = s[k].recip();
+        u.column_mut(k).assign(&g.row(i).mapv(|x| x.scale(inv)));
+    }
+    // complete the columns for zero singular values (and for the full U) to
+    // an orthonormal basis
+    let rank = s.iter().take_while(|&&s| s > A::Real::zero()).count();
+    if rank < cols {
+        let q = qr(&u.slice(s![.., ..rank])).q_columns(cols);
+        u.slice_mut(s![.., rank..]).assign(&q.slice(s![.., rank..]));
+    }
+    let vt = vr.unwrap().select(Axis(0), &order).mapv(A::conj);
+    Ok(Svd {
+        u: Some(u),
+        s: s * scale,
+        vt: Some(vt),
+    })
+}
+
+/// Two-sided Jacobi eigenvalue algorithm for the Hermitian matrix with the
+/// lower triangle of `a`; the rotations are accumulated into `v`, which
+/// should be the identity.
+fn jacobi_eigh<A>(a: &ArrayRef2<A>, mut v: Option<&mut Array2<A>>) -> Result<Array1<A::Real>, LinalgError>
+where A: LinalgField
+{
+    let n = square(a)?;
+    // scaled like in `svd`, so that the products in the convergence test stay
+    // in range
+    let scale = scale_factor(a);
+    let inv = scale.recip();
+    let mut h = Array2::from_shape_fn((n, n), |(i, j)| match i.cmp(&j) {
+        Ordering::Greater => a[[i, j]].scale(inv),
+        Ordering::Equal => A::from_real(a[[i, i]].re() * inv),
+        Ordering::Less => a[[j, i]].conj().scale(inv),
+    });
+    let eps = A::Real::epsilon();
+    for sweep in 0.. {
+        if sweep == MAX_SWEEPS {
+            return Err(LinalgError::NotConverged);
+        }
+        let mut rotated = false;
+        for p in 0..n {
+            for q in p + 1..n {
+                let h_pq = h[[p, q]];
+                let (h_pp, h_qq) = (h[[p, p]].re(), h[[q, q]].re());
+                let r = h_pq.abs();
+                if r <= eps * Float::abs(h_pp).sqrt() * Float::abs(h_qq).sqrt() {
+                    continue;
+                }
+                rotated = true;
+                let (c, s, phase) = jacobi_rotation(h_pp, h_qq, h_pq);
+                // H ← Jᴴ H J, V ← V J with the rotation J in the (p, q) plane
+                let (a_pq, a_qp) = (-phase.conj().scale(s), phase.scale(s));
+                let (hp, hq) = h.multi_slice_mut((s![.., p], s![.., q]));
+                rotate(hp, hq, c, a_pq, a_qp);
+                let (hp, hq) = h.multi_slice_mut((s![p, ..], s![q, ..]));
+                rotate(hp, hq, c, a_pq.conj(), a_qp.conj());
+                if let Some(v) = v.as_deref_mut() {
+                    let (vp, vq) = v.multi_slice_mut((s![.., p], s![.., q]));
+                    rotate(vp, vq, c, a_pq, a_qp);
+                }
+                let t = s / c;
+                h[[p, p]] = A::from_real(h_pp - t * r);
+                h[[q, q]] = A::from_real(h_qq + t * r);
+                h[[p, q]] = A::zero();
+                h[[q, p]] = A::zero();
+            }
+        }
+        if !rotated {
+            break;
+        }
+    }
+
+    let w = h.diag().mapv(|x| x.re() * scale);
+    let mut order = (0..n).collect::<Vec<_>>();
+    order.sort_by(|&i, &j| w[i].partial_cmp(&w[j]).unwrap_or(Ordering::Equal));
+    if let Some(v) = v {
+        *v = v.select(Axis(1), &order);
+    }
+    Ok(w.select(Axis(0), &order))
+}
+
+/// Return the largest absolute value of the elements of `a`, or 1 if it is
+/// zero or not finite, so that `a` divided by it has elements of magnitude
+/// at most 1, as LAPACK does before iterating.
+fn scale_factor<A>(a: &ArrayRef2<A>) -> A::Real
+where A: LinalgField
+{
+    let amax = a.fold(A::Real::zero(), |m, x| Float::max(m, x.abs()));
+    if amax > A::Real::zero() && amax.is_finite() {
+        amax
+    } else {
+        A::Real::one()
+    }
+}
+
+/// Return `(c, s, phase)` for the rotation that diagonalizes the Hermitian
+/// matrix `[[a_pp, a_pq], [conj(a_pq), a_qq]]`: `J = [[c, phase s],
+/// [-conj(phase) s, c]]`, with `a_pq ≠ 0`.
+fn jacobi_rotation<A>(a_pp: A::Real, a_qq: A::Real, a_pq: A) -> (A::Real, A::Real, A)
+where A: LinalgField
+{
+    let one = A::Real::one();
+    let two = one + one;
+    let r = a_pq.abs();
+    let tau = (a_qq - a_pp) / (two * r);
+    let t = if tau >= A::Real::zero() { one } else { -one } / (Float::abs(tau) + one.hypot(tau));
+    let c = one / one.hypot(t);
+    (c, t * c, a_pq.scale(r.recip()))
+}
+
+/// Replace `x` and `y` by `c x + a_xy y` and `a_yx x + c y`.
+fn rotate<A>(mut x: ArrayViewMut1<'_, A>, mut y: ArrayViewMut1<'_, A>, c: A::Real, a_xy: A, a_yx: A)
+where A: LinalgField
+{
+    Zip::from(&mut x).and(&mut y).for_each(|x, y| {
+        let (x0, y0) = (*x, *y);
+        *x = x0.scale(c) + a_xy * y0;
+        *y = a_yx * x0 + y0.scale(c);
+    });
+}
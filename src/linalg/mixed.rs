@@ -0,0 +1,346 @@
+// Copyright 2014-2020 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Matrix multiplication with a separate accumulator type.
+
+use crate::imp_prelude::*;
+
+use crate::linalg::general_mat_mul;
+use crate::linalg::impl_linalg::{cast_as, dot_shape_error, general_dot_shape_error, same_type};
+use crate::{ArrayRef2, LinalgScalar, Zip};
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use num_complex::{Complex32 as c32, Complex64 as c64};
+
+/// Rows of `a` packed at a time by the blocked kernel
+const BLOCK_M: usize = 64;
+/// Length of the shared dimension packed at a time by the blocked kernel
+const BLOCK_K: usize = 256;
+/// Columns of `b` packed at a time by the blocked kernel
+const BLOCK_N: usize = 512;
+/// Products with fewer multiplications than this skip the packing
+const SMALL_PRODUCT: usize = 16 * 16 * 16;
+/// Rows of the register tile of the inner kernel
+const TILE_M: usize = 4;
+/// Columns of the register tile of the inner kernel
+const TILE_N: usize = 16;
+
+impl<A> ArrayRef<A, Ix2>
+{
+    /// Perform matrix multiplication of `self` and `rhs`, accumulating in
+    /// the element type `C`.
+    ///
+    /// The elements of both operands are converted to `C` before they are
+    /// multiplied, so for example `i8` matrices can be multiplied into an
+    /// `i32` result, or `f32` matrices into an `f64` result.
+    ///
+    /// The array shapes must agree in the way that if `self` is *M* × *N*,
+    /// then `rhs` is *N* × *K*. Return a result array with shape *M* × *K*.
+    ///
+    /// **Panics** if shapes are incompatible or the number of elements in the
+    /// result would overflow `isize`.
+    ///
+    /// See [`general_mat_mul_widening`] for how the product is computed.
+    ///
+    /// ```
+    /// use ndarray::{arr2, Array2};
+    ///
+    /// let a = arr2(&[[100i8, -128],
+    ///                [127, 1]]);
+    ///
+    /// let c: Array2<i32> = a.dot_widening(&a);
+    /// assert_eq!(c, arr2(&[[-6256, -12928],
+    ///                      [12827, -16255]]));
+    /// ```
+    ///
+    /// [`general_mat_mul_widening`]: crate::linalg::general_mat_mul_widening
+    #[track_caller]
+    pub fn dot_widening<C, B>(&self, rhs: &ArrayRef<B, Ix2>) -> Array2<C>
+    where
+        A: 'static + Copy + Into<C>,
+        B: 'static + Copy + Into<C>,
+        C: LinalgScalar,
+    {
+        let ((m, k), (k2, n)) = (self.dim(), rhs.dim());
+        if k != k2 || m.checked_mul(n).is_none() {
+            dot_shape_error(m, k, k2, n);
+        }
+        let mut c = Array2::zeros((m, n));
+        general_mat_mul_widening(C::one(), self, rhs, C::zero(), &mut c);
+        c
+    }
+}
+
+/// General matrix-matrix multiplication with a separate accumulator type.
+///
+/// Compute C ← α A B + β C, where the elements of A and B are converted to
+/// the element type of C before they are multiplied and summed.
+///
+/// This is meant for quantised and exact integer workloads, like `i8` × `i8`
+/// or `u8` × `i8` accumulated in `i32`, and for mixed precision products of
+/// `f32` inputs accumulated in `f64`.
+///
+/// The array shapes must agree in the way that
+/// if `a` is *M* × *N*, then `b` is *N* × *K* and `c` is *M* × *K*.
+///
+/// ***Panics*** if array shapes are not compatible<br>
+/// *Note:* For float accumulators the inputs are converted up front and the
+/// product uses the same backend as [`general_mat_mul`]. Inputs of `i8, u8,
+/// i16, u16` accumulated in `i32, i64` are multiplied in blocks along the
+/// shared dimension that are short enough for the float product to be exact,
+/// so they use the float backend too; the sums of the blocks wrap on overflow
+/// of the accumulator. Other types use a cache blocked kernel.
+#[track_caller]
+pub fn general_mat_mul_widening<A, B, C>(alpha: C, a: &ArrayRef2<A>, b: &ArrayRef2<B>, beta: C, c: &mut ArrayRef2<C>)
+where
+    A: 'static + Copy + Into<C>,
+    B: 'static + Copy + Into<C>,
+    C: LinalgScalar,
+{
+    let ((m, k), (k2, n)) = (a.dim(), b.dim());
+    let (m2, n2) = c.dim();
+    if k != k2 || m != m2 || n != n2 {
+        general_dot_shape_error(m, k, k2, n, m2, n2);
+    }
+    if same_type::<C, f32>() || same_type::<C, f64>() || same_type::<C, c32>() || same_type::<C, c64>() {
+        let a = a.mapv(Into::into);
+        let b = b.mapv(Into::into);
+        general_mat_mul(alpha, &a, &b, beta, c);
+    } else {
+        mat_mul_blocked(alpha, a, b, beta, c);
+    }
+}
+
+/// C ← α A B + β C, blocked for cache reuse
+///
+/// Blocks of A and B are converted to the accumulator type and packed into
+/// panels of `TILE_M` rows and `TILE_N` columns, zero padded at the edges.
+/// The inner kernel keeps a `TILE_M` × `TILE_N` tile of the product in
+/// registers while it runs along the shared dimension. `c` is not read when
+/// `beta` is zero.
+pub(crate) fn mat_mul_blocked<A, B, C>(alpha: C, a: &ArrayRef2<A>, b: &ArrayRef2<B>, beta: C, c: &mut ArrayRef2<C>)
+where
+    A: 'static + Copy + Into<C>,
+    B: 'static + Copy + Into<C>,
+    C: LinalgScalar,
+{
+    let ((m, k), (_, n)) = (a.dim(), b.dim());
+    if m == 0 || n == 0 {
+        return;
+    }
+    if k == 0 {
+        if beta.is_zero() {
+            c.fill(beta);
+        } else {
+            c.map_inplace(|x| *x = *x * beta);
+        }
+        return;
+    }
+
+    if m.saturating_mul(n).saturating_mul(k) < SMALL_PRODUCT {
+        for ((i, j), y) in c.indexed_iter_mut() {
+            let z = Zip::from(a.row(i))
+                .and(b.column(j))
+                .fold(C::zero(), |acc, &x, &z| acc + x.into() * z.into());
+            if beta.is_zero() {
+                *y = alpha * z;
+            } else {
+                *y = *y * beta + alpha * z;
+            }
+        }
+        return;
+    }
+
+    if let (Some(a_bits), Some(b_bits)) = (small_int_bits::<A>(), small_int_bits::<B>()) {
+        if same_type::<C, i32>() || same_type::<C, i64>() {
+            // use f32 when its exact blocks are not too short to be efficient
+            let bits = a_bits + b_bits;
+            if bits + 8 <= f32::MANTISSA_DIGITS {
+                mat_mul_exact_float::<_, _, _, f32>(alpha, a, b, beta, c, f32::MANTISSA_DIGITS - bits);
+            } else {
+                mat_mul_exact_float::<_, _, _, f64>(alpha, a, b, beta, c, f64::MANTISSA_DIGITS - bits);
+            }
+            return;
+        }
+    }
+
+    let mut a_pack = Vec::with_capacity(round_up(BLOCK_M.min(m), TILE_M) * BLOCK_K.min(k));
+    let mut b_pack = Vec::with_capacity(round_up(BLOCK_N.min(n), TILE_N) * BLOCK_K.min(k));
+
+    for j0 in (0..n).step_by(BLOCK_N) {
+        let nc = BLOCK_N.min(n - j0);
+        for p0 in (0..k).step_by(BLOCK_K) {
+            let kc = BLOCK_K.min(k - p0);
+            pack(&mut b_pack, b.slice(s![p0..p0 + kc, j0..j0 + nc]).reversed_axes(), TILE_N);
+            // later blocks of the shared dimension add to the partial result
+            let beta = if p0 == 0 { beta } else { C::one() };
+
+            for i0 in (0..m).step_by(BLOCK_M) {
+                let mc = BLOCK_M.min(m - i0);
+                pack(&mut a_pack, a.slice(s![i0..i0 + mc, p0..p0 + kc]), TILE_M);
+
+                for (jr, b_panel) in b_pack.chunks_exact(TILE_N * kc).enumerate() {
+                    for (ir, a_panel) in a_pack.chunks_exact(TILE_M * kc).enumerate() {
+                        let tile = tile_kernel(a_panel, b_panel);
+                        let (i, j) = (i0 + ir * TILE_M, j0 + jr * TILE_N);
+                        let mut c_tile = c.slice_mut(s![i..m.min(i + TILE_M), j..(j0 + nc).min(j + TILE_N)]);
+                        for ((r, s), y) in c_tile.indexed_iter_mut() {
+                            if beta.is_zero() {
+                                *y = alpha * tile[r][s];
+                            } else {
+                                *y = *y * beta + alpha * tile[r][s];
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Float types that hold the products of small integers exactly
+trait ExactFloat: LinalgScalar + From<i8> + From<u8> + From<i16> + From<u16>
+{
+    /// Convert an integer valued float
+    fn to_i64(self) -> i64;
+}
+
+impl ExactFloat for f32
+{
+    fn to_i64(self) -> i64
+    {
+        self as i64
+    }
+}
+
+impl ExactFloat for f64
+{
+    fn to_i64(self) -> i64
+    {
+        self as i64
+    }
+}
+
+/// Return the number of bits of the largest magnitude of `T`, if it is a
+/// small integer type.
+fn small_int_bits<T: 'static>() -> Option<u32>
+{
+    if same_type::<T, i8>() {
+        Some(7)
+    } else if same_type::<T, u8>() {
+        Some(8)
+    } else if same_type::<T, i16>() {
+        Some(15)
+    } else if same_type::<T, u16>() {
+        Some(16)
+    } else {
+        None
+    }
+}
+
+fn small_int_to_float<T, F>(x: &T) -> F
+where
+    T: 'static + Copy,
+    F: ExactFloat,
+{
+    if same_type::<T, i8>() {
+        F::from(cast_as::<T, i8>(x))
+    } else if same_type::<T, u8>() {
+        F::from(cast_as::<T, u8>(x))
+    } else if same_type::<T, i16>() {
+        F::from(cast_as::<T, i16>(x))
+    } else {
+        F::from(cast_as::<T, u16>(x))
+    }
+}
+
+/// C ← α A B + β C for small integer inputs and an `i32` or `i64` accumulator
+///
+/// The products of blocks of at most 2^`block_bits` elements along the shared
+/// dimension are computed in `F`, where they are exact, and then added up in
+/// the accumulator type.
+fn mat_mul_exact_float<A, B, C, F>(
+    alpha: C, a: &ArrayRef2<A>, b: &ArrayRef2<B>, beta: C, c: &mut ArrayRef2<C>, block_bits: u32,
+) where
+    A: 'static + Copy + Into<C>,
+    B: 'static + Copy + Into<C>,
+    C: LinalgScalar,
+    F: ExactFloat,
+{
+    let k = a.ncols();
+    let block = 1usize.checked_shl(block_bits).unwrap_or(usize::MAX);
+    let mut prod = Array2::<F>::zeros(c.raw_dim());
+    let mut p0 = 0;
+    while p0 < k {
+        let kc = block.min(k - p0);
+        let a_block = a.slice(s![.., p0..p0 + kc]).map(small_int_to_float::<A, F>);
+        let b_block = b.slice(s![p0..p0 + kc, ..]).map(small_int_to_float::<B, F>);
+        general_mat_mul(F::one(), &a_block, &b_block, F::zero(), &mut prod);
+
+        let beta = if p0 == 0 { beta } else { C::one() };
+        Zip::from(&mut *c).and(&prod).for_each(|y, &z| {
+            let z = z.to_i64();
+            let z = if same_type::<C, i64>() {
+                cast_as(&z)
+            } else {
+                cast_as(&(z as i32))
+            };
+            if beta.is_zero() {
+                *y = alpha * z;
+            } else {
+                *y = *y * beta + alpha * z;
+            }
+        });
+        p0 += kc;
+    }
+}
+
+fn round_up(n: usize, m: usize) -> usize
+{
+    (n + m - 1) / m * m
+}
+
+/// Pack the rows of `a` into `buf` as panels of `width` rows, each stored
+/// column by column, zero padding the last panel.
+fn pack<A, C>(buf: &mut Vec<C>, a: ArrayView2<'_, A>, width: usize)
+where
+    A: 'static + Copy + Into<C>,
+    C: LinalgScalar,
+{
+    let (rows, cols) = a.dim();
+    buf.clear();
+    for i0 in (0..rows).step_by(width) {
+        let panel = a.slice(s![i0..rows.min(i0 + width), ..]);
+        for col in panel.columns() {
+            buf.extend(col.iter().map(|&x| x.into()));
+            buf.extend((col.len()..width).map(|_| C::zero()));
+        }
+    }
+    debug_assert_eq!(buf.len(), round_up(rows, width) * cols);
+}
+
+/// Multiply a packed panel of `TILE_M` rows with a packed panel of `TILE_N`
+/// columns.
+#[inline(never)]
+fn tile_kernel<C>(a: &[C], b: &[C]) -> [[C; TILE_N]; TILE_M]
+where C: LinalgScalar
+{
+    let mut tile = [[C::zero(); TILE_N]; TILE_M];
+    for (x, y) in a.chunks_exact(TILE_M).zip(b.chunks_exact(TILE_N)) {
+        let x: &[C; TILE_M] = x.try_into().unwrap();
+        let y: &[C; TILE_N] = y.try_into().unwrap();
+        for r in 0..TILE_M {
+            for s in 0..TILE_N {
+                tile[r][s] = tile[r][s] + x[r] * y[s];
+            }
+        }
+    }
+    tile
+}
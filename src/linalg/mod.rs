@@ -8,9 +8,36 @@
 
 //! Linear algebra.
 
+pub use self::einsum::{einsum, Einsum, EinsumError};
+#[cfg(feature = "std")]
+pub use self::factorization::{cholesky, lu, qr, Cholesky, LinalgError, Lu, Qr};
+#[cfg(feature = "blas")]
+pub(crate) use self::impl_linalg::blas_scal;
+pub use self::impl_linalg::general_batched_mat_mul;
 pub use self::impl_linalg::general_mat_mul;
 pub use self::impl_linalg::general_mat_vec_mul;
 pub use self::impl_linalg::kron;
+pub use self::impl_linalg::outer;
+pub use self::impl_linalg::tensordot;
 pub use self::impl_linalg::Dot;
+#[cfg(feature = "std")]
+pub use self::impl_linalg::{general_mat_mul_op, general_mat_vec_mul_op, Transpose};
+#[cfg(feature = "std")]
+pub use self::jacobi::{eigh, eigvalsh, svd, Svd, SvdMode};
+pub use self::mixed::general_mat_mul_widening;
+#[cfg(feature = "std")]
+pub use self::norm::InducedNorm;
+#[cfg(feature = "std")]
+pub use self::solve::{Diagonal, Triangle};
 
+mod einsum;
+#[cfg(feature = "std")]
+mod factorization;
 mod impl_linalg;
+#[cfg(feature = "std")]
+mod jacobi;
+mod mixed;
+#[cfg(feature = "std")]
+mod norm;
+#[cfg(feature = "std")]
+mod solve;
@@ -0,0 +1,365 @@
+// Copyright 2014-2024 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Vector and matrix norms, trace, matrix powers and condition estimates.
+
+use num_traits::{Float, NumCast, One, Zero};
+
+use crate::imp_prelude::*;
+use crate::linalg::factorization::{conj_t, lu, square, Lu};
+use crate::linalg::jacobi::{svd, SvdMode};
+use crate::linalg::solve::{substitute, Diagonal, Triangle};
+use crate::linalg::LinalgError;
+use crate::LinalgField;
+
+/// Maximum number of iterations of the 1-norm estimator in
+/// [`.cond_est()`](ArrayRef::cond_est); it usually stops after two or three.
+const MAX_ESTIMATE_ITERATIONS: usize = 5;
+
+/// Induced (operator) matrix norm, see
+/// [`.norm_induced()`](ArrayRef::norm_induced).
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum InducedNorm
+{
+    /// The 1-norm: the largest sum of the absolute values in a column.
+    One,
+    /// The 2-norm, or spectral norm: the largest singular value.
+    Two,
+    /// The ∞-norm: the largest sum of the absolute values in a row.
+    Infinity,
+}
+
+/// # Norms
+///
+/// Norms of arrays of any dimension, treating all the elements as one
+/// vector. For complex elements, the absolute value is the modulus.
+///
+/// **Requires default crate feature `"std"`**
+impl<A, D> ArrayRef<A, D>
+where
+    A: LinalgField,
+    D: Dimension,
+{
+    /// Return the L1 norm: the sum of the absolute values of the elements.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// assert_eq!(array![3., -4.].norm_l1(), 7.);
+    /// ```
+    ///
+    /// *Note:* If enabled, uses blas `asum` for vectors and matrices of
+    /// `f32, f64` when memory layout allows.
+    pub fn norm_l1(&self) -> A::Real
+    {
+        #[cfg(feature = "blas")]
+        if let Some(sum) = crate::linalg::impl_linalg::blas_asum(self) {
+            return sum;
+        }
+        self.fold(A::Real::zero(), |sum, x| sum + x.abs())
+    }
+
+    /// Return the L2 norm: the square root of the sum of the squared
+    /// absolute values of the elements.
+    ///
+    /// The sum is scaled as it is accumulated, so the result does not
+    /// overflow or underflow unless the norm itself does.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// assert_eq!(array![3., -4.].norm_l2(), 5.);
+    /// assert_eq!(array![3e300, -4e300].norm_l2(), 5e300);
+    /// ```
+    ///
+    /// *Note:* If enabled, uses blas `nrm2` for vectors and matrices of
+    /// `f32, f64, c32, c64` when memory layout allows.
+    pub fn norm_l2(&self) -> A::Real
+    {
+        #[cfg(feature = "blas")]
+        if let Some(norm) = crate::linalg::impl_linalg::blas_nrm2(self) {
+            return norm;
+        }
+        // the norm is scale * √ssq, where scale is the largest absolute
+        // value seen so far
+        let zero = A::Real::zero();
+        let one = A::Real::one();
+        let (scale, ssq) = self.fold((zero, zero), |(scale, ssq), x| {
+            let a = x.abs();
+            if a.is_nan() {
+                (a, one)
+            } else if a > scale {
+                let r = scale / a;
+                (a, one + ssq * r * r)
+            } else if a > zero {
+                let r = if a == scale { one } else { a / scale };
+                (scale, ssq + r * r)
+            } else {
+                (scale, ssq)
+            }
+        });
+        scale * ssq.sqrt()
+    }
+
+    /// Return the max norm: the largest absolute value of the elements, or
+    /// zero if the array is empty.
+    ///
+    /// The result is NaN if any element is NaN.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// assert_eq!(array![3., -4.].norm_max(), 4.);
+    /// ```
+    pub fn norm_max(&self) -> A::Real
+    {
+        self.fold(A::Real::zero(), |max, x| {
+            let a = x.abs();
+            if max.is_nan() || a <= max {
+                max
+            } else {
+                a
+            }
+        })
+    }
+}
+
+/// # Matrix Norms and Functions
+///
+/// **Requires default crate feature `"std"`**
+impl<A> ArrayRef<A, Ix2>
+where A: LinalgField
+{
+    /// Return the Frobenius norm of the matrix: the L2 norm of its elements,
+    /// see [`.norm_l2()`](Self::norm_l2).
+    pub fn norm_fro(&self) -> A::Real
+    {
+        self.norm_l2()
+    }
+
+    /// Return the nuclear norm of the matrix: the sum of its singular
+    /// values, see [`svd`].
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// assert_eq!(array![[3., 0.], [0., -4.]].norm_nuclear(), 7.);
+    /// ```
+    ///
+    /// The result is NaN if the singular values can't be computed, which
+    /// happens if the matrix has elements that are not finite.
+    pub fn norm_nuclear(&self) -> A::Real
+    {
+        match svd(self, SvdMode::ValuesOnly) {
+            Ok(svd) => svd.s.sum(),
+            Err(_) => A::Real::nan(),
+        }
+    }
+
+    /// Return the matrix norm induced by the vector 1-, 2- or ∞-norm.
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use ndarray::linalg::InducedNorm;
+    ///
+    /// let a = array![[1., -2.], [3., 4.]];
+    /// assert_eq!(a.norm_induced(InducedNorm::One), 6.);
+    /// assert_eq!(a.norm_induced(InducedNorm::Infinity), 7.);
+    /// ```
+    ///
+    /// For [`InducedNorm::Two`], the result is NaN if the singular values
+    /// can't be computed, like for [`.norm_nuclear()`](Self::norm_nuclear).
+    pub fn norm_induced(&self, norm: InducedNorm) -> A::Real
+    {
+        match norm {
+            InducedNorm::One => max_lane_l1(self, Axis(0)),
+            InducedNorm::Infinity => max_lane_l1(self, Axis(1)),
+            InducedNorm::Two => match svd(self, SvdMode::ValuesOnly) {
+                Ok(svd) => svd.s.first().copied().unwrap_or_else(A::Real::zero),
+                Err(_) => A::Real::nan(),
+            },
+        }
+    }
+
+    /// Return the trace of the matrix: the sum of its diagonal elements.
+    ///
+    /// The matrix does not need to be square; the diagonal is that of
+    /// [`.diag()`](Self::diag).
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// assert_eq!(array![[1., 2.], [3., 4.]].trace(), 5.);
+    /// ```
+    pub fn trace(&self) -> A
+    {
+        self.diag().sum()
+    }
+
+    /// Return the matrix raised to the integer power `n`.
+    ///
+    /// `n` = 0 gives the identity matrix, and negative `n` the power of the
+    /// inverse. The power is computed by repeated squaring, with about
+    /// 2 log₂ |*n*| matrix multiplications.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[1., 1.], [1., 0.]];
+    /// assert_eq!(a.matrix_power(10).unwrap(), array![[89., 55.], [55., 34.]]);
+    /// assert_eq!(a.matrix_power(-1).unwrap(), array![[0., 1.], [1., -1.]]);
+    /// ```
+    ///
+    /// ***Errors*** if `self` is not square, or if `n` is negative and `self`
+    /// is singular, see [`.inv()`](Self::inv).
+    pub fn matrix_power(&self, n: i32) -> Result<Array2<A>, LinalgError>
+    {
+        let size = square(self)?;
+        let mut base = if n < 0 { self.inv()? } else { self.to_owned() };
+        let mut n = n.unsigned_abs();
+        let mut result = None::<Array2<A>>;
+        while n > 0 {
+            if n & 1 == 1 {
+                result = Some(match result {
+                    Some(result) => result.dot(&base),
+                    None => base.clone(),
+                });
+            }
+            n >>= 1;
+            if n > 0 {
+                base = base.dot(&base);
+            }
+        }
+        Ok(result.unwrap_or_else(|| Array2::eye(size)))
+    }
+
+    /// Return an estimate of the condition number of the matrix in the
+    /// 1-norm, ‖A‖₁ ‖A⁻¹‖₁.
+    ///
+    /// The inverse is not formed: ‖A⁻¹‖₁ is estimated from a few solves with
+    /// the LU factorization of `A` (Hager's method, with Higham's
+    /// refinements), in O(*n*²) operations after the factorization. The
+    /// estimate is a lower bound of the condition number, and it is rarely
+    /// smaller by more than a factor of 3. A singular matrix has an infinite
+    /// condition number.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[4., 0.], [0., 0.125]];
+    /// assert_eq!(a.cond_est().unwrap(), 32.);
+    /// ```
+    ///
+    /// ***Errors*** if `self` is not square.
+    pub fn cond_est(&self) -> Result<A::Real, LinalgError>
+    {
+        let lu = match lu(self) {
+            Ok(lu) => lu,
+            Err(LinalgError::Singular { .. }) => return Ok(A::Real::infinity()),
+            Err(err) => return Err(err),
+        };
+        if self.is_empty() {
+            return Ok(A::Real::zero());
+        }
+        Ok(self.norm_induced(InducedNorm::One) * inverse_norm_l1_est(&lu))
+    }
+}
+
+/// Return the largest L1 norm of the lanes of `a` along `axis`.
+fn max_lane_l1<A>(a: &ArrayRef2<A>, axis: Axis) -> A::Real
+where A: LinalgField
+{
+    a.lanes(axis)
+        .into_iter()
+        .fold(A::Real::zero(), |max, lane| {
+            let sum = lane.fold(A::Real::zero(), |sum, x| sum + x.abs());
+            if max.is_nan() || sum <= max {
+                max
+            } else {
+                sum
+            }
+        })
+}
+
+/// Estimate ‖A⁻¹‖₁ from the LU factorization of the nonempty matrix `A`.
+///
+/// This is Algorithm 2.4 of N. J. Higham, "FORTRAN codes for estimating the
+/// one-norm of a real or complex matrix", ACM TOMS 14 (1988), as in LAPACK's
+/// `xLACN2`.
+fn inverse_norm_l1_est<A>(lu: &Lu<A>) -> A::Real
+where A: LinalgField
+{
+    let n = lu.perm.len();
+    let real = |i: usize| <A::Real as NumCast>::from(i).unwrap();
+    let lut = conj_t(&lu.lu);
+
+    let mut x = Array1::from_elem(n, A::from_real(real(n).recip()));
+    let mut estimate = A::Real::zero();
+    for iteration in 0..MAX_ESTIMATE_ITERATIONS {
+        let y = lu.solve(&x);
+        let y_norm = y.norm_l1();
+        if iteration > 0 && y_norm <= estimate {
+            break;
+        }
+        estimate = y_norm;
+        // ξ = sign(y), z = A⁻ᴴ ξ
+        let xi = y.mapv(|y| {
+            let abs = y.abs();
+            if abs > A::Real::zero() {
+                y.scale(abs.recip())
+            } else {
+                A::one()
+            }
+        });
+        let z = solve_adjoint(lu, &lut, xi);
+        let (j, z_max) = z
+            .iter()
+            .map(|z| z.abs())
+            .enumerate()
+            .fold((0, A::Real::zero()), |(j, max), (i, z)| if z > max { (i, z) } else { (j, max) });
+        // stop if the gradient does not point to a new vertex
+        let z_x = z
+            .iter()
+            .zip(&x)
+            .fold(A::Real::zero(), |sum, (&z, &x)| sum + (z.conj() * x).re());
+        if iteration > 0 && z_max <= z_x {
+            break;
+        }
+        x.fill(A::zero());
+        x[j] = A::one();
+    }
+
+    // safeguard against matrices where the iteration gets stuck: the norm
+    // of A⁻¹ applied to a vector of alternating signs
+    let alternating = Array1::from_shape_fn(n, |i| {
+        let x = A::Real::one() + real(i) / real(n.max(2) - 1);
+        A::from_real(if i % 2 == 0 { x } else { -x })
+    });
+    let safeguard = lu.solve(&alternating).norm_l1() * real(2) / real(3 * n);
+    if safeguard > estimate {
+        safeguard
+    } else {
+        estimate
+    }
+}
+
+/// Solve `Aᴴ x = b` with the LU factorization of `A`, where `lut` is the
+/// conjugate transpose of the combined factors.
+fn solve_adjoint<A>(lu: &Lu<A>, lut: &ArrayRef2<A>, b: Array1<A>) -> Array1<A>
+where A: LinalgField
+{
+    // Aᴴ = Uᴴ Lᴴ P, so x = Pᵀ L⁻ᴴ U⁻ᴴ b
+    let mut w = b.insert_axis(Axis(1));
+    substitute(lut, &mut w, Triangle::Lower, Diagonal::NonUnit);
+    substitute(lut, &mut w, Triangle::Upper, Diagonal::Unit);
+    let mut x = Array1::zeros(lu.perm.len());
+    for (&p, &w) in lu.perm.iter().zip(&w) {
+        x[p] = w;
+    }
+    x
+}
@@ -0,0 +1,319 @@
+// Copyright 2014-2024 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Linear systems, inverse, determinant and least squares.
+
+use num_traits::{Float, NumCast, Zero};
+
+use crate::imp_prelude::*;
+use crate::linalg::factorization::{conj_t, lu, qr, square};
+use crate::linalg::{LinalgError, Transpose};
+use crate::LinalgField;
+
+/// Which triangle of a matrix to use, see
+/// [`.solve_triangular()`](ArrayRef::solve_triangular).
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Triangle
+{
+    /// The diagonal and the elements above it, like [`.triu(0)`](ArrayRef::triu).
+    Upper,
+    /// The diagonal and the elements below it, like [`.tril(0)`](ArrayRef::tril).
+    Lower,
+}
+
+/// Whether the diagonal of a triangular matrix is all ones, see
+/// [`.solve_triangular()`](ArrayRef::solve_triangular).
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Diagonal
+{
+    /// Use the diagonal elements of the matrix.
+    NonUnit,
+    /// Assume that the diagonal elements are one, without reading them.
+    Unit,
+}
+
+/// # Linear Systems
+///
+/// These methods solve linear systems and compute inverses and determinants
+/// with the factorizations in [`linalg`](crate::linalg), without an external
+/// LAPACK library. The right-hand side `b` of a system can be a vector or a
+/// matrix with one column per right-hand side; the solution has the same
+/// dimensionality.
+///
+/// **Requires default crate feature `"std"`**
+impl<A> ArrayRef<A, Ix2>
+where A: LinalgField
+{
+    /// Solve the linear system `A x = b` for `x`, where `A` is `self`.
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use approx::assert_abs_diff_eq;
+    ///
+    /// let a = array![[3., 1.], [1., 2.]];
+    /// let x = a.solve(&array![9., 8.]).unwrap();
+    /// assert_abs_diff_eq!(x, array![2., 3.], epsilon = 1e-12);
+    /// ```
+    ///
+    /// ***Errors*** if `self` is not square or is singular, see [`lu`].
+    ///
+    /// **Panics** if `b` is not a vector or a matrix, or if its number of
+    /// rows is not the size of `self`.
+    #[track_caller]
+    pub fn solve<D>(&self, b: &ArrayRef<A, D>) -> Result<Array<A, D>, LinalgError>
+    where D: Dimension
+    {
+        Ok(lu(self)?.solve(b))
+    }
+
+    /// Return the inverse of the matrix.
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use approx::assert_abs_diff_eq;
+    ///
+    /// let a = array![[4., 7.], [2., 6.]];
+    /// assert_abs_diff_eq!(a.inv().unwrap(), array![[0.6, -0.7], [-0.2, 0.4]], epsilon = 1e-12);
+    /// ```
+    ///
+    /// ***Errors*** if `self` is not square or is singular, see [`lu`].
+    pub fn inv(&self) -> Result<Array2<A>, LinalgError>
+    {
+        let lu = lu(self)?;
+        Ok(lu.solve(&Array2::eye(self.nrows())))
+    }
+
+    /// Return the determinant of the matrix.
+    ///
+    /// The determinant of a singular matrix is zero.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// assert_eq!(array![[1., 2.], [3., 4.]].det().unwrap(), -2.);
+    /// assert_eq!(array![[1., 2.], [2., 4.]].det().unwrap(), 0.);
+    /// ```
+    ///
+    /// ***Errors*** if `self` is not square.
+    pub fn det(&self) -> Result<A, LinalgError>
+    {
+        match lu(self) {
+            Ok(lu) => Ok(lu.det()),
+            Err(LinalgError::Singular { .. }) => Ok(A::zero()),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Return the sign and the natural logarithm of the absolute value of
+    /// the determinant of the matrix.
+    ///
+    /// The determinant is `sign * exp(logabsdet)`; for complex elements,
+    /// `sign` has absolute value one. Unlike [`.det()`](Self::det), this does
+    /// not overflow or underflow for large matrices. For a singular matrix,
+    /// the result is `(0, -∞)`.
+    ///
+    /// ```
+    /// use ndarray::Array2;
+    ///
+    /// let a = Array2::<f64>::eye(500) * 0.1;
+    /// assert_eq!(a.det().unwrap(), 0.);
+    /// let (sign, logabsdet) = a.slogdet().unwrap();
+    /// assert_eq!(sign, 1.);
+    /// assert!((logabsdet - 500. * 0.1f64.ln()).abs() < 1e-9);
+    /// ```
+    ///
+    /// ***Errors*** if `self` is not square.
+    pub fn slogdet(&self) -> Result<(A, A::Real), LinalgError>
+    {
+        let lu = match lu(self) {
+            Ok(lu) => lu,
+            Err(LinalgError::Singular { .. }) => return Ok((A::zero(), A::Real::neg_infinity())),
+            Err(err) => return Err(err),
+        };
+        let sign = if lu.odd { -A::one() } else { A::one() };
+        Ok(lu
+            .lu
+            .diag()
+            .fold((sign, A::Real::zero()), |(sign, logabsdet), &d| {
+                let abs = d.abs();
+                (sign * d.scale(abs.recip()), logabsdet + abs.ln())
+            }))
+    }
+
+    /// Return the least squares solution `x` of `A x = b`, where `A` is
+    /// `self`.
+    ///
+    /// For an *m* × *n* matrix with *m* ≥ *n*, `x` minimizes the norm of
+    /// `A x - b`; with *m* < *n*, `x` is the solution of smallest norm. The
+    /// solution has *n* rows. It is computed with the QR factorization of `A`
+    /// (or of `Aᴴ`, for *m* < *n*), see [`qr`].
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use approx::assert_abs_diff_eq;
+    ///
+    /// // fit a line y = c₀ + c₁ t through three points
+    /// let a = array![[1., 0.], [1., 1.], [1., 2.]];
+    /// let c = a.lstsq(&array![1., 2., 4.]).unwrap();
+    /// assert_abs_diff_eq!(c, array![5. / 6., 1.5], epsilon = 1e-12);
+    /// ```
+    ///
+    /// ***Errors*** if `self` does not have full rank
+    /// ([`LinalgError::Singular`]): the diagonal element `index` of the
+    /// triangular factor `R` is zero, relative to the precision of `A`.
+    ///
+    /// **Panics** if `b` is not a vector or a matrix, or if its number of
+    /// rows is not the number of rows of `self`.
+    #[track_caller]
+    pub fn lstsq<D>(&self, b: &ArrayRef<A, D>) -> Result<Array<A, D>, LinalgError>
+    where D: Dimension
+    {
+        let (m, n) = self.dim();
+        let b2 = as_columns(b);
+        assert_eq!(b2.nrows(), m, "ndarray: right-hand side has {} rows, expected {}", b2.nrows(), m);
+        let x = if m >= n {
+            // A = Q R, x = R⁻¹ Qᴴ b
+            let qr = qr(self);
+            let r = qr.r();
+            check_rank(&r, m)?;
+            let mut x = qr.q().dot_op(Transpose::ConjTrans, &b2, Transpose::NoTrans);
+            substitute(&r, &mut x, Triangle::Upper, Diagonal::NonUnit);
+            x
+        } else {
+            // Aᴴ = Q R, x = Q R⁻ᴴ b
+            let qr = qr(&conj_t(self));
+            let rh = conj_t(&qr.r());
+            check_rank(&rh, n)?;
+            let mut y = b2.to_owned();
+            substitute(&rh, &mut y, Triangle::Lower, Diagonal::NonUnit);
+            qr.q().dot(&y)
+        };
+        Ok(like_rhs(x, b.ndim()))
+    }
+
+    /// Solve the linear system `A x = b` for `x`, where `A` is the upper or
+    /// lower triangle of `self`.
+    ///
+    /// Only the elements of the triangle are read: those that
+    /// [`.triu(0)`](ArrayRef::triu) (for [`Triangle::Upper`]) or
+    /// [`.tril(0)`](ArrayRef::tril) (for [`Triangle::Lower`]) keep; with
+    /// [`Diagonal::Unit`], the diagonal is not read either.
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use ndarray::linalg::{Diagonal, Triangle};
+    ///
+    /// let a = array![[2., 1.], [-1., 4.]];
+    /// let b = array![4., 8.];
+    /// assert_eq!(a.solve_triangular(&b, Triangle::Upper, Diagonal::NonUnit).unwrap(), array![1., 2.]);
+    /// assert_eq!(a.solve_triangular(&b, Triangle::Lower, Diagonal::NonUnit).unwrap(), array![2., 2.5]);
+    /// assert_eq!(a.solve_triangular(&b, Triangle::Lower, Diagonal::Unit).unwrap(), array![4., 12.]);
+    /// ```
+    ///
+    /// ***Errors*** if `self` is not square ([`LinalgError::NotSquare`]), or
+    /// if an element of the diagonal is zero ([`LinalgError::Singular`]).
+    ///
+    /// **Panics** if `b` is not a vector or a matrix, or if its number of
+    /// rows is not the size of `self`.
+    #[track_caller]
+    pub fn solve_triangular<D>(
+        &self, b: &ArrayRef<A, D>, triangle: Triangle, diagonal: Diagonal,
+    ) -> Result<Array<A, D>, LinalgError>
+    where D: Dimension
+    {
+        let n = square(self)?;
+        let b2 = as_columns(b);
+        assert_eq!(b2.nrows(), n, "ndarray: right-hand side has {} rows, expected {}", b2.nrows(), n);
+        if diagonal == Diagonal::NonUnit {
+            if let Some(index) = self.diag().iter().position(|d| d.is_zero()) {
+                return Err(LinalgError::Singular { index });
+            }
+        }
+        let mut x = b2.to_owned();
+        substitute(self, &mut x, triangle, diagonal);
+        Ok(like_rhs(x, b.ndim()))
+    }
+}
+
+/// Check that the diagonal of the triangular factor `r` of a matrix with
+/// `m` rows has no elements that are zero to working precision.
+fn check_rank<A>(r: &ArrayRef2<A>, m: usize) -> Result<(), LinalgError>
+where A: LinalgField
+{
+    let max = r.diag().fold(A::Real::zero(), |max, d| max.max(d.abs()));
+    let tol = max * A::Real::epsilon() * <A::Real as NumCast>::from(m.max(r.ncols())).unwrap();
+    match r.diag().iter().position(|d| d.abs() <= tol) {
+        Some(index) => Err(LinalgError::Singular { index }),
+        None => Ok(()),
+    }
+}
+
+/// Replace `x` by the solution of `A x = b`, where `b` is the initial `x`
+/// and `A` the triangle of `a`, by forward or back substitution.
+pub(crate) fn substitute<A>(a: &ArrayRef2<A>, x: &mut ArrayRef2<A>, triangle: Triangle, diagonal: Diagonal)
+where A: LinalgField
+{
+    #[cfg(feature = "blas")]
+    if crate::linalg::impl_linalg::blas_trsm(a, x, triangle, diagonal) {
+        return;
+    }
+    let n = a.nrows();
+    let rows = match triangle {
+        Triangle::Lower => (0..n).collect::<Vec<_>>(),
+        Triangle::Upper => (0..n).rev().collect(),
+    };
+    for i in rows {
+        let (mut row, done) = match triangle {
+            Triangle::Lower => {
+                let (done, rest) = x.view_mut().split_at(Axis(0), i);
+                (rest.index_axis_move(Axis(0), 0), done)
+            }
+            Triangle::Upper => {
+                let (rest, done) = x.view_mut().split_at(Axis(0), i + 1);
+                (rest.index_axis_move(Axis(0), i), done)
+            }
+        };
+        let offset = if triangle == Triangle::Lower { 0 } else { i + 1 };
+        for (j, x_j) in done.rows().into_iter().enumerate() {
+            row.scaled_add(-a[[i, offset + j]], &x_j);
+        }
+        if diagonal == Diagonal::NonUnit {
+            let d = A::one() / a[[i, i]];
+            row.mapv_inplace(|x| x * d);
+        }
+    }
+}
+
+/// View `b`, a vector or a matrix, as a matrix with one column per
+/// right-hand side.
+#[track_caller]
+pub(crate) fn as_columns<A, D>(b: &ArrayRef<A, D>) -> ArrayView2<'_, A>
+where D: Dimension
+{
+    match b.ndim() {
+        1 => b
+            .view()
+            .into_dimensionality::<Ix1>()
+            .unwrap()
+            .insert_axis(Axis(1)),
+        2 => b.view().into_dimensionality::<Ix2>().unwrap(),
+        ndim => panic!("ndarray: right-hand side must be a vector or a matrix, but has {} axes", ndim),
+    }
+}
+
+/// Convert the solution `x` back to the dimensionality of the right-hand
+/// side, which has `ndim` axes.
+pub(crate) fn like_rhs<A, D>(x: Array2<A>, ndim: usize) -> Array<A, D>
+where D: Dimension
+{
+    if ndim == 1 {
+        x.index_axis_move(Axis(1), 0).into_dimensionality().unwrap()
+    } else {
+        x.into_dimensionality().unwrap()
+    }
+}
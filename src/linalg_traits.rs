@@ -6,6 +6,8 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
+#[cfg(feature = "std")]
+use num_complex::Complex;
 #[cfg(feature = "std")]
 use num_traits::Float;
 use num_traits::{One, Zero};
@@ -14,7 +16,7 @@ use num_traits::{One, Zero};
 use std::fmt;
 use std::ops::{Add, Div, Mul, Sub};
 #[cfg(feature = "std")]
-use std::ops::{AddAssign, DivAssign, MulAssign, RemAssign, SubAssign};
+use std::ops::{AddAssign, DivAssign, MulAssign, Neg, RemAssign, SubAssign};
 
 #[cfg(feature = "std")]
 use crate::ScalarOperand;
@@ -64,3 +66,148 @@ pub trait NdFloat:
 impl NdFloat for f32 {}
 #[cfg(feature = "std")]
 impl NdFloat for f64 {}
+
+/// Real and complex floating-point element types: `f32`, `f64`,
+/// `Complex<f32>` and `Complex<f64>`.
+///
+/// These are the element types of the matrix factorizations in
+/// [`linalg`](crate::linalg), such as [`lu`](crate::linalg::lu). For complex
+/// elements, the factorizations use the conjugate transpose where the real
+/// ones use the transpose.
+///
+/// This trait can only be implemented by the four types above.
+///
+/// **Requires default crate feature `"std"`**
+#[cfg(feature = "std")]
+pub trait LinalgField:
+    LinalgScalar + Neg<Output = Self> + PartialEq + fmt::Debug + ScalarOperand + Send + Sync
+{
+    /// The type of the real and imaginary parts: `Self` for the real types
+    /// and `T` for `Complex<T>`.
+    type Real: NdFloat + LinalgField<Real = Self::Real>;
+
+    /// Return the complex conjugate; the identity for real types.
+    fn conj(self) -> Self;
+
+    /// Return the real part.
+    fn re(self) -> Self::Real;
+
+    /// Return the absolute value (modulus), without intermediate overflow.
+    fn abs(self) -> Self::Real;
+
+    /// Return the squared absolute value.
+    fn abs_sqr(self) -> Self::Real;
+
+    /// Convert a real number to `Self`.
+    fn from_real(re: Self::Real) -> Self;
+
+    /// Multiply by a real number.
+    fn scale(self, factor: Self::Real) -> Self;
+
+    private_decl! {}
+}
+
+#[cfg(feature = "std")]
+macro_rules! impl_linalg_field_real {
+    ($t:ty) => {
+        impl LinalgField for $t
+        {
+            type Real = $t;
+
+            #[inline]
+            fn conj(self) -> Self
+            {
+                self
+            }
+
+            #[inline]
+            fn re(self) -> Self::Real
+            {
+                self
+            }
+
+            #[inline]
+            fn abs(self) -> Self::Real
+            {
+                Float::abs(self)
+            }
+
+            #[inline]
+            fn abs_sqr(self) -> Self::Real
+            {
+                self * self
+            }
+
+            #[inline]
+            fn from_real(re: Self::Real) -> Self
+            {
+                re
+            }
+
+            #[inline]
+            fn scale(self, factor: Self::Real) -> Self
+            {
+                self * factor
+            }
+
+            private_impl! {}
+        }
+    };
+}
+
+#[cfg(feature = "std")]
+macro_rules! impl_linalg_field_complex {
+    ($t:ty) => {
+        impl LinalgField for Complex<$t>
+        {
+            type Real = $t;
+
+            #[inline]
+            fn conj(self) -> Self
+            {
+                Complex::conj(&self)
+            }
+
+            #[inline]
+            fn re(self) -> Self::Real
+            {
+                self.re
+            }
+
+            #[inline]
+            fn abs(self) -> Self::Real
+            {
+                self.re.hypot(self.im)
+            }
+
+            #[inline]
+            fn abs_sqr(self) -> Self::Real
+            {
+                self.norm_sqr()
+            }
+
+            #[inline]
+            fn from_real(re: Self::Real) -> Self
+            {
+                Complex::new(re, 0.)
+            }
+
+            #[inline]
+            fn scale(self, factor: Self::Real) -> Self
+            {
+                Complex::scale(&self, factor)
+            }
+
+            private_impl! {}
+        }
+    };
+}
+
+#[cfg(feature = "std")]
+impl_linalg_field_real!(f32);
+#[cfg(feature = "std")]
+impl_linalg_field_real!(f64);
+#[cfg(feature = "std")]
+impl_linalg_field_complex!(f32);
+#[cfg(feature = "std")]
+impl_linalg_field_complex!(f64);
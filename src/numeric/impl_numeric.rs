@@ -6,15 +6,20 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
 #[cfg(feature = "std")]
 use num_traits::Float;
 use num_traits::One;
 use num_traits::{FromPrimitive, Zero};
-use std::ops::{Add, Div, Mul, MulAssign, Sub};
+use std::cmp::Ordering;
+use std::ops::{Add, AddAssign, Div, Mul, MulAssign, Sub};
 
 use crate::imp_prelude::*;
+use crate::numeric::min_max::{extremum_by, is_unordered};
+use crate::numeric::quantile::{partitioned_quantile, quantile_cmp, quantile_position};
 use crate::numeric_util;
-use crate::Slice;
+use crate::{MinMaxError, NanPolicy, QuantileMethod, ScanMode, Slice, Summation, Zip};
 
 /// # Numerical Methods for Arrays
 impl<A, D> ArrayRef<A, D>
@@ -46,6 +51,38 @@ where D: Dimension
         sum
     }
 
+    /// Return the sum of all elements in the array, using the summation
+    /// method `method`.
+    ///
+    /// [`Pairwise`](crate::Pairwise) and [`Compensated`](crate::Compensated)
+    /// summation keep the rounding error small for long arrays of floating
+    /// point numbers, where [`.sum()`](Self::sum) can lose precision; see
+    /// [`Summation`].
+    ///
+    /// ```
+    /// use ndarray::{Array, Compensated, Pairwise};
+    ///
+    /// let a = Array::from_elem(1_000_000, 0.1f32);
+    /// assert_eq!(a.sum_with(Compensated), 100_000.);
+    /// assert!((a.sum_with(Pairwise) - 100_000.).abs() < 0.1);
+    /// ```
+    pub fn sum_with<S>(&self, method: S) -> A
+    where
+        A: Clone,
+        S: Summation<A>,
+    {
+        if let Some(slc) = self.as_slice_memory_order() {
+            return method.sum_slice(slc);
+        }
+        method.sum_iter(self.rows().into_iter().map(|row| {
+            if let Some(slc) = row.as_slice() {
+                method.sum_slice(slc)
+            } else {
+                method.sum_iter(row.iter().cloned())
+            }
+        }))
+    }
+
     /// Returns the [arithmetic mean] x̅ of all elements in the array:
     ///
     /// ```text
@@ -71,6 +108,35 @@ where D: Dimension
         }
     }
 
+    /// Returns the arithmetic mean of all elements in the array, using the
+    /// summation method `method`; see [`.mean()`](Self::mean) and
+    /// [`.sum_with()`](Self::sum_with).
+    ///
+    /// If the array is empty, `None` is returned.
+    ///
+    /// ```
+    /// use ndarray::{array, Array1, Compensated};
+    ///
+    /// let a = array![1e16, 1., -1e16, 3.];
+    /// assert_eq!(a.mean_with(Compensated), Some(1.));
+    /// assert_eq!(Array1::<f64>::zeros(0).mean_with(Compensated), None);
+    /// ```
+    ///
+    /// **Panics** if `A::from_usize()` fails to convert the number of elements in the array.
+    pub fn mean_with<S>(&self, method: S) -> Option<A>
+    where
+        A: Clone + FromPrimitive + Div<Output = A>,
+        S: Summation<A>,
+    {
+        let n_elements = self.len();
+        if n_elements == 0 {
+            None
+        } else {
+            let n_elements = A::from_usize(n_elements).expect("Converting number of elements to `A` must not fail.");
+            Some(self.sum_with(method) / n_elements)
+        }
+    }
+
     /// Return the product of all elements in the array.
     ///
     /// ```
@@ -136,6 +202,204 @@ where D: Dimension
         result
     }
 
+    /// Return the cumulative sum of elements along a given axis.
+    ///
+    /// ```
+    /// use ndarray::{arr2, Axis};
+    ///
+    /// let a = arr2(&[[1., 2., 3.],
+    ///                [4., 5., 6.]]);
+    ///
+    /// // Cumulative sum along rows (axis 0)
+    /// assert_eq!(
+    ///     a.cumsum(Axis(0)),
+    ///     arr2(&[[1., 2., 3.],
+    ///           [5., 7., 9.]])
+    /// );
+    ///
+    /// // Cumulative sum along columns (axis 1)
+    /// assert_eq!(
+    ///     a.cumsum(Axis(1)),
+    ///     arr2(&[[1., 3., 6.],
+    ///           [4., 9., 15.]])
+    /// );
+    /// ```
+    ///
+    /// See [`.scan_axis()`](Self::scan_axis) for an exclusive cumulative sum.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    #[track_caller]
+    pub fn cumsum(&self, axis: Axis) -> Array<A, D>
+    where
+        A: Clone + Add<Output = A> + AddAssign,
+        D: Dimension + RemoveAxis,
+    {
+        if axis.0 >= self.ndim() {
+            panic!("axis is out of bounds for array of dimension");
+        }
+
+        let mut result = self.to_owned();
+        result.accumulate_axis_inplace(axis, |prev, curr| *curr += prev.clone());
+        result
+    }
+
+    /// Return the cumulative minimum of elements along a given axis.
+    ///
+    /// Elements that do not compare to themselves (like NaN) propagate: once
+    /// one is encountered along the axis, the rest of the result is NaN.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![[3, 1, 2],
+    ///                [0, 4, 5]];
+    /// assert_eq!(a.cummin(Axis(1)), array![[3, 1, 1], [0, 0, 0]]);
+    /// ```
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    #[track_caller]
+    pub fn cummin(&self, axis: Axis) -> Array<A, D>
+    where
+        A: Clone + PartialOrd,
+        D: Dimension + RemoveAxis,
+    {
+        if axis.0 >= self.ndim() {
+            panic!("axis is out of bounds for array of dimension");
+        }
+
+        let mut result = self.to_owned();
+        result.accumulate_axis_inplace(axis, |prev, curr| {
+            if is_unordered(prev) || (!is_unordered(curr) && *prev < *curr) {
+                *curr = prev.clone();
+            }
+        });
+        result
+    }
+
+    /// Return the cumulative maximum of elements along a given axis.
+    ///
+    /// Elements that do not compare to themselves (like NaN) propagate: once
+    /// one is encountered along the axis, the rest of the result is NaN.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![[3, 1, 2],
+    ///                [0, 4, 5]];
+    /// assert_eq!(a.cummax(Axis(1)), array![[3, 3, 3], [0, 4, 5]]);
+    /// ```
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    #[track_caller]
+    pub fn cummax(&self, axis: Axis) -> Array<A, D>
+    where
+        A: Clone + PartialOrd,
+        D: Dimension + RemoveAxis,
+    {
+        if axis.0 >= self.ndim() {
+            panic!("axis is out of bounds for array of dimension");
+        }
+
+        let mut result = self.to_owned();
+        result.accumulate_axis_inplace(axis, |prev, curr| {
+            if is_unordered(prev) || (!is_unordered(curr) && *prev > *curr) {
+                *curr = prev.clone();
+            }
+        });
+        result
+    }
+
+    /// Return the cumulative scan of elements along a given axis, using the
+    /// closure `f` to combine the running value with each element.
+    ///
+    /// The running value of each lane along `axis` starts at `init`. With
+    /// [`ScanMode::Inclusive`], the result at position *i* includes the
+    /// element at *i*; with [`ScanMode::Exclusive`] it only includes the
+    /// elements before *i*, and the first result is `init`.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis, ScanMode};
+    ///
+    /// let counts = array![[3, 1, 2],
+    ///                     [0, 4, 5]];
+    ///
+    /// // Start offsets of each bin: an exclusive prefix sum
+    /// let offsets = counts.scan_axis(Axis(1), 0, ScanMode::Exclusive, |&acc, &x| acc + x);
+    /// assert_eq!(offsets, array![[0, 3, 4], [0, 0, 4]]);
+    ///
+    /// // The result can have a different element type
+    /// let halves = counts.scan_axis(Axis(1), 0., ScanMode::Inclusive, |&acc, &x| acc + x as f64 / 2.);
+    /// assert_eq!(halves, array![[1.5, 2., 3.], [0., 2., 4.5]]);
+    /// ```
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    #[track_caller]
+    pub fn scan_axis<B, F>(&self, axis: Axis, init: B, mode: ScanMode, mut f: F) -> Array<B, D>
+    where
+        B: Clone,
+        F: FnMut(&B, &A) -> B,
+        D: RemoveAxis,
+    {
+        let mut result = Array::uninit(self._dim().clone());
+        Zip::from(result.lanes_mut(axis))
+            .and(self.lanes(axis))
+            .for_each(|mut out_lane, lane| {
+                let mut acc = init.clone();
+                for (out, elt) in out_lane.iter_mut().zip(&lane) {
+                    let next = f(&acc, elt);
+                    match mode {
+                        ScanMode::Inclusive => {
+                            out.write(next.clone());
+                            acc = next;
+                        }
+                        ScanMode::Exclusive => {
+                            out.write(std::mem::replace(&mut acc, next));
+                        }
+                    }
+                }
+            });
+        unsafe { result.assume_init() }
+    }
+
+    /// Replace the elements along a given axis with their cumulative scan,
+    /// using the closure `f` to combine the running value with each element.
+    ///
+    /// This is the in-place version of [`.scan_axis()`](Self::scan_axis),
+    /// built on [`.accumulate_axis_inplace()`](Self::accumulate_axis_inplace).
+    ///
+    /// ```
+    /// use ndarray::{array, Axis, ScanMode};
+    ///
+    /// let mut a = array![[1, 2, 3],
+    ///                    [4, 5, 6]];
+    /// a.scan_axis_inplace(Axis(0), 0, ScanMode::Exclusive, |&acc, &x| acc + x);
+    /// assert_eq!(a, array![[0, 0, 0], [1, 2, 3]]);
+    /// ```
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    #[track_caller]
+    pub fn scan_axis_inplace<F>(&mut self, axis: Axis, init: A, mode: ScanMode, mut f: F)
+    where
+        A: Clone,
+        F: FnMut(&A, &A) -> A,
+        D: RemoveAxis,
+    {
+        if self.len_of(axis) == 0 {
+            return;
+        }
+        self.index_axis_mut(axis, 0)
+            .map_inplace(|x| *x = f(&init, x));
+        self.accumulate_axis_inplace(axis, |prev, curr| *curr = f(prev, curr));
+        if mode == ScanMode::Exclusive {
+            // Shift each lane one step towards the back, and start it at `init`
+            Zip::from(self.lanes_mut(axis)).for_each(|mut lane| {
+                lane.invert_axis(Axis(0));
+                lane.rotate1_front();
+            });
+            self.index_axis_mut(axis, 0).fill(init);
+        }
+    }
+
     /// Return variance of elements in the array.
     ///
     /// The variance is computed using the [Welford one-pass
@@ -248,6 +512,97 @@ where D: Dimension
         self.var(ddof).sqrt()
     }
 
+    /// Return a reference to the minimum of all elements in the array.
+    ///
+    /// If several elements are equal to the minimum, the first one in logical
+    /// order is returned. `nan` decides how elements that do not compare to
+    /// themselves (like NaN) are treated, see [`NanPolicy`].
+    ///
+    /// ***Errors*** with [`MinMaxError::EmptyInput`] if the array has no
+    /// elements (apart from skipped NaNs), and with
+    /// [`MinMaxError::UndefinedOrder`] if elements could not be compared.
+    ///
+    /// ```
+    /// use ndarray::{array, MinMaxError, NanPolicy};
+    ///
+    /// let a = array![[3., 1.], [f64::NAN, 2.]];
+    /// assert_eq!(a.min(NanPolicy::Skip), Ok(&1.));
+    /// assert!(a.min(NanPolicy::Propagate).unwrap().is_nan());
+    /// assert_eq!(a.min(NanPolicy::Error), Err(MinMaxError::UndefinedOrder));
+    /// ```
+    pub fn min(&self, nan: NanPolicy) -> Result<&A, MinMaxError>
+    where A: PartialOrd
+    {
+        extremum_by(self.iter().map(|elt| ((), elt)), nan, Ordering::Less).map(|(_, elt)| elt)
+    }
+
+    /// Return a reference to the maximum of all elements in the array.
+    ///
+    /// If several elements are equal to the maximum, the first one in logical
+    /// order is returned. `nan` decides how elements that do not compare to
+    /// themselves (like NaN) are treated, see [`NanPolicy`].
+    ///
+    /// ***Errors*** with [`MinMaxError::EmptyInput`] if the array has no
+    /// elements (apart from skipped NaNs), and with
+    /// [`MinMaxError::UndefinedOrder`] if elements could not be compared.
+    ///
+    /// ```
+    /// use ndarray::{array, NanPolicy};
+    ///
+    /// let a = array![[3, 1], [7, 2]];
+    /// assert_eq!(a.max(NanPolicy::Error), Ok(&7));
+    /// ```
+    pub fn max(&self, nan: NanPolicy) -> Result<&A, MinMaxError>
+    where A: PartialOrd
+    {
+        extremum_by(self.iter().map(|elt| ((), elt)), nan, Ordering::Greater).map(|(_, elt)| elt)
+    }
+
+    /// Return the index of the minimum of all elements in the array.
+    ///
+    /// If several elements are equal to the minimum, the index of the first
+    /// one in logical order is returned. `nan` decides how elements that do
+    /// not compare to themselves (like NaN) are treated, see [`NanPolicy`].
+    ///
+    /// ***Errors*** with [`MinMaxError::EmptyInput`] if the array has no
+    /// elements (apart from skipped NaNs), and with
+    /// [`MinMaxError::UndefinedOrder`] if elements could not be compared.
+    ///
+    /// ```
+    /// use ndarray::{array, NanPolicy};
+    ///
+    /// let a = array![[3., 1.], [f64::NAN, 2.]];
+    /// assert_eq!(a.argmin(NanPolicy::Skip), Ok((0, 1)));
+    /// assert_eq!(a.argmin(NanPolicy::Propagate), Ok((1, 0)));
+    /// ```
+    pub fn argmin(&self, nan: NanPolicy) -> Result<D::Pattern, MinMaxError>
+    where A: PartialOrd
+    {
+        extremum_by(self.indexed_iter(), nan, Ordering::Less).map(|(index, _)| index)
+    }
+
+    /// Return the index of the maximum of all elements in the array.
+    ///
+    /// If several elements are equal to the maximum, the index of the first
+    /// one in logical order is returned. `nan` decides how elements that do
+    /// not compare to themselves (like NaN) are treated, see [`NanPolicy`].
+    ///
+    /// ***Errors*** with [`MinMaxError::EmptyInput`] if the array has no
+    /// elements (apart from skipped NaNs), and with
+    /// [`MinMaxError::UndefinedOrder`] if elements could not be compared.
+    ///
+    /// ```
+    /// use ndarray::{array, NanPolicy};
+    ///
+    /// let a = array![[3, 7], [7, 2]];
+    /// assert_eq!(a.argmax(NanPolicy::Error), Ok((0, 1)));
+    /// ```
+    pub fn argmax(&self, nan: NanPolicy) -> Result<D::Pattern, MinMaxError>
+    where A: PartialOrd
+    {
+        extremum_by(self.indexed_iter(), nan, Ordering::Greater).map(|(index, _)| index)
+    }
+
     /// Return sum along `axis`.
     ///
     /// ```
@@ -282,6 +637,33 @@ where D: Dimension
         }
     }
 
+    /// Return sum along `axis`, using the summation method `method`; see
+    /// [`.sum_with()`](Self::sum_with).
+    ///
+    /// ```
+    /// use ndarray::{Array, Axis, Compensated};
+    ///
+    /// let a = Array::from_elem((3, 100_000), 0.1f32);
+    /// assert_eq!(a.sum_axis_with(Axis(1), Compensated), Array::from_elem(3, 10_000.));
+    /// ```
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    #[track_caller]
+    pub fn sum_axis_with<S>(&self, axis: Axis, method: S) -> Array<A, D::Smaller>
+    where
+        A: Clone,
+        S: Summation<A>,
+        D: RemoveAxis,
+    {
+        Zip::from(self.lanes(axis)).map_collect(|lane| {
+            if let Some(slc) = lane.as_slice() {
+                method.sum_slice(slc)
+            } else {
+                method.sum_iter(lane.iter().cloned())
+            }
+        })
+    }
+
     /// Return product along `axis`.
     ///
     /// The product of an empty array is 1.
@@ -476,6 +858,436 @@ where D: Dimension
         self.var_axis(axis, ddof).mapv_into(|x| x.sqrt())
     }
 
+    /// Return sum along several axes at once.
+    ///
+    /// If `keepdims` is `true`, the reduced axes are kept with length one, so
+    /// that the result broadcasts against `self`; otherwise they are removed.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![[[1., 2.], [3., 4.]],
+    ///                [[5., 6.], [7., 8.]]];
+    /// assert_eq!(a.sum_axes(&[Axis(1), Axis(2)], false), array![10., 26.].into_dyn());
+    /// assert_eq!(a.sum_axes(&[Axis(0), Axis(1)], true), array![[[16., 20.]]].into_dyn());
+    /// ```
+    ///
+    /// **Panics** if an axis is out of bounds or is repeated in `axes`.
+    #[track_caller]
+    pub fn sum_axes(&self, axes: &[Axis], keepdims: bool) -> ArrayD<A>
+    where A: Clone + Zero + Add<Output = A>
+    {
+        let mut res = Array::<A, _>::zeros(self.reduced_dim(axes));
+        self.for_each_reduced_subview(axes, |subview| {
+            res.zip_mut_with(&subview, |x, y| *x = x.clone() + y.clone());
+        });
+        crate::impl_methods::remove_reduced_axes(res, axes, keepdims)
+    }
+
+    /// Return mean along several axes at once.
+    ///
+    /// If `keepdims` is `true`, the reduced axes are kept with length one, so
+    /// that the result broadcasts against `self`; otherwise they are removed.
+    ///
+    /// Return `None` if the number of elements reduced into each result is
+    /// zero.
+    ///
+    /// **Panics** if an axis is out of bounds or is repeated in `axes`, or if
+    /// `A::from_usize()` fails for the number of reduced elements.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// // Subtract the mean over the last two axes
+    /// let images = array![[[1., 2.], [3., 4.]],
+    ///                     [[5., 7.], [9., 11.]]];
+    /// let mean = images.mean_axes(&[Axis(1), Axis(2)], true).unwrap();
+    /// assert_eq!(mean.shape(), &[2, 1, 1]);
+    /// let centered = &images - &mean;
+    /// assert_eq!(centered, array![[[-1.5, -0.5], [0.5, 1.5]],
+    ///                             [[-3., -1.], [1., 3.]]].into_dyn());
+    /// ```
+    #[track_caller]
+    pub fn mean_axes(&self, axes: &[Axis], keepdims: bool) -> Option<ArrayD<A>>
+    where A: Clone + Zero + FromPrimitive + Add<Output = A> + Div<Output = A>
+    {
+        let count: usize = axes.iter().map(|&axis| self.len_of(axis)).product();
+        if count == 0 {
+            None
+        } else {
+            let count = A::from_usize(count).expect("Converting number of elements to `A` must not fail.");
+            let sum = self.sum_axes(axes, keepdims);
+            Some(sum / aview0(&count))
+        }
+    }
+
+    /// Return variance along several axes at once.
+    ///
+    /// The variance is computed using the [Welford one-pass
+    /// algorithm](https://www.jstor.org/stable/1266577), over all `n`
+    /// elements of each subview along `axes`; see [`.var_axis()`](Self::var_axis)
+    /// for the definition and the meaning of `ddof`.
+    ///
+    /// If `keepdims` is `true`, the reduced axes are kept with length one, so
+    /// that the result broadcasts against `self`; otherwise they are removed.
+    ///
+    /// **Panics** if `ddof` is less than zero or greater than `n`, if an axis
+    /// is out of bounds or is repeated in `axes`, or if `A::from_usize()`
+    /// fails for any of the numbers in the range `0..=n`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![[[1., 2.], [3., 4.]],
+    ///                [[5., 6.], [7., 8.]]];
+    /// let var = a.var_axes(&[Axis(0), Axis(2)], 0., false);
+    /// assert_eq!(var, array![4.25, 4.25].into_dyn());
+    /// ```
+    #[track_caller]
+    #[cfg(feature = "std")]
+    pub fn var_axes(&self, axes: &[Axis], ddof: A, keepdims: bool) -> ArrayD<A>
+    where A: Float + FromPrimitive
+    {
+        let zero = A::from_usize(0).expect("Converting 0 to `A` must not fail.");
+        let count: usize = axes.iter().map(|&axis| self.len_of(axis)).product();
+        let n = A::from_usize(count).expect("Converting length to `A` must not fail.");
+        assert!(
+            !(ddof < zero || ddof > n),
+            "`ddof` must not be less than zero or greater than the number of \
+             reduced elements",
+        );
+        let dof = n - ddof;
+        let mut mean = Array::<A, _>::zeros(self.reduced_dim(axes));
+        let mut sum_sq = Array::<A, _>::zeros(self.reduced_dim(axes));
+        let mut i = 0;
+        self.for_each_reduced_subview(axes, |subview| {
+            let count = A::from_usize(i + 1).expect("Converting index to `A` must not fail.");
+            azip!((mean in &mut mean, sum_sq in &mut sum_sq, &x in &subview) {
+                let delta = x - *mean;
+                *mean = *mean + delta / count;
+                *sum_sq = (x - *mean).mul_add(delta, *sum_sq);
+            });
+            i += 1;
+        });
+        crate::impl_methods::remove_reduced_axes(sum_sq.mapv_into(|s| s / dof), axes, keepdims)
+    }
+
+    /// Return standard deviation along several axes at once.
+    ///
+    /// This is the square root of [`.var_axes()`](Self::var_axes); see there
+    /// for the details.
+    ///
+    /// **Panics** if `ddof` is less than zero or greater than `n`, if an axis
+    /// is out of bounds or is repeated in `axes`, or if `A::from_usize()`
+    /// fails for any of the numbers in the range `0..=n`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![[[1., 2.], [3., 4.]],
+    ///                [[5., 6.], [7., 8.]]];
+    /// let stddev = a.std_axes(&[Axis(0), Axis(1)], 0., true);
+    /// assert_eq!(stddev, array![[[5f64.sqrt(), 5f64.sqrt()]]].into_dyn());
+    /// ```
+    #[track_caller]
+    #[cfg(feature = "std")]
+    pub fn std_axes(&self, axes: &[Axis], ddof: A, keepdims: bool) -> ArrayD<A>
+    where A: Float + FromPrimitive
+    {
+        self.var_axes(axes, ddof, keepdims).mapv_into(|x| x.sqrt())
+    }
+
+    /// Return the minimum along `axis`.
+    ///
+    /// See [`.min()`](Self::min) for how NaNs and ties are handled.
+    ///
+    /// ***Errors*** with [`MinMaxError::EmptyInput`] if the length of `axis`
+    /// is zero or if a lane along `axis` has only skipped NaNs, and with
+    /// [`MinMaxError::UndefinedOrder`] if elements could not be compared.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis, NanPolicy};
+    ///
+    /// let a = array![[3., 1., 4.],
+    ///                [1., 5., f64::NAN]];
+    /// assert_eq!(a.min_axis(Axis(0), NanPolicy::Skip), Ok(array![1., 1., 4.]));
+    /// assert_eq!(a.min_axis(Axis(1), NanPolicy::Skip), Ok(array![1., 1.]));
+    /// ```
+    #[track_caller]
+    pub fn min_axis(&self, axis: Axis, nan: NanPolicy) -> Result<Array<A, D::Smaller>, MinMaxError>
+    where
+        A: Clone + PartialOrd,
+        D: RemoveAxis,
+    {
+        self.extremum_axis(axis, nan, Ordering::Less, |lane, index| lane[index].clone())
+    }
+
+    /// Return the maximum along `axis`.
+    ///
+    /// See [`.max()`](Self::max) for how NaNs and ties are handled.
+    ///
+    /// ***Errors*** with [`MinMaxError::EmptyInput`] if the length of `axis`
+    /// is zero or if a lane along `axis` has only skipped NaNs, and with
+    /// [`MinMaxError::UndefinedOrder`] if elements could not be compared.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis, NanPolicy};
+    ///
+    /// let a = array![[3, 1, 4],
+    ///                [1, 5, 9]];
+    /// assert_eq!(a.max_axis(Axis(1), NanPolicy::Error), Ok(array![4, 9]));
+    /// ```
+    #[track_caller]
+    pub fn max_axis(&self, axis: Axis, nan: NanPolicy) -> Result<Array<A, D::Smaller>, MinMaxError>
+    where
+        A: Clone + PartialOrd,
+        D: RemoveAxis,
+    {
+        self.extremum_axis(axis, nan, Ordering::Greater, |lane, index| lane[index].clone())
+    }
+
+    /// Return the indices of the minimum along `axis`.
+    ///
+    /// See [`.argmin()`](Self::argmin) for how NaNs and ties are handled.
+    ///
+    /// ***Errors*** with [`MinMaxError::EmptyInput`] if the length of `axis`
+    /// is zero or if a lane along `axis` has only skipped NaNs, and with
+    /// [`MinMaxError::UndefinedOrder`] if elements could not be compared.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis, NanPolicy};
+    ///
+    /// let a = array![[3., 1., 4.],
+    ///                [1., 5., f64::NAN]];
+    /// assert_eq!(a.argmin_axis(Axis(0), NanPolicy::Propagate), Ok(array![1, 0, 1]));
+    /// assert_eq!(a.argmin_axis(Axis(1), NanPolicy::Skip), Ok(array![1, 0]));
+    /// ```
+    #[track_caller]
+    pub fn argmin_axis(&self, axis: Axis, nan: NanPolicy) -> Result<Array<usize, D::Smaller>, MinMaxError>
+    where
+        A: PartialOrd,
+        D: RemoveAxis,
+    {
+        self.extremum_axis(axis, nan, Ordering::Less, |_, index| index)
+    }
+
+    /// Return the indices of the maximum along `axis`.
+    ///
+    /// See [`.argmax()`](Self::argmax) for how NaNs and ties are handled.
+    ///
+    /// ***Errors*** with [`MinMaxError::EmptyInput`] if the length of `axis`
+    /// is zero or if a lane along `axis` has only skipped NaNs, and with
+    /// [`MinMaxError::UndefinedOrder`] if elements could not be compared.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis, NanPolicy};
+    ///
+    /// let a = array![[3, 1, 4],
+    ///                [1, 5, 9]];
+    /// assert_eq!(a.argmax_axis(Axis(0), NanPolicy::Error), Ok(array![0, 1, 1]));
+    /// ```
+    #[track_caller]
+    pub fn argmax_axis(&self, axis: Axis, nan: NanPolicy) -> Result<Array<usize, D::Smaller>, MinMaxError>
+    where
+        A: PartialOrd,
+        D: RemoveAxis,
+    {
+        self.extremum_axis(axis, nan, Ordering::Greater, |_, index| index)
+    }
+
+    /// Find the extremum of each lane along `axis` and map its lane and
+    /// index in the lane to the result.
+    fn extremum_axis<B, F>(
+        &self, axis: Axis, nan: NanPolicy, target: Ordering, mut f: F,
+    ) -> Result<Array<B, D::Smaller>, MinMaxError>
+    where
+        A: PartialOrd,
+        D: RemoveAxis,
+        F: FnMut(&ArrayView1<'_, A>, usize) -> B,
+    {
+        if self.len_of(axis) == 0 {
+            return Err(MinMaxError::EmptyInput);
+        }
+        let mut error = None;
+        let result = crate::Zip::from(self.lanes(axis)).map_collect(|lane| {
+            let index = match extremum_by(lane.iter().enumerate(), nan, target) {
+                Ok((index, _)) => index,
+                Err(e) => {
+                    error.get_or_insert(e);
+                    0
+                }
+            };
+            f(&lane, index)
+        });
+        match error {
+            Some(e) => Err(e),
+            None => Ok(result),
+        }
+    }
+
+    /// Return the `q`-quantile of all elements in the array, computed with the
+    /// interpolation method `method`.
+    ///
+    /// The quantile is found by quickselect on a copy of the elements, with
+    /// [`.partition_by()`](Self::partition_by), in linear time on average. If any
+    /// element is NaN, the result is the first NaN; the other elements must
+    /// have a total order. For the interpolating methods, the fraction between
+    /// two elements is converted with `A::from_f64()`, which truncates it to
+    /// zero for integer elements.
+    ///
+    /// If the array is empty, `None` is returned.
+    ///
+    /// ```
+    /// use ndarray::{array, QuantileMethod};
+    ///
+    /// let a = array![[4., 1.], [3., 2.]];
+    /// assert_eq!(a.quantile(0.5, QuantileMethod::Linear), Some(2.5));
+    /// assert_eq!(a.quantile(0.5, QuantileMethod::Lower), Some(2.));
+    /// assert_eq!(a.quantile(0.75, QuantileMethod::Linear), Some(3.25));
+    /// assert_eq!(a.quantile(0.75, QuantileMethod::Nearest), Some(3.));
+    /// ```
+    ///
+    /// **Panics** if `q` is not between 0 and 1 (inclusive).
+    #[track_caller]
+    pub fn quantile(&self, q: f64, method: QuantileMethod) -> Option<A>
+    where A: Clone + PartialOrd + FromPrimitive + Add<Output = A> + Sub<Output = A> + Mul<Output = A>
+    {
+        assert!((0. ..=1.).contains(&q), "ndarray: quantile {} is not between 0 and 1", q);
+        if self.is_empty() {
+            return None;
+        }
+        if let Some(nan) = self.iter().find(|x| is_unordered(*x)) {
+            return Some(nan.clone());
+        }
+        let (i, frac) = quantile_position(self.len(), q);
+        let elements = self.to_shape(self.len()).unwrap();
+        let elements = elements.partition_by(i, Axis(0), quantile_cmp);
+        Some(partitioned_quantile(&elements, i, frac, method))
+    }
+
+    /// Return the `q`-quantile along `axis`, computed with the interpolation
+    /// method `method`; see [`.quantile()`](Self::quantile).
+    ///
+    /// Return `None` if the length of the axis is zero.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis, QuantileMethod};
+    ///
+    /// let a = array![[1., 5., 2.],
+    ///                [8., 3., 4.]];
+    /// assert_eq!(a.quantile_axis(Axis(1), 0.25, QuantileMethod::Linear), Some(array![1.5, 3.5]));
+    /// assert_eq!(a.quantile_axis(Axis(0), 1., QuantileMethod::Linear), Some(array![8., 5., 4.]));
+    /// ```
+    ///
+    /// **Panics** if `axis` is out of bounds or if `q` is not between 0 and 1
+    /// (inclusive).
+    #[track_caller]
+    pub fn quantile_axis(&self, axis: Axis, q: f64, method: QuantileMethod) -> Option<Array<A, D::Smaller>>
+    where
+        A: Clone + PartialOrd + FromPrimitive + Add<Output = A> + Sub<Output = A> + Mul<Output = A>,
+        D: RemoveAxis,
+    {
+        assert!((0. ..=1.).contains(&q), "ndarray: quantile {} is not between 0 and 1", q);
+        if self.len_of(axis) == 0 {
+            return None;
+        }
+        let (i, frac) = quantile_position(self.len_of(axis), q);
+        let partitioned = self.partition_by(i, axis, quantile_cmp);
+        Some(
+            Zip::from(self.lanes(axis))
+                .and(partitioned.lanes(axis))
+                .map_collect(|lane, partitioned| match lane.iter().find(|x| is_unordered(*x)) {
+                    Some(nan) => nan.clone(),
+                    None => partitioned_quantile(&partitioned, i, frac, method),
+                }),
+        )
+    }
+
+    /// Return the median of all elements in the array.
+    ///
+    /// For an even number of elements, this is the mean of the two middle
+    /// elements; it is the same as `.quantile(0.5, QuantileMethod::Linear)`,
+    /// see [`.quantile()`](Self::quantile).
+    ///
+    /// If the array is empty, `None` is returned.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// assert_eq!(array![3., 1., 2.].median(), Some(2.));
+    /// assert_eq!(array![3., 1., 2., 10.].median(), Some(2.5));
+    /// ```
+    pub fn median(&self) -> Option<A>
+    where A: Clone + PartialOrd + FromPrimitive + Add<Output = A> + Sub<Output = A> + Mul<Output = A>
+    {
+        self.quantile(0.5, QuantileMethod::Linear)
+    }
+
+    /// Return the median along `axis`, see [`.median()`](Self::median).
+    ///
+    /// Return `None` if the length of the axis is zero.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    #[track_caller]
+    pub fn median_axis(&self, axis: Axis) -> Option<Array<A, D::Smaller>>
+    where
+        A: Clone + PartialOrd + FromPrimitive + Add<Output = A> + Sub<Output = A> + Mul<Output = A>,
+        D: RemoveAxis,
+    {
+        self.quantile_axis(axis, 0.5, QuantileMethod::Linear)
+    }
+
+    /// Count the elements of the array in each of the bins delimited by
+    /// `edges`.
+    ///
+    /// Bin `i` holds the elements `x` with `edges[i] <= x < edges[i + 1]`,
+    /// except that the last bin also includes its upper edge. Elements outside
+    /// of the bins, and NaNs, are not counted.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[0.5, 1., 2.5], [3., -1., 1.5]];
+    /// assert_eq!(a.histogram(&array![0., 1., 2., 3.]), array![1, 2, 2]);
+    /// ```
+    ///
+    /// **Panics** if there are fewer than two edges, or if they are not
+    /// strictly increasing.
+    #[track_caller]
+    pub fn histogram(&self, edges: &ArrayRef<A, Ix1>) -> Array1<usize>
+    where A: PartialOrd
+    {
+        assert!(edges.len() >= 2, "ndarray: histogram needs at least two bin edges");
+        let edges = edges.iter().collect::<Vec<_>>();
+        assert!(
+            edges.windows(2).all(|w| w[0] < w[1]),
+            "ndarray: histogram bin edges must be strictly increasing"
+        );
+        let (first, last) = (edges[0], edges[edges.len() - 1]);
+        let mut counts = Array1::zeros(edges.len() - 1);
+        for x in self.iter() {
+            if !(first <= x && x <= last) {
+                continue;
+            }
+            // the number of edges that are less than or equal to `x`
+            let n_below = edges.partition_point(|&e| e <= x);
+            counts[n_below.min(edges.len() - 1) - 1] += 1;
+        }
+        counts
+    }
+
     /// Calculates the (forward) finite differences of order `n`, along the `axis`.
     /// For the 1D-case, `n==1`, this means: `diff[i] == arr[i+1] - arr[i]`
     ///
@@ -531,3 +1343,57 @@ where D: Dimension
         inp
     }
 }
+
+/// # Counting Methods for Arrays of Indices
+impl<D> ArrayRef<usize, D>
+where D: Dimension
+{
+    /// Count the number of occurrences of each value in the array.
+    ///
+    /// Element `i` of the result is the number of elements equal to `i`. The
+    /// length of the result is the largest element plus one, or `minlength` if
+    /// that is greater.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[1, 3], [1, 0]];
+    /// assert_eq!(a.bincount(0), array![1, 2, 0, 1]);
+    /// assert_eq!(a.bincount(6), array![1, 2, 0, 1, 0, 0]);
+    /// ```
+    pub fn bincount(&self, minlength: usize) -> Array1<usize>
+    {
+        self.bincount_weighted(&Array::from_elem(self.raw_dim(), 1), minlength)
+    }
+
+    /// Sum up the `weights` for each value in the array.
+    ///
+    /// Element `i` of the result is the sum of the weights at the positions
+    /// of the elements equal to `i`; see [`.bincount()`](Self::bincount).
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![0, 2, 2, 1];
+    /// assert_eq!(a.bincount_weighted(&array![0.5, 1., 2., 0.25], 0), array![0.5, 0.25, 3.]);
+    /// ```
+    ///
+    /// **Panics** if `weights` does not have the same shape as the array.
+    #[track_caller]
+    pub fn bincount_weighted<W>(&self, weights: &ArrayRef<W, D>, minlength: usize) -> Array1<W>
+    where W: Clone + Zero + Add<Output = W>
+    {
+        assert_eq!(self.shape(), weights.shape(), "ndarray: bincount weights must have the shape of the array");
+        let len = self
+            .iter()
+            .map(|&i| i + 1)
+            .max()
+            .unwrap_or(0)
+            .max(minlength);
+        let mut counts = Array1::<W>::zeros(len);
+        Zip::from(self).and(weights).for_each(|&i, w| {
+            counts[i] = counts[i].clone() + w.clone();
+        });
+        counts
+    }
+}
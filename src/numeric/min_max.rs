@@ -0,0 +1,91 @@
+// Copyright 2014-2024 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::cmp::Ordering;
+#[cfg(feature = "std")]
+use std::error::Error;
+use std::fmt;
+
+/// How to treat elements without an order (like NaN) when searching for the
+/// minimum or maximum of an array.
+///
+/// An element `x` is considered NaN if it does not compare to itself, that is
+/// if `x.partial_cmp(&x)` is `None`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum NanPolicy
+{
+    /// The result is the first NaN, if there is any.
+    Propagate,
+    /// NaNs are ignored.
+    Skip,
+    /// NaNs are an error, [`MinMaxError::UndefinedOrder`].
+    Error,
+}
+
+/// An error from searching for the minimum or maximum of an array.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MinMaxError
+{
+    /// The array (or a lane of it) has no elements, or only NaNs that were
+    /// skipped.
+    EmptyInput,
+    /// Elements could not be compared; either a NaN with
+    /// [`NanPolicy::Error`], or two elements without an order between them.
+    UndefinedOrder,
+}
+
+impl fmt::Display for MinMaxError
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        let description = match self {
+            MinMaxError::EmptyInput => "empty input",
+            MinMaxError::UndefinedOrder => "undefined order between elements",
+        };
+        write!(f, "MinMaxError: {}", description)
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for MinMaxError {}
+
+/// Return `true` if `x` does not compare to itself, like NaN.
+#[allow(clippy::eq_op)]
+pub(crate) fn is_unordered<A: PartialOrd>(x: &A) -> bool
+{
+    x.partial_cmp(x).is_none()
+}
+
+/// Find the first element of `iter` that is at least as extreme as all other
+/// elements, where `target` is `Less` for the minimum and `Greater` for the
+/// maximum.
+pub(crate) fn extremum_by<'a, A, I, P>(iter: I, nan: NanPolicy, target: Ordering) -> Result<(P, &'a A), MinMaxError>
+where
+    A: PartialOrd + 'a,
+    I: IntoIterator<Item = (P, &'a A)>,
+{
+    let mut best: Option<(P, &'a A)> = None;
+    for (index, elt) in iter {
+        if is_unordered(elt) {
+            match nan {
+                NanPolicy::Propagate => return Ok((index, elt)),
+                NanPolicy::Skip => continue,
+                NanPolicy::Error => return Err(MinMaxError::UndefinedOrder),
+            }
+        }
+        match best {
+            None => best = Some((index, elt)),
+            Some((_, current)) => match elt.partial_cmp(current) {
+                Some(ord) if ord == target => best = Some((index, elt)),
+                Some(_) => {}
+                None => return Err(MinMaxError::UndefinedOrder),
+            },
+        }
+    }
+    best.ok_or(MinMaxError::EmptyInput)
+}
@@ -1,3 +1,13 @@
 mod impl_numeric;
 
 mod impl_float_maths;
+
+mod min_max;
+mod quantile;
+mod scan;
+mod summation;
+
+pub use self::min_max::{MinMaxError, NanPolicy};
+pub use self::quantile::QuantileMethod;
+pub use self::scan::ScanMode;
+pub use self::summation::{Compensated, Naive, Pairwise, Summation};
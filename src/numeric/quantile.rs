@@ -0,0 +1,97 @@
+// Copyright 2014-2024 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use num_traits::FromPrimitive;
+use std::cmp::Ordering;
+use std::ops::{Add, Mul, Sub};
+
+use crate::numeric::min_max::is_unordered;
+use crate::ArrayRef1;
+
+/// How to compute a quantile that falls between two elements, see
+/// [`ArrayRef::quantile`](crate::ArrayRef::quantile).
+///
+/// The `q`-quantile of `n` sorted elements `x[0], ..., x[n - 1]` is at the
+/// fractional position `q * (n - 1)`. When it is between the elements `x[i]`
+/// and `x[i + 1]`, the methods give:
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum QuantileMethod
+{
+    /// `x[i]`.
+    Lower,
+    /// `x[i + 1]`.
+    Higher,
+    /// The element closest to the position; halfway between, the one with
+    /// the even index.
+    Nearest,
+    /// The mean of `x[i]` and `x[i + 1]`.
+    Midpoint,
+    /// Linear interpolation between `x[i]` and `x[i + 1]`.
+    Linear,
+}
+
+/// Compare `a` and `b` to select the quantiles with
+/// [`.partition_by()`](crate::ArrayRef::partition_by): unordered elements
+/// (NaN) are smaller than all others, so that it is a total order if the
+/// other elements have one.
+pub(crate) fn quantile_cmp<A>(a: &A, b: &A) -> Ordering
+where A: PartialOrd
+{
+    match (is_unordered(a), is_unordered(b)) {
+        (true, true) => Ordering::Equal,
+        (true, false) => Ordering::Less,
+        (false, true) => Ordering::Greater,
+        (false, false) => a
+            .partial_cmp(b)
+            .expect("ndarray: quantile of elements without a total order"),
+    }
+}
+
+/// Return the position of the `q`-quantile of `n` elements, split into the
+/// index of the lower element and the fraction towards the next one.
+pub(crate) fn quantile_position(n: usize, q: f64) -> (usize, f64)
+{
+    let pos = q * (n - 1) as f64;
+    // `pos` is not negative, so truncation is rounding down
+    let i = pos as usize;
+    (i, pos - i as f64)
+}
+
+/// Return the quantile at position `(i, frac)`, see [`quantile_position`],
+/// of the elements of `lane`, which must be partitioned at `i` with
+/// [`quantile_cmp`] and must not contain NaN.
+pub(crate) fn partitioned_quantile<A>(lane: &ArrayRef1<A>, i: usize, frac: f64, method: QuantileMethod) -> A
+where A: Clone + PartialOrd + FromPrimitive + Add<Output = A> + Sub<Output = A> + Mul<Output = A>
+{
+    let lower = lane[i].clone();
+    if frac == 0. {
+        return lower;
+    }
+    // the smallest element above position `i` is the one at `i + 1`
+    let higher = lane
+        .slice(s![i + 1..])
+        .iter()
+        .min_by(|a, b| quantile_cmp(*a, *b))
+        .unwrap()
+        .clone();
+    let interpolate = |frac: f64| {
+        let frac = A::from_f64(frac).expect("Converting the quantile fraction to `A` must not fail.");
+        lower.clone() + (higher.clone() - lower.clone()) * frac
+    };
+    match method {
+        QuantileMethod::Lower => lower,
+        QuantileMethod::Higher => higher,
+        QuantileMethod::Nearest => match frac.partial_cmp(&0.5) {
+            Some(Ordering::Less) => lower,
+            Some(Ordering::Equal) if i % 2 == 0 => lower,
+            _ => higher,
+        },
+        QuantileMethod::Midpoint => interpolate(0.5),
+        QuantileMethod::Linear => interpolate(frac),
+    }
+}
@@ -0,0 +1,20 @@
+// Copyright 2014-2024 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+/// Whether a cumulative scan includes the current element in each result, see
+/// [`ArrayRef::scan_axis`](crate::ArrayRef::scan_axis).
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ScanMode
+{
+    /// Each result includes the element at its own position:
+    /// `[f(init, x0), f(f(init, x0), x1), ...]`.
+    Inclusive,
+    /// Each result only includes the elements before its own position:
+    /// `[init, f(init, x0), ...]`.
+    Exclusive,
+}
@@ -0,0 +1,171 @@
+// Copyright 2014-2024 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+use num_traits::{Signed, Zero};
+use std::ops::Add;
+
+use crate::numeric_util;
+
+/// Number of elements that pairwise summation adds up directly.
+const PAIRWISE_BLOCK: usize = 128;
+
+/// A method for summing a sequence of elements.
+///
+/// The summation methods are used with [`ArrayRef::sum_with`](crate::ArrayRef::sum_with) and related
+/// methods, to choose between speed and accuracy for each call:
+///
+/// - [`Naive`]: the same unrolled accumulation as [`ArrayRef::sum`](crate::ArrayRef::sum); the
+///   error grows linearly with the number of elements.
+/// - [`Pairwise`]: sums blocks of elements and adds the block sums pairwise;
+///   the error grows logarithmically with the number of elements, at almost
+///   the speed of `Naive`.
+/// - [`Compensated`]: Kahan–Neumaier compensated summation; the error is
+///   independent of the number of elements, at a few times the cost.
+pub trait Summation<A>: Copy
+{
+    /// Sum the elements of `iter`, in order.
+    fn sum_iter<I>(self, iter: I) -> A
+    where I: IntoIterator<Item = A>;
+
+    /// Sum the elements of the slice `xs`.
+    fn sum_slice(self, xs: &[A]) -> A;
+}
+
+/// Plain (unrolled) summation, see [`Summation`].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Naive;
+
+/// Pairwise summation, see [`Summation`].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Pairwise;
+
+/// Kahan–Neumaier compensated summation, see [`Summation`].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Compensated;
+
+impl<A> Summation<A> for Naive
+where A: Clone + Zero + Add<Output = A>
+{
+    fn sum_iter<I>(self, iter: I) -> A
+    where I: IntoIterator<Item = A>
+    {
+        iter.into_iter().fold(A::zero(), |acc, x| acc + x)
+    }
+
+    fn sum_slice(self, xs: &[A]) -> A
+    {
+        numeric_util::unrolled_fold(xs, A::zero, A::add)
+    }
+}
+
+impl<A> Summation<A> for Pairwise
+where A: Clone + Zero + Add<Output = A>
+{
+    fn sum_iter<I>(self, iter: I) -> A
+    where I: IntoIterator<Item = A>
+    {
+        // Sum blocks of elements, and combine the block sums like the carries
+        // of a binary counter: each entry holds the sum of 2^level blocks.
+        let mut iter = iter.into_iter();
+        let mut partials: Vec<(u32, A)> = Vec::new();
+        loop {
+            let mut n = 0;
+            let mut sum = A::zero();
+            for x in iter.by_ref().take(PAIRWISE_BLOCK) {
+                sum = sum + x;
+                n += 1;
+            }
+            if n == 0 {
+                break;
+            }
+            let mut level = 0;
+            while let Some(&(top_level, _)) = partials.last() {
+                if top_level != level {
+                    break;
+                }
+                let (_, top) = partials.pop().unwrap();
+                sum = top + sum;
+                level += 1;
+            }
+            partials.push((level, sum));
+            if n < PAIRWISE_BLOCK {
+                break;
+            }
+        }
+        partials
+            .into_iter()
+            .rev()
+            .fold(A::zero(), |acc, (_, sum)| sum + acc)
+    }
+
+    fn sum_slice(self, xs: &[A]) -> A
+    {
+        if xs.len() <= PAIRWISE_BLOCK {
+            numeric_util::unrolled_fold(xs, A::zero, A::add)
+        } else {
+            let (head, tail) = xs.split_at(xs.len() / 2);
+            self.sum_slice(head) + self.sum_slice(tail)
+        }
+    }
+}
+
+impl<A> Summation<A> for Compensated
+where A: Clone + Signed + PartialOrd
+{
+    fn sum_iter<I>(self, iter: I) -> A
+    where I: IntoIterator<Item = A>
+    {
+        let mut sum = A::zero();
+        let mut compensation = A::zero();
+        for x in iter {
+            let (t, err) = two_sum(sum, x);
+            // Fold the compensation back into the sum, so that it stays
+            // smaller than the last bit of the sum and does not accumulate
+            // rounding errors of its own.
+            let (t, err) = two_sum(t, compensation + err);
+            sum = t;
+            compensation = err;
+        }
+        sum + compensation
+    }
+
+    fn sum_slice(self, xs: &[A]) -> A
+    {
+        self.sum_iter(xs.iter().cloned())
+    }
+}
+
+/// Return `a + b` and the rounding error of the addition.
+fn two_sum<A>(a: A, b: A) -> (A, A)
+where A: Clone + Signed + PartialOrd
+{
+    let t = a.clone() + b.clone();
+    // An infinite or NaN sum has no rounding error to recover, and the
+    // formulas below would turn it into NaN.
+    if !is_finite(&t) {
+        return (t, A::zero());
+    }
+    // Recover the low-order bits lost in the addition from whichever operand
+    // is larger in magnitude.
+    let err = if a.abs() >= b.abs() {
+        (a - t.clone()) + b
+    } else {
+        (b - t.clone()) + a
+    };
+    (t, err)
+}
+
+/// Return `true` if `x` is neither infinite nor NaN; `x - x` is zero for any
+/// finite number, and integers are always finite.
+fn is_finite<A>(x: &A) -> bool
+where A: Clone + Signed + PartialOrd
+{
+    x.clone() - x.clone() == A::zero()
+}
@@ -0,0 +1,462 @@
+// Copyright 2014-2024 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Padding arrays, either into a new array or as a lazy producer.
+
+use std::mem::MaybeUninit;
+
+use crate::imp_prelude::*;
+use crate::indexes::IndexPtr;
+use crate::split_at::SplitAt;
+use crate::{IntoDimension, Layout, NdProducer, Slice, Zip};
+
+/// How the border is filled in by [`ArrayRef::pad`] and
+/// [`ArrayRef::padded`].
+///
+/// The examples show how `[1, 2, 3]` is padded with two elements on each
+/// side.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PadMode<A>
+{
+    /// Fill the border with a constant value: `0 0 | 1 2 3 | 0 0`.
+    Constant(A),
+    /// Repeat the element at the edge: `1 1 | 1 2 3 | 3 3`.
+    Edge,
+    /// Mirror the elements about the edge, without repeating the edge:
+    /// `3 2 | 1 2 3 | 2 1`.
+    Reflect,
+    /// Mirror the elements about the edge, repeating the edge:
+    /// `2 1 | 1 2 3 | 3 2`.
+    Symmetric,
+    /// Wrap around to the other end of the axis: `2 3 | 1 2 3 | 1 2`.
+    Wrap,
+}
+
+impl<A> PadMode<A>
+{
+    /// Return the index in an axis of length `n` that the (possibly out of
+    /// bounds) index `i` is filled in from, or `None` if it is filled with
+    /// the constant.
+    #[inline]
+    fn source_index(&self, i: isize, n: usize) -> Option<usize>
+    {
+        let n = n as isize;
+        if 0 <= i && i < n {
+            return Some(i as usize);
+        }
+        let i = match *self {
+            PadMode::Constant(_) => return None,
+            PadMode::Edge => i.clamp(0, n - 1),
+            PadMode::Reflect if n == 1 => 0,
+            PadMode::Reflect => {
+                let period = 2 * (n - 1);
+                let i = i.rem_euclid(period);
+                if i < n {
+                    i
+                } else {
+                    period - i
+                }
+            }
+            PadMode::Symmetric => {
+                let period = 2 * n;
+                let i = i.rem_euclid(period);
+                if i < n {
+                    i
+                } else {
+                    period - 1 - i
+                }
+            }
+            PadMode::Wrap => i.rem_euclid(n),
+        };
+        Some(i as usize)
+    }
+}
+
+/// Check that there is one pad width for each axis of `dim`, and that only
+/// the constant mode pads an empty axis; return the padded shape.
+#[track_caller]
+fn padded_dim<A, D>(dim: &D, widths: &[(usize, usize)], mode: &PadMode<A>) -> D
+where D: Dimension
+{
+    assert_eq!(
+        widths.len(),
+        dim.ndim(),
+        "ndarray: expected one pad width for each of the {} axes, but got {}",
+        dim.ndim(),
+        widths.len()
+    );
+    let mut padded = dim.clone();
+    for (axis, &(before, after)) in widths.iter().enumerate() {
+        if dim[axis] == 0 && before + after > 0 && !matches!(mode, PadMode::Constant(_)) {
+            panic!("ndarray: can't pad the empty axis {} except with a constant", axis);
+        }
+        padded[axis] += before + after;
+    }
+    padded
+}
+
+/// Clone the initialized slab at index `j` along `axis` of `src` into the
+/// slab at index `i` of `dst`.
+fn clone_slab<A, D>(
+    dst: &mut ArrayRef<MaybeUninit<A>, D>, i: usize, src: &ArrayRef<MaybeUninit<A>, D>, j: usize, axis: Axis,
+) where
+    A: Clone,
+    D: Dimension,
+{
+    let mut dst = dst.view_mut();
+    dst.collapse_axis(axis, i);
+    let mut src = src.view();
+    src.collapse_axis(axis, j);
+    Zip::from(dst).and(src).for_each(|x, y| {
+        // Safety: the caller only passes slabs of `src` that are initialized
+        *x = MaybeUninit::new(unsafe { y.assume_init_ref() }.clone());
+    });
+}
+
+/// # Padding
+///
+/// Both methods take the number of elements to add `(before, after)` the
+/// array along each axis, and the [`PadMode`] that fills in the border.
+impl<A, D> ArrayRef<A, D>
+where D: Dimension
+{
+    /// Return a copy of the array, padded with `widths[i].0` elements before
+    /// and `widths[i].1` elements after it along each axis `i`.
+    ///
+    /// See [`.padded()`](Self::padded) for a lazy version of the padded
+    /// array, which doesn't allocate.
+    ///
+    /// ```
+    /// use ndarray::{array, PadMode};
+    ///
+    /// let a = array![[1, 2],
+    ///                [3, 4]];
+    /// assert_eq!(a.pad(&[(1, 0), (0, 2)], PadMode::Constant(0)),
+    ///            array![[0, 0, 0, 0],
+    ///                   [1, 2, 0, 0],
+    ///                   [3, 4, 0, 0]]);
+    /// assert_eq!(a.pad(&[(1, 1), (1, 1)], PadMode::Edge),
+    ///            array![[1, 1, 2, 2],
+    ///                   [1, 1, 2, 2],
+    ///                   [3, 3, 4, 4],
+    ///                   [3, 3, 4, 4]]);
+    ///
+    /// let b = array![1, 2, 3];
+    /// assert_eq!(b.pad(&[(2, 2)], PadMode::Reflect), array![3, 2, 1, 2, 3, 2, 1]);
+    /// assert_eq!(b.pad(&[(2, 2)], PadMode::Symmetric), array![2, 1, 1, 2, 3, 3, 2]);
+    /// assert_eq!(b.pad(&[(2, 2)], PadMode::Wrap), array![2, 3, 1, 2, 3, 1, 2]);
+    /// ```
+    ///
+    /// **Panics** if the length of `widths` is not the number of axes, if an
+    /// empty axis is padded in a mode other than [`PadMode::Constant`], or if
+    /// the padded shape would overflow `isize`.
+    #[track_caller]
+    pub fn pad(&self, widths: &[(usize, usize)], mode: PadMode<A>) -> Array<A, D>
+    where A: Clone
+    {
+        let mut res = Array::uninit(padded_dim(self._dim(), widths, &mode));
+        let centre = |axis: Axis| Slice::from(widths[axis.index()].0..widths[axis.index()].0 + self.len_of(axis));
+        self.assign_to(res.slice_each_axis_mut(|ax| centre(ax.axis)));
+
+        // Pad one axis at a time: the axes before `axis` are already padded,
+        // and they are copied along with the centre of the axes after it.
+        for (axis, &(before, after)) in widths.iter().enumerate() {
+            let axis = Axis(axis);
+            let n = self.len_of(axis);
+            let part = res.slice_each_axis_mut(|ax| {
+                if ax.axis > axis {
+                    centre(ax.axis)
+                } else {
+                    Slice::from(..)
+                }
+            });
+            let (mut lo, rest) = part.split_at(axis, before);
+            let (mid, mut hi) = rest.split_at(axis, n);
+            if let PadMode::Constant(value) = &mode {
+                lo.map_inplace(|x| *x = MaybeUninit::new(value.clone()));
+                hi.map_inplace(|x| *x = MaybeUninit::new(value.clone()));
+                continue;
+            }
+            for i in 0..before {
+                let source = mode.source_index(i as isize - before as isize, n).unwrap();
+                clone_slab(&mut lo, i, &mid, source, axis);
+            }
+            for i in 0..after {
+                let source = mode.source_index((n + i) as isize, n).unwrap();
+                clone_slab(&mut hi, i, &mid, source, axis);
+            }
+        }
+        // Safety: the centre and the border along each axis are assigned above
+        unsafe { res.assume_init() }
+    }
+
+    /// Return a producer of the elements of the array, padded with
+    /// `widths[i].0` elements before and `widths[i].1` elements after it
+    /// along each axis `i`.
+    ///
+    /// The padded array is never materialised: the producer computes each
+    /// element of the border from the [`PadMode`] when it is visited. It can
+    /// be used with [`Zip`], and [`.windows()`](PaddedView::windows) splits it
+    /// into overlapping windows, which is the usual setup for a convolution
+    /// that keeps the shape of its input.
+    ///
+    /// The elements are produced by value, cloned from the array.
+    ///
+    /// ```
+    /// use ndarray::{array, Array2, PadMode, Zip};
+    ///
+    /// let a = array![[1, 2, 3],
+    ///                [4, 5, 6]];
+    ///
+    /// // Sum over each 3 × 3 neighbourhood, treating the outside as zero
+    /// let padded = a.padded(&[(1, 1), (1, 1)], PadMode::Constant(0));
+    /// let sums: Array2<i32> = Zip::from(padded.windows((3, 3)))
+    ///     .map_collect(|w| Zip::from(w).fold(0, |acc, x| acc + x));
+    /// assert_eq!(sums, array![[12, 21, 16],
+    ///                         [12, 21, 16]]);
+    ///
+    /// // The same with periodic boundaries
+    /// let padded = a.padded(&[(1, 1), (1, 1)], PadMode::Wrap);
+    /// let sums: Array2<i32> = Zip::from(padded.windows((3, 3)))
+    ///     .map_collect(|w| Zip::from(w).fold(0, |acc, x| acc + x));
+    /// assert_eq!(sums, array![[36, 36, 36],
+    ///                         [27, 27, 27]]);
+    /// ```
+    ///
+    /// **Panics** if the length of `widths` is not the number of axes, or if
+    /// an empty axis is padded in a mode other than [`PadMode::Constant`].
+    #[track_caller]
+    pub fn padded(&self, widths: &[(usize, usize)], mode: PadMode<A>) -> PaddedView<'_, A, D>
+    where
+        A: Clone,
+        D: Copy,
+    {
+        let dim = padded_dim(self._dim(), widths, &mode);
+        let mut before = D::zeros(self.ndim());
+        for (b, &(w, _)) in before.slice_mut().iter_mut().zip(widths) {
+            *b = w;
+        }
+        PaddedView {
+            view: self.view(),
+            mode,
+            before,
+            start: D::zeros(self.ndim()),
+            dim,
+        }
+    }
+}
+
+/// A lazily padded array: a producer of the elements of an array, with the
+/// border filled in from a [`PadMode`].
+///
+/// See [`.padded()`](crate::ArrayRef::padded) for more information.
+#[derive(Clone)]
+pub struct PaddedView<'a, A, D>
+{
+    view: ArrayView<'a, A, D>,
+    mode: PadMode<A>,
+    /// The padding before the array along each axis.
+    before: D,
+    /// The index of the first element of this producer in the padded array;
+    /// it is not zero for a window or a part after a split.
+    start: D,
+    dim: D,
+}
+
+impl<'a, A, D> PaddedView<'a, A, D>
+where
+    A: Clone,
+    D: Dimension + Copy,
+{
+    /// Return the shape of the padded array.
+    pub fn shape(&self) -> &[usize]
+    {
+        self.dim.slice()
+    }
+
+    /// Return a producer of all the windows of size `window_size` that fit
+    /// into the padded array, like [`.windows()`](crate::ArrayRef::windows).
+    ///
+    /// Each window is itself a `PaddedView` of the elements it covers.
+    ///
+    /// **Panics** if any dimension of `window_size` is zero.
+    #[track_caller]
+    pub fn windows<E>(&self, window_size: E) -> PaddedWindows<'a, A, D>
+    where E: IntoDimension<Dim = D>
+    {
+        let window = window_size.into_dimension();
+        assert!(
+            window.slice().iter().all(|&w| w != 0),
+            "ndarray: window size must not be zero, got {:?}",
+            window.slice()
+        );
+        let mut origins = self.clone();
+        for (d, &w) in origins.dim.slice_mut().iter_mut().zip(window.slice()) {
+            *d = if *d >= w { *d - w + 1 } else { 0 };
+        }
+        PaddedWindows { origins, window }
+    }
+
+    /// Return the element at `index` in the padded array.
+    fn get(&self, index: D) -> A
+    {
+        let mut source = index;
+        for (axis, s) in source.slice_mut().iter_mut().enumerate() {
+            let i = index[axis] as isize - self.before[axis] as isize;
+            match self.mode.source_index(i, self.view.len_of(Axis(axis))) {
+                Some(i) => *s = i,
+                None => match &self.mode {
+                    PadMode::Constant(value) => return value.clone(),
+                    _ => unreachable!(),
+                },
+            }
+        }
+        // Safety: `source_index` returns indices that are in bounds
+        unsafe { self.view.uget(source).clone() }
+    }
+}
+
+// The producer uses the index into the padded array as its pointer, like
+// `Indices`, and computes the element at that index in `as_ref`.
+impl<'a, A, D> NdProducer for PaddedView<'a, A, D>
+where
+    A: Clone,
+    D: Dimension + Copy,
+{
+    type Item = A;
+    type Dim = D;
+    type Ptr = IndexPtr<D>;
+    type Stride = usize;
+
+    private_impl! {}
+
+    fn raw_dim(&self) -> Self::Dim
+    {
+        self.dim
+    }
+
+    fn as_ptr(&self) -> Self::Ptr
+    {
+        IndexPtr { index: self.start }
+    }
+
+    fn layout(&self) -> Layout
+    {
+        if self.dim.ndim() <= 1 {
+            Layout::one_dimensional()
+        } else {
+            Layout::none()
+        }
+    }
+
+    unsafe fn as_ref(&self, ptr: Self::Ptr) -> Self::Item
+    {
+        self.get(ptr.index)
+    }
+
+    unsafe fn uget_ptr(&self, i: &Self::Dim) -> Self::Ptr
+    {
+        let mut index = *i;
+        index += &self.start;
+        IndexPtr { index }
+    }
+
+    fn stride_of(&self, axis: Axis) -> Self::Stride
+    {
+        axis.index()
+    }
+
+    #[inline(always)]
+    fn contiguous_stride(&self) -> Self::Stride
+    {
+        0
+    }
+
+    fn split_at(self, axis: Axis, index: usize) -> (Self, Self)
+    {
+        let (dim_a, dim_b) = self.dim.split_at(axis, index);
+        let mut b = self.clone();
+        b.start[axis.index()] += index;
+        b.dim = dim_b;
+        (PaddedView { dim: dim_a, ..self }, b)
+    }
+}
+
+/// Window producer of a lazily padded array.
+///
+/// See [`PaddedView::windows`] for more information.
+#[derive(Clone)]
+pub struct PaddedWindows<'a, A, D>
+{
+    /// The origins of the windows, as a padded view with one element for
+    /// each window.
+    origins: PaddedView<'a, A, D>,
+    window: D,
+}
+
+impl<'a, A, D> NdProducer for PaddedWindows<'a, A, D>
+where
+    A: Clone,
+    D: Dimension + Copy,
+{
+    type Item = PaddedView<'a, A, D>;
+    type Dim = D;
+    type Ptr = IndexPtr<D>;
+    type Stride = usize;
+
+    private_impl! {}
+
+    fn raw_dim(&self) -> Self::Dim
+    {
+        self.origins.raw_dim()
+    }
+
+    fn as_ptr(&self) -> Self::Ptr
+    {
+        self.origins.as_ptr()
+    }
+
+    fn layout(&self) -> Layout
+    {
+        self.origins.layout()
+    }
+
+    unsafe fn as_ref(&self, ptr: Self::Ptr) -> Self::Item
+    {
+        PaddedView {
+            view: self.origins.view,
+            mode: self.origins.mode.clone(),
+            before: self.origins.before,
+            start: ptr.index,
+            dim: self.window,
+        }
+    }
+
+    unsafe fn uget_ptr(&self, i: &Self::Dim) -> Self::Ptr
+    {
+        self.origins.uget_ptr(i)
+    }
+
+    fn stride_of(&self, axis: Axis) -> Self::Stride
+    {
+        self.origins.stride_of(axis)
+    }
+
+    #[inline(always)]
+    fn contiguous_stride(&self) -> Self::Stride
+    {
+        self.origins.contiguous_stride()
+    }
+
+    fn split_at(self, axis: Axis, index: usize) -> (Self, Self)
+    {
+        let window = self.window;
+        let (a, b) = self.origins.split_at(axis, index);
+        (PaddedWindows { origins: a, window }, PaddedWindows { origins: b, window })
+    }
+}
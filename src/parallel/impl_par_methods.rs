@@ -1,5 +1,8 @@
+use std::cmp::Ordering;
+
+use crate::sort::{argsort_lane, gather_lane, sort_lane, top_k_lane};
 use crate::AssignElem;
-use crate::{Array, ArrayRef, Dimension, IntoNdProducer, NdProducer, Zip};
+use crate::{Array, ArrayRef, Axis, Dimension, IntoNdProducer, NdProducer, Zip};
 
 use super::send_producer::SendProducer;
 use crate::parallel::par::ParallelSplits;
@@ -41,6 +44,136 @@ where
     }
 }
 
+/// # Parallel sorting
+impl<A, D> ArrayRef<A, D>
+where
+    D: Dimension,
+    A: Send + Sync,
+{
+    /// Parallel version of [`sort_axis`](Self::sort_axis).
+    ///
+    /// Sort the array in place along `axis`, sorting the lanes in parallel.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    #[track_caller]
+    pub fn par_sort_axis(&mut self, axis: Axis)
+    where A: Clone + Ord
+    {
+        self.par_sort_axis_by(axis, A::cmp)
+    }
+
+    /// Parallel version of [`sort_axis_by`](Self::sort_axis_by).
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    #[track_caller]
+    pub fn par_sort_axis_by<F>(&mut self, axis: Axis, compare: F)
+    where
+        A: Clone,
+        F: Fn(&A, &A) -> Ordering + Sync,
+    {
+        Zip::from(self.lanes_mut(axis))
+            .into_par_iter()
+            .for_each_init(Vec::new, |buf, (lane,)| sort_lane(lane, buf, |xs| xs.sort_by(&compare)));
+    }
+
+    /// Parallel version of [`sort_unstable_axis`](Self::sort_unstable_axis).
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    #[track_caller]
+    pub fn par_sort_unstable_axis(&mut self, axis: Axis)
+    where A: Clone + Ord
+    {
+        self.par_sort_unstable_axis_by(axis, A::cmp)
+    }
+
+    /// Parallel version of [`sort_unstable_axis_by`](Self::sort_unstable_axis_by).
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    #[track_caller]
+    pub fn par_sort_unstable_axis_by<F>(&mut self, axis: Axis, compare: F)
+    where
+        A: Clone,
+        F: Fn(&A, &A) -> Ordering + Sync,
+    {
+        Zip::from(self.lanes_mut(axis))
+            .into_par_iter()
+            .for_each_init(Vec::new, |buf, (lane,)| sort_lane(lane, buf, |xs| xs.sort_unstable_by(&compare)));
+    }
+
+    /// Parallel version of [`argsort_axis`](Self::argsort_axis).
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    #[track_caller]
+    pub fn par_argsort_axis(&self, axis: Axis) -> Array<usize, D>
+    where A: Ord
+    {
+        self.par_argsort_axis_by(axis, A::cmp)
+    }
+
+    /// Parallel version of [`argsort_axis_by`](Self::argsort_axis_by).
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    #[track_caller]
+    pub fn par_argsort_axis_by<F>(&self, axis: Axis, compare: F) -> Array<usize, D>
+    where F: Fn(&A, &A) -> Ordering + Sync
+    {
+        let mut indices = Array::zeros(self.raw_dim());
+        Zip::from(indices.lanes_mut(axis))
+            .and(self.lanes(axis))
+            .into_par_iter()
+            .for_each_init(Vec::new, |perm, (out, lane)| argsort_lane(lane, out, perm, &compare));
+        indices
+    }
+
+    /// Parallel version of [`select_nth_axis`](Self::select_nth_axis).
+    ///
+    /// **Panics** if `axis` is out of bounds or if `n` is not less than the
+    /// length of the axis.
+    #[track_caller]
+    pub fn par_select_nth_axis(&mut self, axis: Axis, n: usize)
+    where A: Clone + Ord
+    {
+        let axis_len = self.len_of(axis);
+        assert!(n < axis_len, "ndarray: index {} is out of bounds for axis of length {}", n, axis_len);
+        Zip::from(self.lanes_mut(axis))
+            .into_par_iter()
+            .for_each_init(Vec::new, |buf, (lane,)| {
+                sort_lane(lane, buf, |xs| {
+                    xs.select_nth_unstable(n);
+                })
+            });
+    }
+
+    /// Parallel version of [`top_k_axis`](Self::top_k_axis).
+    ///
+    /// **Panics** if `axis` is out of bounds or if `k` is greater than the
+    /// length of the axis.
+    #[track_caller]
+    pub fn par_top_k_axis(&self, axis: Axis, k: usize) -> (Array<A, D>, Array<usize, D>)
+    where A: Clone + Ord
+    {
+        let axis_len = self.len_of(axis);
+        assert!(k <= axis_len, "ndarray: k = {} is greater than the axis length {}", k, axis_len);
+        let mut dim = self.raw_dim();
+        dim[axis.index()] = k;
+
+        let mut indices = Array::zeros(dim.clone());
+        Zip::from(indices.lanes_mut(axis))
+            .and(self.lanes(axis))
+            .into_par_iter()
+            .for_each_init(Vec::new, |perm, (out, lane)| top_k_lane(lane, out, perm));
+
+        let mut values = Array::uninit(dim);
+        Zip::from(values.lanes_mut(axis))
+            .and(indices.lanes(axis))
+            .and(self.lanes(axis))
+            .par_for_each(gather_lane);
+        // Safety: every element of `values` was written above
+        let values = unsafe { values.assume_init() };
+        (values, indices)
+    }
+}
+
 // Zip
 
 const COLLECT_MAX_SPLITS: usize = 10;
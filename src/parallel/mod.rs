@@ -21,6 +21,8 @@
 //!
 //! - [`ArrayRef::par_map_inplace()`](crate::ArrayRef::par_map_inplace)
 //! - [`ArrayRef::par_mapv_inplace()`](crate::ArrayRef::par_mapv_inplace)
+//! - [`ArrayRef::par_sort_axis()`](crate::ArrayRef::par_sort_axis) and the other
+//!   parallel sorting methods
 //! - [`Zip::par_for_each()`] (all arities)
 //! - [`Zip::par_map_collect()`] (all arities)
 //! - [`Zip::par_map_assign_into()`] (all arities)
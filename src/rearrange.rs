@@ -0,0 +1,217 @@
+// Copyright 2014-2024 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Flipping, rotating and rolling the elements of an array.
+
+use crate::imp_prelude::*;
+use crate::{ArrayRef1, Slice};
+
+/// # Rearranging elements
+///
+/// [`.flip()`](Self::flip) and [`.rot90()`](Self::rot90) return views that
+/// only adjust the strides of the array, like [`.t()`](Self::t), so they
+/// never copy. [`.roll()`](Self::roll) returns a new array, while
+/// [`.roll_inplace()`](Self::roll_inplace) moves the elements within the
+/// array itself.
+impl<A, D> ArrayRef<A, D>
+where D: Dimension
+{
+    /// Return a view of the array with the order of the elements reversed
+    /// along each of `axes`.
+    ///
+    /// This does not move any data, it just inverts the strides of the axes;
+    /// see also [`.invert_axis()`](LayoutRef::invert_axis) for the in-place
+    /// version of a single axis.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![[1, 2, 3],
+    ///                [4, 5, 6]];
+    /// assert_eq!(a.flip(&[Axis(1)]), array![[3, 2, 1],
+    ///                                       [6, 5, 4]]);
+    /// assert_eq!(a.flip(&[Axis(0), Axis(1)]), array![[6, 5, 4],
+    ///                                                [3, 2, 1]]);
+    /// ```
+    ///
+    /// **Panics** if an axis is out of bounds or is repeated in `axes`.
+    #[track_caller]
+    pub fn flip(&self, axes: &[Axis]) -> ArrayView<'_, A, D>
+    {
+        let mut view = self.view();
+        for (i, &axis) in axes.iter().enumerate() {
+            assert!(
+                axis.index() < self.ndim(),
+                "ndarray: axis {} is out of bounds for array of dimension {}",
+                axis.index(),
+                self.ndim()
+            );
+            assert!(!axes[..i].contains(&axis), "ndarray: axis {} is repeated", axis.index());
+            view.invert_axis(axis);
+        }
+        view
+    }
+
+    /// Return a view of the array rotated by 90 degrees `k` times in the
+    /// plane of `axes`.
+    ///
+    /// The rotation goes from the first axis towards the second: for an
+    /// image with rows along `Axis(0)` and columns along `Axis(1)`,
+    /// `rot90(1, (Axis(0), Axis(1)))` rotates it counterclockwise. A
+    /// negative `k` rotates the other way.
+    ///
+    /// Like [`.flip()`](Self::flip) this only adjusts the dimensions and
+    /// strides, using axis swaps and reversals.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![[1, 2],
+    ///                [3, 4]];
+    /// let plane = (Axis(0), Axis(1));
+    /// assert_eq!(a.rot90(1, plane), array![[2, 4],
+    ///                                      [1, 3]]);
+    /// assert_eq!(a.rot90(2, plane), array![[4, 3],
+    ///                                      [2, 1]]);
+    /// assert_eq!(a.rot90(-1, plane), a.rot90(3, plane));
+    /// ```
+    ///
+    /// **Panics** if an axis is out of bounds or if the two axes are equal.
+    #[track_caller]
+    pub fn rot90(&self, k: isize, axes: (Axis, Axis)) -> ArrayView<'_, A, D>
+    {
+        let (ax0, ax1) = axes;
+        assert!(
+            ax0.index() < self.ndim() && ax1.index() < self.ndim(),
+            "ndarray: axes ({}, {}) are out of bounds for array of dimension {}",
+            ax0.index(),
+            ax1.index(),
+            self.ndim()
+        );
+        assert_ne!(ax0, ax1, "ndarray: the axes of rotation must be different");
+        let mut view = self.view();
+        match k.rem_euclid(4) {
+            1 => {
+                view.invert_axis(ax1);
+                view.swap_axes(ax0.index(), ax1.index());
+            }
+            2 => {
+                view.invert_axis(ax0);
+                view.invert_axis(ax1);
+            }
+            3 => {
+                view.swap_axes(ax0.index(), ax1.index());
+                view.invert_axis(ax1);
+            }
+            _ => {}
+        }
+        view
+    }
+
+    /// Return a copy of the array with the elements shifted by `shift`
+    /// positions along `axis`.
+    ///
+    /// Elements that move past the end of the axis wrap around to the
+    /// start, so that the element at index `i` ends up at index
+    /// `(i + shift) mod n`, where `n` is the length of the axis. A negative
+    /// `shift` moves the elements towards the start.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![[1, 2, 3],
+    ///                [4, 5, 6]];
+    /// assert_eq!(a.roll(1, Axis(1)), array![[3, 1, 2],
+    ///                                       [6, 4, 5]]);
+    /// assert_eq!(a.roll(-1, Axis(1)), array![[2, 3, 1],
+    ///                                        [5, 6, 4]]);
+    /// assert_eq!(a.roll(1, Axis(0)), array![[4, 5, 6],
+    ///                                       [1, 2, 3]]);
+    /// ```
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    #[track_caller]
+    pub fn roll(&self, shift: isize, axis: Axis) -> Array<A, D>
+    where A: Clone
+    {
+        let n = self.len_of(axis);
+        if n == 0 {
+            return self.to_owned();
+        }
+        let s = shift.rem_euclid(n as isize) as usize;
+        let mut res = Array::uninit(self.raw_dim());
+        self.slice_axis(axis, Slice::from(..n - s))
+            .assign_to(res.slice_axis_mut(axis, Slice::from(s..)));
+        self.slice_axis(axis, Slice::from(n - s..))
+            .assign_to(res.slice_axis_mut(axis, Slice::from(..s)));
+        // Safety: the two parts assigned above cover the whole array
+        unsafe { res.assume_init() }
+    }
+
+    /// Shift the elements of the array by `shift` positions along `axis`,
+    /// in place.
+    ///
+    /// This is the in-place version of [`.roll()`](Self::roll), with the same
+    /// wrap-around behaviour, and it does not need the elements to be
+    /// `Clone`. If the array is contiguous in memory, the elements are
+    /// rotated in place with [`slice::rotate_right`]; otherwise each lane
+    /// along `axis` is rotated by swapping elements.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let mut a = array![[1, 2, 3],
+    ///                    [4, 5, 6]];
+    /// a.roll_inplace(1, Axis(1));
+    /// assert_eq!(a, array![[3, 1, 2],
+    ///                      [6, 4, 5]]);
+    /// ```
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    #[track_caller]
+    pub fn roll_inplace(&mut self, shift: isize, axis: Axis)
+    {
+        let n = self.len_of(axis);
+        if n <= 1 || self.is_empty() {
+            return;
+        }
+        let s = shift.rem_euclid(n as isize) as usize;
+        if s == 0 {
+            return;
+        }
+        let stride = self.stride_of(axis);
+        if let Some(data) = self.as_slice_memory_order_mut() {
+            // In a contiguous array, the elements under each step along `axis`
+            // form a block of `|stride|` elements, and the blocks for the
+            // whole axis form one chunk of the data.
+            let step = stride.unsigned_abs();
+            for chunk in data.chunks_exact_mut(n * step) {
+                if stride > 0 {
+                    chunk.rotate_right(s * step);
+                } else {
+                    chunk.rotate_left(s * step);
+                }
+            }
+        } else {
+            for mut lane in self.lanes_mut(axis) {
+                reverse_lane(&mut lane);
+                reverse_lane(&mut lane.slice_axis_mut(Axis(0), Slice::from(..s)));
+                reverse_lane(&mut lane.slice_axis_mut(Axis(0), Slice::from(s..)));
+            }
+        }
+    }
+}
+
+/// Reverse the order of the elements of `lane` in place.
+fn reverse_lane<A>(lane: &mut ArrayRef1<A>)
+{
+    let n = lane.len();
+    for i in 0..n / 2 {
+        lane.swap(i, n - 1 - i);
+    }
+}
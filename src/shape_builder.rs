@@ -1,6 +1,10 @@
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
 use crate::dimension::IntoDimension;
+use crate::error::{self, ShapeError};
 use crate::order::Order;
-use crate::Dimension;
+use crate::{Axis, Dim, Dimension, Ix, Ix1, IxDyn};
 
 /// A contiguous array shape of n dimensions.
 ///
@@ -218,7 +222,8 @@ where D: Dimension
 /// Shape or array dimension argument, with optional [`Order`] parameter.
 ///
 /// This is an argument conversion trait that is used to accept an array shape and
-/// (optionally) an ordering argument.
+/// (optionally) an ordering argument. The shape may also be a [`ShapeSpec`], that infers
+/// the length of one axis from the number of elements of the array.
 ///
 /// See for example [`.to_shape()`](crate::ArrayRef::to_shape).
 pub trait ShapeArg
@@ -228,6 +233,18 @@ pub trait ShapeArg
 
     /// Convert the argument into a shape and an [`Order`].
     fn into_shape_and_order(self) -> (Self::Dim, Option<Order>);
+
+    /// Convert the argument into a shape and an [`Order`], for an array of `len` elements.
+    ///
+    /// **Errors** if the length of an inferred axis can't be found from `len`. Whether the
+    /// shape has `len` elements is left to the caller to check.
+    #[doc(hidden)]
+    fn into_shape_and_order_for_len(self, len: usize) -> Result<(Self::Dim, Option<Order>), ShapeError>
+    where Self: Sized
+    {
+        let _ = len;
+        Ok(self.into_shape_and_order())
+    }
 }
 
 impl<T> ShapeArg for T
@@ -251,3 +268,194 @@ where T: IntoDimension
         (self.0.into_dimension(), Some(self.1))
     }
 }
+
+/// An array shape where the length of (at most) one axis is inferred from the number of
+/// elements of the array, when it is reshaped.
+///
+/// A `ShapeSpec` is created from the lengths of the axes as `isize`, where `-1` marks the
+/// axis to infer, like in NumPy; or with [`ShapeSpec::infer`], which is often more
+/// convenient in generic code.
+///
+/// It can be used with [`.to_shape()`](crate::ArrayRef::to_shape),
+/// [`.into_shape_with_order()`](crate::ArrayBase::into_shape_with_order) and
+/// [`.into_shape_clone()`](crate::ArrayBase::into_shape_clone), with or without an
+/// [`Order`].
+///
+/// ```
+/// use ndarray::{Array, Axis, Order, ShapeSpec};
+///
+/// let a = Array::from_iter(0..24);
+/// let b = a.to_shape(ShapeSpec::from((2, -1, 4))).unwrap();
+/// assert_eq!(b.shape(), &[2, 3, 4]);
+///
+/// // Flatten all axes but the first, for an array of any dimension
+/// let c = b.to_shape((ShapeSpec::infer((b.len_of(Axis(0)), 0), Axis(1)), Order::RowMajor)).unwrap();
+/// assert_eq!(c.shape(), &[2, 12]);
+///
+/// // The error tells which sizes don't fit
+/// let err = a.to_shape(ShapeSpec::from([5, -1])).unwrap_err();
+/// assert!(err.to_string().contains("24 elements are not a multiple of 5"));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ShapeSpec<D>
+{
+    /// The shape, with a length of zero for the inferred axis
+    dim: D,
+    infer: Option<usize>,
+}
+
+impl<D> ShapeSpec<D>
+where D: Dimension
+{
+    /// Create a shape spec from the shape `shape`, where the length of `axis` is inferred;
+    /// the length of `axis` in `shape` is ignored.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    #[track_caller]
+    pub fn infer<E>(shape: E, axis: Axis) -> Self
+    where E: IntoDimension<Dim = D>
+    {
+        let mut dim = shape.into_dimension();
+        assert!(
+            axis.index() < dim.ndim(),
+            "ndarray: axis {} is out of bounds for a shape of dimension {}",
+            axis.index(),
+            dim.ndim()
+        );
+        dim[axis.index()] = 0;
+        ShapeSpec {
+            dim,
+            infer: Some(axis.index()),
+        }
+    }
+
+    /// Create a shape spec from `lengths`, where `-1` marks the inferred axis.
+    #[track_caller]
+    fn from_lengths(mut dim: D, lengths: &[isize]) -> Self
+    {
+        let mut infer = None;
+        for (axis, (d, &n)) in dim.slice_mut().iter_mut().zip(lengths).enumerate() {
+            if n >= 0 {
+                *d = n as usize;
+            } else if n == -1 {
+                if let Some(first) = infer {
+                    panic!("ndarray: only one axis can be inferred, but both axis {} and {} are -1", first, axis);
+                }
+                infer = Some(axis);
+                *d = 0;
+            } else {
+                panic!("ndarray: invalid length {} for axis {}, expected -1 or a length", n, axis);
+            }
+        }
+        ShapeSpec { dim, infer }
+    }
+
+    /// Return the shape for an array of `len` elements.
+    fn resolve(self, len: usize) -> Result<D, ShapeError>
+    {
+        let mut dim = self.dim;
+        if let Some(axis) = self.infer {
+            dim[axis] = 1;
+            let size = match dim.size_checked() {
+                Some(size) => size,
+                None => return Err(error::incompatible_shapes(&Ix1(len), &dim)),
+            };
+            if size == 0 || len % size != 0 {
+                return Err(error::incompatible_inferred_shape(len, size, axis));
+            }
+            dim[axis] = len / size;
+        }
+        Ok(dim)
+    }
+}
+
+/// **Panics** if more than one length is `-1`, or if a length is less than `-1`.
+impl<const N: usize> From<[isize; N]> for ShapeSpec<Dim<[Ix; N]>>
+where Dim<[Ix; N]>: Dimension
+{
+    #[track_caller]
+    fn from(lengths: [isize; N]) -> Self
+    {
+        ShapeSpec::from_lengths(Dim::new([0; N]), &lengths)
+    }
+}
+
+macro_rules! shape_spec_from_tuple {
+    ($($n:literal: ($($index:tt)*))*) => {
+        $(
+        /// **Panics** if more than one length is `-1`, or if a length is less than `-1`.
+        impl From<($(shape_spec_from_tuple!(@isize $index),)*)> for ShapeSpec<Dim<[Ix; $n]>> {
+            #[track_caller]
+            fn from(lengths: ($(shape_spec_from_tuple!(@isize $index),)*)) -> Self {
+                ShapeSpec::from([$(lengths.$index),*])
+            }
+        }
+        )*
+    };
+    (@isize $index:tt) => { isize };
+}
+
+shape_spec_from_tuple! {
+    1: (0)
+    2: (0 1)
+    3: (0 1 2)
+    4: (0 1 2 3)
+    5: (0 1 2 3 4)
+    6: (0 1 2 3 4 5)
+}
+
+/// **Panics** if more than one length is `-1`, or if a length is less than `-1`.
+impl From<&[isize]> for ShapeSpec<IxDyn>
+{
+    #[track_caller]
+    fn from(lengths: &[isize]) -> Self
+    {
+        ShapeSpec::from_lengths(IxDyn::zeros(lengths.len()), lengths)
+    }
+}
+
+/// **Panics** if more than one length is `-1`, or if a length is less than `-1`.
+impl From<Vec<isize>> for ShapeSpec<IxDyn>
+{
+    #[track_caller]
+    fn from(lengths: Vec<isize>) -> Self
+    {
+        ShapeSpec::from(&lengths[..])
+    }
+}
+
+/// Without the number of elements, [`ShapeArg::into_shape_and_order`] returns the shape
+/// with a length of zero for the inferred axis.
+impl<D> ShapeArg for ShapeSpec<D>
+where D: Dimension
+{
+    type Dim = D;
+
+    fn into_shape_and_order(self) -> (Self::Dim, Option<Order>)
+    {
+        (self.dim, None)
+    }
+
+    fn into_shape_and_order_for_len(self, len: usize) -> Result<(Self::Dim, Option<Order>), ShapeError>
+    {
+        Ok((self.resolve(len)?, None))
+    }
+}
+
+/// Without the number of elements, [`ShapeArg::into_shape_and_order`] returns the shape
+/// with a length of zero for the inferred axis.
+impl<D> ShapeArg for (ShapeSpec<D>, Order)
+where D: Dimension
+{
+    type Dim = D;
+
+    fn into_shape_and_order(self) -> (Self::Dim, Option<Order>)
+    {
+        (self.0.dim, Some(self.1))
+    }
+
+    fn into_shape_and_order_for_len(self, len: usize) -> Result<(Self::Dim, Option<Order>), ShapeError>
+    {
+        Ok((self.0.resolve(len)?, Some(self.1)))
+    }
+}
@@ -0,0 +1,301 @@
+// Copyright 2014-2024 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Sorting, argsort and partial sorting along an axis.
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+use std::cmp::Ordering;
+use std::mem::MaybeUninit;
+
+use crate::imp_prelude::*;
+use crate::Zip;
+
+/// # Sorting
+///
+/// These methods sort each lane of the array along an axis independently,
+/// like the sorting methods of slices. They work with arrays of any memory
+/// layout; lanes that are not contiguous are sorted through a temporary
+/// buffer.
+///
+/// With the crate feature `rayon`, there are parallel versions of the methods
+/// with a `par_` prefix.
+impl<A, D> ArrayRef<A, D>
+where D: Dimension
+{
+    /// Sort the array in place along `axis`.
+    ///
+    /// The sort is stable: equal elements keep their relative order. See
+    /// [`slice::sort`] for details.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let mut a = array![[3, 1, 2],
+    ///                    [1, 2, 0]];
+    /// a.sort_axis(Axis(0));
+    /// assert_eq!(a, array![[1, 1, 0],
+    ///                      [3, 2, 2]]);
+    /// a.sort_axis(Axis(1));
+    /// assert_eq!(a, array![[0, 1, 1],
+    ///                      [2, 2, 3]]);
+    /// ```
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    #[track_caller]
+    pub fn sort_axis(&mut self, axis: Axis)
+    where A: Clone + Ord
+    {
+        self.sort_axis_by(axis, A::cmp)
+    }
+
+    /// Sort the array in place along `axis`, with the comparator function
+    /// `compare`.
+    ///
+    /// The sort is stable: equal elements keep their relative order. See
+    /// [`slice::sort_by`] for details.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let mut a = array![[0.5f64, -1., 2.],
+    ///                    [1.5, 0., -3.]];
+    /// a.sort_axis_by(Axis(1), |x, y| y.total_cmp(x));
+    /// assert_eq!(a, array![[2., 0.5, -1.],
+    ///                      [1.5, 0., -3.]]);
+    /// ```
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    #[track_caller]
+    pub fn sort_axis_by<F>(&mut self, axis: Axis, mut compare: F)
+    where
+        A: Clone,
+        F: FnMut(&A, &A) -> Ordering,
+    {
+        let mut buf = Vec::new();
+        Zip::from(self.lanes_mut(axis)).for_each(|lane| sort_lane(lane, &mut buf, |xs| xs.sort_by(&mut compare)));
+    }
+
+    /// Sort the array in place along `axis`, without preserving the order of
+    /// equal elements.
+    ///
+    /// See [`slice::sort_unstable`] for details.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    #[track_caller]
+    pub fn sort_unstable_axis(&mut self, axis: Axis)
+    where A: Clone + Ord
+    {
+        self.sort_unstable_axis_by(axis, A::cmp)
+    }
+
+    /// Sort the array in place along `axis`, with the comparator function
+    /// `compare`, without preserving the order of equal elements.
+    ///
+    /// See [`slice::sort_unstable_by`] for details.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    #[track_caller]
+    pub fn sort_unstable_axis_by<F>(&mut self, axis: Axis, mut compare: F)
+    where
+        A: Clone,
+        F: FnMut(&A, &A) -> Ordering,
+    {
+        let mut buf = Vec::new();
+        Zip::from(self.lanes_mut(axis))
+            .for_each(|lane| sort_lane(lane, &mut buf, |xs| xs.sort_unstable_by(&mut compare)));
+    }
+
+    /// Return the indices that sort the array along `axis`.
+    ///
+    /// Each lane of the result holds the indices of the elements of the
+    /// corresponding lane of `self`, in sorted order. The sort is stable:
+    /// equal elements are listed in increasing order of their indices.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![[3, 1, 2],
+    ///                [1, 2, 1]];
+    /// assert_eq!(a.argsort_axis(Axis(1)), array![[1, 2, 0],
+    ///                                            [0, 2, 1]]);
+    /// assert_eq!(a.argsort_axis(Axis(0)), array![[1, 0, 1],
+    ///                                            [0, 1, 0]]);
+    /// ```
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    #[track_caller]
+    pub fn argsort_axis(&self, axis: Axis) -> Array<usize, D>
+    where A: Ord
+    {
+        self.argsort_axis_by(axis, A::cmp)
+    }
+
+    /// Return the indices that sort the array along `axis`, with the
+    /// comparator function `compare`.
+    ///
+    /// The sort is stable, see [`.argsort_axis()`](Self::argsort_axis).
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    #[track_caller]
+    pub fn argsort_axis_by<F>(&self, axis: Axis, mut compare: F) -> Array<usize, D>
+    where F: FnMut(&A, &A) -> Ordering
+    {
+        let mut indices = Array::zeros(self.raw_dim());
+        let mut perm = Vec::new();
+        Zip::from(indices.lanes_mut(axis))
+            .and(self.lanes(axis))
+            .for_each(|out, lane| argsort_lane(lane, out, &mut perm, &mut compare));
+        indices
+    }
+
+    /// Partially sort the array in place along `axis`, so that the element
+    /// at index `n` of each lane is in its sorted position.
+    ///
+    /// All elements before index `n` in the lane are less than or equal to
+    /// it, and all elements after it are greater than or equal to it; the
+    /// order within each side is unspecified. This is the in-place version of
+    /// [`.partition()`](Self::partition); see [`slice::select_nth_unstable`]
+    /// for details.
+    ///
+    /// ```
+    /// use ndarray::{array, s, Axis};
+    ///
+    /// let mut a = array![[7, 1, 5, 2, 6],
+    ///                    [0, 9, 3, 4, 8]];
+    /// a.select_nth_axis(Axis(1), 2);
+    /// assert_eq!(a.column(2), array![5, 4]);
+    /// for row in a.rows() {
+    ///     assert!(row.slice(s![..2]).iter().all(|&x| x <= row[2]));
+    ///     assert!(row.slice(s![3..]).iter().all(|&x| x >= row[2]));
+    /// }
+    /// ```
+    ///
+    /// **Panics** if `axis` is out of bounds or if `n` is not less than the
+    /// length of the axis.
+    #[track_caller]
+    pub fn select_nth_axis(&mut self, axis: Axis, n: usize)
+    where A: Clone + Ord
+    {
+        let axis_len = self.len_of(axis);
+        assert!(n < axis_len, "ndarray: index {} is out of bounds for axis of length {}", n, axis_len);
+        let mut buf = Vec::new();
+        Zip::from(self.lanes_mut(axis)).for_each(|lane| {
+            sort_lane(lane, &mut buf, |xs| {
+                xs.select_nth_unstable(n);
+            })
+        });
+    }
+
+    /// Return the `k` largest elements along `axis`, and their indices.
+    ///
+    /// The result has the shape of `self`, except that the length of `axis`
+    /// is `k`. Each lane holds the `k` largest elements of the corresponding
+    /// lane of `self` in decreasing order; equal elements are ordered by
+    /// increasing index.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![[3, 9, 2, 9],
+    ///                [5, 1, 7, 0]];
+    /// let (values, indices) = a.top_k_axis(Axis(1), 2);
+    /// assert_eq!(values, array![[9, 9], [7, 5]]);
+    /// assert_eq!(indices, array![[1, 3], [2, 0]]);
+    /// ```
+    ///
+    /// **Panics** if `axis` is out of bounds or if `k` is greater than the
+    /// length of the axis.
+    #[track_caller]
+    pub fn top_k_axis(&self, axis: Axis, k: usize) -> (Array<A, D>, Array<usize, D>)
+    where A: Clone + Ord
+    {
+        let axis_len = self.len_of(axis);
+        assert!(k <= axis_len, "ndarray: k = {} is greater than the axis length {}", k, axis_len);
+        let mut dim = self.raw_dim();
+        dim[axis.index()] = k;
+
+        let mut indices = Array::zeros(dim.clone());
+        let mut perm = Vec::new();
+        Zip::from(indices.lanes_mut(axis))
+            .and(self.lanes(axis))
+            .for_each(|out, lane| top_k_lane(lane, out, &mut perm));
+
+        let mut values = Array::uninit(dim);
+        Zip::from(values.lanes_mut(axis))
+            .and(indices.lanes(axis))
+            .and(self.lanes(axis))
+            .for_each(gather_lane);
+        // Safety: every element of `values` was written above
+        let values = unsafe { values.assume_init() };
+        (values, indices)
+    }
+}
+
+/// Call `sort` on the elements of `lane` as a slice, copying them through
+/// `buf` if the lane is not contiguous.
+pub(crate) fn sort_lane<A, F>(mut lane: ArrayViewMut1<'_, A>, buf: &mut Vec<A>, sort: F)
+where
+    A: Clone,
+    F: FnOnce(&mut [A]),
+{
+    if let Some(xs) = lane.as_slice_mut() {
+        sort(xs);
+    } else {
+        buf.clear();
+        buf.extend(lane.iter().cloned());
+        sort(buf);
+        lane.iter_mut().zip(buf.drain(..)).for_each(|(x, y)| *x = y);
+    }
+}
+
+/// Write the indices that sort `lane` by `compare` (stable) into `out`,
+/// using `perm` as scratch space.
+pub(crate) fn argsort_lane<A, F>(
+    lane: ArrayView1<'_, A>, mut out: ArrayViewMut1<'_, usize>, perm: &mut Vec<usize>, mut compare: F,
+) where F: FnMut(&A, &A) -> Ordering
+{
+    perm.clear();
+    perm.extend(0..lane.len());
+    if let Some(xs) = lane.as_slice() {
+        perm.sort_by(|&i, &j| compare(&xs[i], &xs[j]));
+    } else {
+        perm.sort_by(|&i, &j| compare(&lane[i], &lane[j]));
+    }
+    out.iter_mut().zip(&*perm).for_each(|(x, &i)| *x = i);
+}
+
+/// Write the indices of the `out.len()` largest elements of `lane` into
+/// `out`, in decreasing order of the elements and then increasing index,
+/// using `perm` as scratch space.
+pub(crate) fn top_k_lane<A>(lane: ArrayView1<'_, A>, mut out: ArrayViewMut1<'_, usize>, perm: &mut Vec<usize>)
+where A: Ord
+{
+    let (n, k) = (lane.len(), out.len());
+    // decreasing value, then increasing index: a total order, so an unstable
+    // sort is enough
+    let compare = |&i: &usize, &j: &usize| lane[j].cmp(&lane[i]).then(i.cmp(&j));
+    perm.clear();
+    perm.extend(0..n);
+    if k > 0 && k < n {
+        perm.select_nth_unstable_by(k - 1, compare);
+    }
+    perm.truncate(k);
+    perm.sort_unstable_by(compare);
+    out.iter_mut().zip(&*perm).for_each(|(x, &i)| *x = i);
+}
+
+/// Write the elements of `lane` at `indices` into `out`.
+pub(crate) fn gather_lane<A>(
+    out: ArrayViewMut1<'_, MaybeUninit<A>>, indices: ArrayView1<'_, usize>, lane: ArrayView1<'_, A>,
+) where A: Clone
+{
+    Zip::from(out)
+        .and(indices)
+        .for_each(|x, &i| *x = MaybeUninit::new(lane[i].clone()));
+}
@@ -0,0 +1,202 @@
+// Copyright 2014-2024 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Tiling and repeating arrays.
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use crate::imp_prelude::*;
+use crate::{IntoDimension, Slice};
+
+/// The number of times [`ArrayRef::repeat`] repeats the elements along the
+/// axis.
+///
+/// It is usually created from a `usize`, to repeat every element the same
+/// number of times, or from a slice with one count for each element.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Repeats<'a>
+{
+    /// Repeat every element the same number of times.
+    Each(usize),
+    /// Repeat the element at index `i` `counts[i]` times.
+    Counts(&'a [usize]),
+}
+
+impl From<usize> for Repeats<'_>
+{
+    #[inline]
+    fn from(n: usize) -> Self
+    {
+        Repeats::Each(n)
+    }
+}
+
+impl<'a> From<&'a [usize]> for Repeats<'a>
+{
+    #[inline]
+    fn from(counts: &'a [usize]) -> Self
+    {
+        Repeats::Counts(counts)
+    }
+}
+
+impl<'a, const N: usize> From<&'a [usize; N]> for Repeats<'a>
+{
+    #[inline]
+    fn from(counts: &'a [usize; N]) -> Self
+    {
+        Repeats::Counts(counts)
+    }
+}
+
+impl<'a> From<&'a Vec<usize>> for Repeats<'a>
+{
+    #[inline]
+    fn from(counts: &'a Vec<usize>) -> Self
+    {
+        Repeats::Counts(counts)
+    }
+}
+
+#[cold]
+#[track_caller]
+fn too_large(shape: &[usize]) -> !
+{
+    panic!("ndarray: the shape {:?} is too large", shape)
+}
+
+/// # Tiling and repeating
+///
+/// Both methods copy the array into a new array in standard layout. They work
+/// by *broadcasting* a view of the array, with extra axes inserted, into the
+/// repeated shape, so that the elements are cloned in a single pass that
+/// copies whole rows where it can.
+impl<A, D> ArrayRef<A, D>
+where D: Dimension
+{
+    /// Return a new array made of the array repeated `reps[i]` times along
+    /// each axis `i`, like a grid of copies.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[1, 2],
+    ///                [3, 4]];
+    /// assert_eq!(a.tile((2, 3)), array![[1, 2, 1, 2, 1, 2],
+    ///                                   [3, 4, 3, 4, 3, 4],
+    ///                                   [1, 2, 1, 2, 1, 2],
+    ///                                   [3, 4, 3, 4, 3, 4]]);
+    /// ```
+    ///
+    /// **Panics** if the number of repetitions does not match the number of
+    /// axes, or if the result would have more than `isize::MAX` elements.
+    #[track_caller]
+    pub fn tile<E>(&self, reps: E) -> Array<A, D>
+    where
+        E: IntoDimension<Dim = D>,
+        A: Clone,
+    {
+        let reps = reps.into_dimension();
+        assert_eq!(
+            reps.ndim(),
+            self.ndim(),
+            "ndarray: expected one repetition count for each of the {} axes, but got {}",
+            self.ndim(),
+            reps.ndim()
+        );
+        // View the array with shape (1, n0, 1, n1, ...) and broadcast it to
+        // (r0, n0, r1, n1, ...), which is the tiled array in standard layout.
+        let mut view = self.view().into_dyn();
+        let mut shape = Vec::with_capacity(2 * self.ndim());
+        for (axis, (&r, &n)) in reps.slice().iter().zip(self.shape()).enumerate() {
+            view = view.insert_axis(Axis(2 * axis));
+            shape.push(r);
+            shape.push(n);
+        }
+        let res = match view.broadcast_owned(&shape[..]) {
+            Some(res) => res,
+            None => too_large(&shape),
+        };
+        let mut dim = reps;
+        for (d, &n) in dim.slice_mut().iter_mut().zip(self.shape()) {
+            *d *= n;
+        }
+        res.into_shape_with_order(dim).unwrap()
+    }
+
+    /// Return a new array with each element repeated along `axis`, either
+    /// the same number of times or by a count for each element.
+    ///
+    /// `repeats` is usually a `usize` or a slice of counts; see [`Repeats`].
+    /// The repeated elements are next to each other, unlike in
+    /// [`.tile()`](Self::tile).
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![[1, 2],
+    ///                [3, 4]];
+    /// assert_eq!(a.repeat(2, Axis(1)), array![[1, 1, 2, 2],
+    ///                                         [3, 3, 4, 4]]);
+    /// assert_eq!(a.repeat(&[0, 3], Axis(0)), array![[3, 4],
+    ///                                               [3, 4],
+    ///                                               [3, 4]]);
+    /// ```
+    ///
+    /// **Panics** if `axis` is out of bounds, if the number of counts is not
+    /// the length of the axis, or if the result would have more than
+    /// `isize::MAX` elements.
+    #[track_caller]
+    pub fn repeat<'r, R>(&self, repeats: R, axis: Axis) -> Array<A, D>
+    where
+        R: Into<Repeats<'r>>,
+        A: Clone,
+    {
+        let n = self.len_of(axis);
+        match repeats.into() {
+            Repeats::Each(r) => {
+                // View the array with a new axis of length 1 after `axis`,
+                // and broadcast it to length `r`.
+                let view = self.view().into_dyn().insert_axis(Axis(axis.index() + 1));
+                let mut shape = view.shape().to_vec();
+                shape[axis.index() + 1] = r;
+                let res = match view.broadcast_owned(&shape[..]) {
+                    Some(res) => res,
+                    None => too_large(&shape),
+                };
+                let mut dim = self.raw_dim();
+                dim[axis.index()] *= r;
+                res.into_shape_with_order(dim).unwrap()
+            }
+            Repeats::Counts(counts) => {
+                assert_eq!(
+                    counts.len(),
+                    n,
+                    "ndarray: expected {} repeat counts for axis {}, but got {}",
+                    n,
+                    axis.index(),
+                    counts.len()
+                );
+                let mut dim = self.raw_dim();
+                dim[axis.index()] = counts.iter().sum();
+                let mut res = Array::uninit(dim);
+                let mut start = 0;
+                for (i, &count) in counts.iter().enumerate() {
+                    let out = res.slice_axis_mut(axis, Slice::from(start..start + count));
+                    let slab = self.slice_axis(axis, Slice::from(i..i + 1));
+                    slab.broadcast(out.raw_dim()).unwrap().assign_to(out);
+                    start += count;
+                }
+                // Safety: the counts add up to the length of the result along
+                // `axis`, so every element was assigned above
+                unsafe { res.assume_init() }
+            }
+        }
+    }
+}
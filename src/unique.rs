@@ -0,0 +1,306 @@
+// Copyright 2014-2024 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Unique elements of arrays, with counts and inverse indices.
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+use std::cmp::Ordering;
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+#[cfg(feature = "std")]
+use std::hash::Hash;
+
+use crate::imp_prelude::*;
+
+/// # Unique elements
+///
+/// The `unique` methods for elements that implement `Ord` return the unique
+/// elements in sorted order. The `_by_hash` methods for elements that
+/// implement `Hash` and `Eq` return them in the order of their first
+/// occurrence; they require the crate feature `std`.
+///
+/// The inverse indices map each element of the array to its position among
+/// the unique elements, so that the array can be reconstructed with
+/// [`.select()`](ArrayRef::select):
+///
+/// ```
+/// use ndarray::{array, Axis};
+///
+/// let labels = array!["b", "a", "c", "a"];
+/// let (unique, inverse) = labels.unique_inverse();
+/// assert_eq!(unique, array!["a", "b", "c"]);
+/// assert_eq!(inverse, array![1, 0, 2, 0]);
+/// assert_eq!(unique.select(Axis(0), inverse.as_slice().unwrap()), labels);
+/// ```
+impl<A> ArrayRef<A, Ix1>
+{
+    /// Return the unique elements of the array, in sorted order.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// assert_eq!(array![3, 1, 3, 2, 1].unique(), array![1, 2, 3]);
+    /// ```
+    pub fn unique(&self) -> Array1<A>
+    where A: Clone + Ord
+    {
+        let (perm, starts) = sorted_groups(self.len(), |i, j| self[i].cmp(&self[j]));
+        starts[..starts.len() - 1]
+            .iter()
+            .map(|&start| self[perm[start]].clone())
+            .collect()
+    }
+
+    /// Return the unique elements of the array, in sorted order, and the
+    /// number of times each of them occurs.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let (unique, counts) = array![3, 1, 3, 2, 3].unique_counts();
+    /// assert_eq!(unique, array![1, 2, 3]);
+    /// assert_eq!(counts, array![1, 1, 3]);
+    /// ```
+    pub fn unique_counts(&self) -> (Array1<A>, Array1<usize>)
+    where A: Clone + Ord
+    {
+        let (perm, starts) = sorted_groups(self.len(), |i, j| self[i].cmp(&self[j]));
+        let unique = starts[..starts.len() - 1]
+            .iter()
+            .map(|&start| self[perm[start]].clone())
+            .collect();
+        let counts = starts.windows(2).map(|w| w[1] - w[0]).collect();
+        (unique, counts)
+    }
+
+    /// Return the unique elements of the array, in sorted order, and the
+    /// index of each element of the array among them.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let (unique, inverse) = array![3, 1, 3, 2].unique_inverse();
+    /// assert_eq!(unique, array![1, 2, 3]);
+    /// assert_eq!(inverse, array![2, 0, 2, 1]);
+    /// ```
+    pub fn unique_inverse(&self) -> (Array1<A>, Array1<usize>)
+    where A: Clone + Ord
+    {
+        let (perm, starts) = sorted_groups(self.len(), |i, j| self[i].cmp(&self[j]));
+        let unique = starts[..starts.len() - 1]
+            .iter()
+            .map(|&start| self[perm[start]].clone())
+            .collect();
+        (unique, inverse_of_groups(&perm, &starts))
+    }
+
+    /// Return the unique elements of the array, in the order of their first
+    /// occurrence.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// assert_eq!(array!["x", "z", "x", "y"].unique_by_hash(), array!["x", "z", "y"]);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn unique_by_hash(&self) -> Array1<A>
+    where A: Clone + Eq + Hash
+    {
+        self.unique_counts_by_hash().0
+    }
+
+    /// Return the unique elements of the array, in the order of their first
+    /// occurrence, and the number of times each of them occurs.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let (unique, counts) = array!["x", "z", "x", "y"].unique_counts_by_hash();
+    /// assert_eq!(unique, array!["x", "z", "y"]);
+    /// assert_eq!(counts, array![2, 1, 1]);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn unique_counts_by_hash(&self) -> (Array1<A>, Array1<usize>)
+    where A: Clone + Eq + Hash
+    {
+        let (unique, inverse) = self.unique_inverse_by_hash();
+        let mut counts = Array1::zeros(unique.len());
+        for &i in &inverse {
+            counts[i] += 1;
+        }
+        (unique, counts)
+    }
+
+    /// Return the unique elements of the array, in the order of their first
+    /// occurrence, and the index of each element of the array among them.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let (unique, inverse) = array!["x", "z", "x", "y"].unique_inverse_by_hash();
+    /// assert_eq!(unique, array!["x", "z", "y"]);
+    /// assert_eq!(inverse, array![0, 1, 0, 2]);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn unique_inverse_by_hash(&self) -> (Array1<A>, Array1<usize>)
+    where A: Clone + Eq + Hash
+    {
+        let mut positions = HashMap::new();
+        let mut unique = Vec::new();
+        let inverse = self
+            .iter()
+            .map(|x| {
+                *positions.entry(x).or_insert_with(|| {
+                    unique.push(x.clone());
+                    unique.len() - 1
+                })
+            })
+            .collect();
+        (Array1::from(unique), inverse)
+    }
+}
+
+impl<A, D> ArrayRef<A, D>
+where D: Dimension
+{
+    /// Return the unique subviews along `axis`, in lexicographic order of
+    /// their elements.
+    ///
+    /// For a two-dimensional array and `Axis(0)`, these are the unique rows.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![[1, 2], [0, 5], [1, 2]];
+    /// assert_eq!(a.unique_axis(Axis(0)), array![[0, 5], [1, 2]]);
+    /// assert_eq!(a.unique_axis(Axis(1)), array![[1, 2], [0, 5], [1, 2]]);
+    /// ```
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    #[track_caller]
+    pub fn unique_axis(&self, axis: Axis) -> Array<A, D>
+    where
+        A: Clone + Ord,
+        D: RemoveAxis,
+    {
+        self.unique_axis_inverse(axis).0
+    }
+
+    /// Return the unique subviews along `axis`, in lexicographic order of
+    /// their elements, and the index of each subview of the array among them.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![[1, 2], [0, 5], [1, 2]];
+    /// let (unique, inverse) = a.unique_axis_inverse(Axis(0));
+    /// assert_eq!(unique, array![[0, 5], [1, 2]]);
+    /// assert_eq!(inverse, array![1, 0, 1]);
+    /// assert_eq!(unique.select(Axis(0), inverse.as_slice().unwrap()), a);
+    /// ```
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    #[track_caller]
+    pub fn unique_axis_inverse(&self, axis: Axis) -> (Array<A, D>, Array1<usize>)
+    where
+        A: Clone + Ord,
+        D: RemoveAxis,
+    {
+        let subviews = self.axis_iter(axis).collect::<Vec<_>>();
+        let (perm, starts) = sorted_groups(subviews.len(), |i, j| subviews[i].iter().cmp(subviews[j].iter()));
+        let firsts = starts[..starts.len() - 1]
+            .iter()
+            .map(|&start| perm[start])
+            .collect::<Vec<_>>();
+        (self.select(axis, &firsts), inverse_of_groups(&perm, &starts))
+    }
+
+    /// Return the unique subviews along `axis`, in the order of their first
+    /// occurrence.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![[1, 2], [0, 5], [1, 2]];
+    /// assert_eq!(a.unique_axis_by_hash(Axis(0)), array![[1, 2], [0, 5]]);
+    /// ```
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    #[cfg(feature = "std")]
+    #[track_caller]
+    pub fn unique_axis_by_hash(&self, axis: Axis) -> Array<A, D>
+    where
+        A: Clone + Eq + Hash,
+        D: RemoveAxis,
+    {
+        self.unique_axis_inverse_by_hash(axis).0
+    }
+
+    /// Return the unique subviews along `axis`, in the order of their first
+    /// occurrence, and the index of each subview of the array among them.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![[1, 2], [0, 5], [1, 2]];
+    /// let (unique, inverse) = a.unique_axis_inverse_by_hash(Axis(0));
+    /// assert_eq!(unique, array![[1, 2], [0, 5]]);
+    /// assert_eq!(inverse, array![0, 1, 0]);
+    /// ```
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    #[cfg(feature = "std")]
+    #[track_caller]
+    pub fn unique_axis_inverse_by_hash(&self, axis: Axis) -> (Array<A, D>, Array1<usize>)
+    where
+        A: Clone + Eq + Hash,
+        D: RemoveAxis,
+    {
+        let mut positions = HashMap::new();
+        let mut firsts = Vec::new();
+        let inverse = self
+            .axis_iter(axis)
+            .enumerate()
+            .map(|(i, subview)| {
+                *positions.entry(subview).or_insert_with(|| {
+                    firsts.push(i);
+                    firsts.len() - 1
+                })
+            })
+            .collect();
+        (self.select(axis, &firsts), inverse)
+    }
+}
+
+/// Stable sort the indices `0..n` by `compare`, and return them together with
+/// the start of each group of equal elements in the sorted indices, followed
+/// by `n`.
+fn sorted_groups<F>(n: usize, mut compare: F) -> (Vec<usize>, Vec<usize>)
+where F: FnMut(usize, usize) -> Ordering
+{
+    let mut perm = (0..n).collect::<Vec<_>>();
+    perm.sort_by(|&i, &j| compare(i, j));
+    let mut starts = (0..n)
+        .filter(|&k| k == 0 || compare(perm[k - 1], perm[k]) != Ordering::Equal)
+        .collect::<Vec<_>>();
+    starts.push(n);
+    (perm, starts)
+}
+
+/// Map each original index to the number of its group.
+fn inverse_of_groups(perm: &[usize], starts: &[usize]) -> Array1<usize>
+{
+    let mut inverse = Array1::zeros(perm.len());
+    for (group, w) in starts.windows(2).enumerate() {
+        for &i in &perm[w[0]..w[1]] {
+            inverse[i] = group;
+        }
+    }
+    inverse
+}
@@ -0,0 +1,187 @@
+use ndarray::prelude::*;
+use ndarray::{GatherElem, ScatterMode, Slice};
+
+#[test]
+fn gather_adjacent_index_arrays()
+{
+    let a = Array::from_iter(0..24)
+        .into_shape_with_order((2, 3, 4))
+        .unwrap();
+    let i = array![[0, 2], [1, 1]];
+    let j = array![3, 0];
+
+    // index arrays next to each other replace their axes
+    let g = a.gather(&[(..).into(), (&i).into(), (&j).into()]);
+    assert_eq!(g.shape(), &[2, 2, 2]);
+    for k in 0..2 {
+        for m in 0..2 {
+            for n in 0..2 {
+                assert_eq!(g[[k, m, n]], a[[k, i[[m, n]], j[n]]]);
+            }
+        }
+    }
+
+    // a single index counts as an index array and keeps them adjacent
+    let g = a.gather(&[(..).into(), 1.into(), (&j).into()]);
+    assert_eq!(g, array![[7, 4], [19, 16]].into_dyn());
+}
+
+#[test]
+fn gather_separated_index_arrays()
+{
+    let a = Array::from_iter(0..24)
+        .into_shape_with_order((2, 3, 4))
+        .unwrap();
+    let i = array![1, 0, 1];
+    let j = array![3, 0, 2];
+
+    // index arrays that are not adjacent put the index axes first
+    let g = a.gather(&[(&i).into(), (..).into(), (&j).into()]);
+    assert_eq!(g.shape(), &[3, 3]);
+    for m in 0..3 {
+        assert_eq!(g.index_axis(Axis(0), m), a.slice(s![i[m], .., j[m]]).into_dyn());
+    }
+
+    let g = a.gather(&[(&i).into(), 2.into(), GatherElem::from(Slice::from(1..).step_by(2))]);
+    assert_eq!(g, array![[21, 23], [9, 11], [21, 23]].into_dyn());
+}
+
+#[test]
+fn gather_basic_only_and_new_axis()
+{
+    let a = Array::from_iter(0..12)
+        .into_shape_with_order((3, 4))
+        .unwrap();
+    let g = a.gather(&[(1..).into(), NewAxis.into(), (-1).into()]);
+    assert_eq!(g, a.slice(s![1.., NewAxis, -1]).into_dyn());
+
+    let rows = array![2, 0];
+    let g = a.gather(&[NewAxis.into(), (&rows).into(), (..2).into()]);
+    assert_eq!(g, array![[[8, 9], [0, 1]]].into_dyn());
+}
+
+#[test]
+fn take_matches_select()
+{
+    let a = Array::from_iter(0..20)
+        .into_shape_with_order((4, 5))
+        .unwrap();
+    let indices = array![4, 1, 1, 0];
+    assert_eq!(a.take(Axis(1), &indices), a.select(Axis(1), &[4, 1, 1, 0]).into_dyn());
+    assert_eq!(a.t().take(Axis(0), &indices), a.t().select(Axis(0), &[4, 1, 1, 0]).into_dyn());
+
+    let empty = Array1::<usize>::zeros(0);
+    assert_eq!(a.take(Axis(0), &empty).shape(), &[0, 5]);
+}
+
+#[test]
+#[should_panic]
+fn gather_out_of_bounds()
+{
+    let a = Array2::<f32>::zeros((3, 4));
+    a.gather(&[(&array![0, 3]).into(), (..).into()]);
+}
+
+#[test]
+#[should_panic]
+fn gather_incompatible_index_shapes()
+{
+    let a = Array2::<f32>::zeros((3, 4));
+    a.gather(&[(&array![0, 1]).into(), (&array![0, 1, 2]).into()]);
+}
+
+#[test]
+fn mask_select_broadcast()
+{
+    let a = Array::from_iter(0..12)
+        .into_shape_with_order((3, 4))
+        .unwrap();
+    let mask = a.mapv(|x| x % 3 == 0);
+    assert_eq!(a.mask_select(&mask), array![0, 3, 6, 9]);
+
+    // logical order is kept for non-standard layouts
+    assert_eq!(a.t().mask_select(&mask.t()), array![0, 9, 6, 3]);
+
+    // the mask is broadcast along the rows, and the array along a new axis
+    let rows = array![[true], [false], [true]];
+    assert_eq!(a.mask_select(&rows), array![0, 1, 2, 3, 8, 9, 10, 11]);
+    let b = array![1, 2];
+    let m = array![[true, false], [false, true]];
+    assert_eq!(b.mask_select(&m), array![1, 2]);
+}
+
+#[test]
+fn mask_fill_and_assign()
+{
+    let mut a = Array::from_iter(0..12)
+        .into_shape_with_order((3, 4))
+        .unwrap();
+    a.mask_fill(&array![false, true, false, true], -1);
+    assert_eq!(a.column(1), array![-1, -1, -1]);
+    assert_eq!(a.column(3), array![-1, -1, -1]);
+
+    let mask = a.mapv(|x| x < 0);
+    let mut b = Array::zeros((4, 3));
+    b.view_mut()
+        .reversed_axes()
+        .mask_assign(&mask, &Array::from_iter(1..=6));
+    assert_eq!(b, array![[0, 0, 0], [1, 3, 5], [0, 0, 0], [2, 4, 6]]);
+    assert_eq!(b.t().mask_select(&mask), Array::from_iter(1..=6));
+}
+
+#[test]
+#[should_panic]
+fn mask_assign_wrong_source_length()
+{
+    let mut a = Array2::<f32>::zeros((2, 2));
+    a.mask_assign(&array![true, false], &array![1., 2., 3.]);
+}
+
+#[test]
+fn scatter_modes()
+{
+    let indices = [2, 0, 2, 2];
+    let source = array![[1, 8], [2, 7], [3, 6], [-4, 5]];
+
+    let mut a = Array2::<i32>::zeros((3, 2));
+    a.scatter(Axis(0), &indices, &source, ScatterMode::Overwrite);
+    assert_eq!(a, array![[2, 7], [0, 0], [-4, 5]]);
+
+    let mut a = Array2::<i32>::zeros((3, 2));
+    a.scatter(Axis(0), &indices, &source, ScatterMode::Add);
+    assert_eq!(a, array![[2, 7], [0, 0], [0, 19]]);
+
+    let mut a = Array2::<i32>::zeros((3, 2));
+    a.scatter(Axis(0), &indices, &source, ScatterMode::Min);
+    assert_eq!(a, array![[0, 0], [0, 0], [-4, 0]]);
+
+    let mut a = Array2::<i32>::zeros((3, 2));
+    a.scatter(Axis(0), &indices, &source, ScatterMode::Max);
+    assert_eq!(a, array![[2, 7], [0, 0], [3, 8]]);
+
+    // scatter is the inverse of select
+    let mut a = Array2::<i32>::zeros((2, 3));
+    a.scatter(Axis(1), &[2, 0], &source.slice(s![..2, ..]).t(), ScatterMode::Overwrite);
+    assert_eq!(a.select(Axis(1), &[2, 0]), source.slice(s![..2, ..]).t());
+}
+
+#[test]
+fn put_along_axis_accumulates()
+{
+    let mut a = Array2::<f64>::zeros((2, 3));
+    let indices = array![[0, 0], [2, 1]];
+    a.put_along_axis(Axis(1), &indices, &array![[1., 2.], [3., 4.]], ScatterMode::Add);
+    assert_eq!(a, array![[3., 0., 0.], [0., 4., 3.]]);
+
+    let mut a = Array2::<f64>::zeros((3, 2));
+    a.put_along_axis(Axis(0), &indices.t(), &array![[1., 2.], [3., 4.]].t(), ScatterMode::Max);
+    assert_eq!(a, array![[2., 0.], [0., 4.], [0., 3.]]);
+}
+
+#[test]
+#[should_panic]
+fn scatter_out_of_bounds()
+{
+    let mut a = Array1::<f64>::zeros(3);
+    a.scatter(Axis(0), &[1, 3], &array![1., 2.], ScatterMode::Add);
+}
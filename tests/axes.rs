@@ -0,0 +1,111 @@
+use ndarray::prelude::*;
+
+#[test]
+fn moveaxis_matches_permuted_axes()
+{
+    let a = Array::from_iter(0..120)
+        .into_shape_with_order((2, 3, 4, 5))
+        .unwrap();
+    assert_eq!(a.view().moveaxis(&[], &[]), a);
+    assert_eq!(a.view().moveaxis(&[Axis(0)], &[Axis(3)]), a.view().permuted_axes([1, 2, 3, 0]));
+    assert_eq!(a.view().moveaxis(&[Axis(3)], &[Axis(1)]), a.view().permuted_axes([0, 3, 1, 2]));
+    assert_eq!(a.view().moveaxis(&[Axis(0), Axis(3)], &[Axis(3), Axis(0)]), a.view().permuted_axes([3, 1, 2, 0]));
+    assert_eq!(a.view().moveaxis(&[Axis(2), Axis(0)], &[Axis(0), Axis(1)]), a.view().permuted_axes([2, 0, 1, 3]));
+
+    let d = a.clone().into_dyn().moveaxis(&[Axis(1)], &[Axis(0)]);
+    assert_eq!(d.shape(), &[3, 2, 4, 5]);
+    assert_eq!(d, a.view().permuted_axes([1, 0, 2, 3]).into_dyn());
+}
+
+#[test]
+#[should_panic]
+fn moveaxis_length_mismatch()
+{
+    let a = Array3::<f64>::zeros((2, 3, 4));
+    let _ = a.moveaxis(&[Axis(0), Axis(1)], &[Axis(2)]);
+}
+
+#[test]
+#[should_panic]
+fn moveaxis_repeated_destination()
+{
+    let a = Array3::<f64>::zeros((2, 3, 4));
+    let _ = a.moveaxis(&[Axis(0), Axis(1)], &[Axis(2), Axis(2)]);
+}
+
+#[test]
+fn expand_and_squeeze()
+{
+    let a = Array::from_iter(0..12)
+        .into_shape_with_order((3, 4))
+        .unwrap();
+
+    let b: ArrayView4<i32> = a.view().expand_dims([Axis(3), Axis(0)]);
+    assert_eq!(b.shape(), &[1, 3, 4, 1]);
+    assert_eq!(b, a.view().insert_axis(Axis(0)).insert_axis(Axis(3)));
+    let c: ArrayView2<i32> = b.squeeze_axes([Axis(0), Axis(3)]);
+    assert_eq!(c, a);
+
+    let d = a.view().expand_dims(&[Axis(1), Axis(2)][..]);
+    assert_eq!(d.shape(), &[3, 1, 1, 4]);
+    assert_eq!(d.squeeze_axes(vec![Axis(2), Axis(1)]), a.clone().into_dyn());
+
+    let e: ArrayView2<i32> = a.view().expand_dims([]);
+    assert_eq!(e, a);
+
+    // Squeezing keeps the strides of the other axes
+    let t = a.t().expand_dims([Axis(1)]);
+    assert_eq!(t.shape(), &[4, 1, 3]);
+    assert_eq!(t.squeeze_axes(&[Axis(1)]), a.t());
+
+    let mut f = Array::zeros((1, 3, 1)).expand_dims([Axis(2), Axis(0)]);
+    f.view_mut()
+        .squeeze_axes([Axis(0), Axis(1), Axis(2), Axis(4)])
+        .assign(&array![1., 2., 3.]);
+    assert_eq!(f.shape(), &[1, 1, 1, 3, 1]);
+    assert_eq!(f.into_raw_vec_and_offset().0, vec![1., 2., 3.]);
+}
+
+#[test]
+#[should_panic]
+fn squeeze_axis_not_one()
+{
+    let a = Array3::<f64>::zeros((1, 2, 1));
+    let _ = a.squeeze_axes([Axis(1)]);
+}
+
+#[test]
+#[should_panic]
+fn expand_dims_out_of_bounds()
+{
+    let a = Array2::<f64>::zeros((1, 2));
+    let _ = a.expand_dims([Axis(3)]);
+}
+
+#[test]
+fn atleast()
+{
+    let s = arr0(7);
+    let v = array![1, 2, 3];
+    let m = array![[1, 2, 3], [4, 5, 6]];
+
+    let s1: Array1<i32> = s.clone().atleast_1d();
+    let s2: Array2<i32> = s.clone().atleast_2d();
+    let s3: Array3<i32> = s.clone().atleast_3d();
+    assert_eq!(s1, array![7]);
+    assert_eq!(s2, array![[7]]);
+    assert_eq!(s3, array![[[7]]]);
+
+    assert_eq!(v.view().atleast_1d(), v);
+    assert_eq!(v.view().atleast_2d(), array![[1, 2, 3]]);
+    assert_eq!(v.view().atleast_3d(), array![[[1], [2], [3]]]);
+
+    assert_eq!(m.view().atleast_2d(), m);
+    assert_eq!(m.view().atleast_3d(), m.view().insert_axis(Axis(2)));
+    assert_eq!(m.view().insert_axis(Axis(0)).atleast_3d().shape(), &[1, 2, 3]);
+
+    let dyn_v = v.clone().into_dyn();
+    assert_eq!(dyn_v.view().atleast_2d().shape(), &[1, 3]);
+    assert_eq!(dyn_v.atleast_3d().shape(), &[1, 3, 1]);
+    assert_eq!(m.into_dyn().atleast_1d().shape(), &[2, 3]);
+}
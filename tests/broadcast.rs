@@ -95,3 +95,22 @@ fn test_broadcast_1d()
     println!("b2=\n{:?}", b2);
     assert_eq!(b0, b2);
 }
+
+#[test]
+fn test_broadcast_owned()
+{
+    let a = array![[1, 2, 3], [4, 5, 6]];
+    let col = a.column(1);
+    let b = col.broadcast_owned((4, 2)).unwrap();
+    assert_eq!(b, col.broadcast((4, 2)).unwrap());
+    assert!(b.is_standard_layout());
+
+    let c = a.t().broadcast_owned((2, 3, 2)).unwrap();
+    assert_eq!(c, a.t().broadcast((2, 3, 2)).unwrap());
+    assert!(c.is_standard_layout());
+    assert_eq!(a.broadcast_owned(a.raw_dim()).unwrap(), a);
+
+    assert!(a.broadcast_owned((2, 2)).is_none());
+    assert!(a.broadcast_owned((usize::MAX, 2, 3)).is_none());
+    assert_eq!(a.broadcast_owned((0, 2, 3)).unwrap().shape(), &[0, 2, 3]);
+}
@@ -0,0 +1,148 @@
+use ndarray::linalg::{einsum, Einsum, EinsumError};
+use ndarray::prelude::*;
+
+/// Evaluate `subscripts` (with an explicit output) by looping over all
+/// values of all labels.
+fn reference_einsum(subscripts: &str, operands: &[ArrayViewD<'_, f64>]) -> ArrayD<f64>
+{
+    let (inputs, output) = subscripts.split_once("->").unwrap();
+    let inputs = inputs
+        .split(',')
+        .map(|s| s.chars().collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    let output = output.chars().collect::<Vec<_>>();
+    let mut labels = Vec::new();
+    let mut lengths = Vec::new();
+    for (input, op) in inputs.iter().zip(operands) {
+        for (&l, &n) in input.iter().zip(op.shape()) {
+            if !labels.contains(&l) {
+                labels.push(l);
+                lengths.push(n);
+            }
+        }
+    }
+    let pos = |l: &char| labels.iter().position(|m| m == l).unwrap();
+    let out_shape = output.iter().map(|l| lengths[pos(l)]).collect::<Vec<_>>();
+    let mut result = ArrayD::zeros(out_shape);
+    for index in ndarray::indices(lengths.clone()) {
+        let index = index.slice().to_vec();
+        let product = inputs.iter().zip(operands).fold(1., |acc, (input, op)| {
+            let i = input.iter().map(|l| index[pos(l)]).collect::<Vec<_>>();
+            acc * op[&i[..]]
+        });
+        let o = output.iter().map(|l| index[pos(l)]).collect::<Vec<_>>();
+        result[&o[..]] += product;
+    }
+    result
+}
+
+fn range(shape: &[usize], offset: usize) -> ArrayD<f64>
+{
+    ArrayD::from_shape_fn(shape, |i| ((i.slice().iter().sum::<usize>() * 7 + offset) % 11) as f64 - 5.)
+}
+
+#[test]
+fn einsum_matches_reference()
+{
+    let cases: &[(&str, &[&[usize]])] = &[
+        ("ij,jk->ik", &[&[3, 4], &[4, 5]]),
+        ("ij,jk->ki", &[&[3, 4], &[4, 5]]),
+        ("bij,bjk->bik", &[&[2, 3, 4], &[2, 4, 5]]),
+        ("bij,jk->bik", &[&[2, 3, 4], &[4, 5]]),
+        ("ij,ij->ij", &[&[3, 4], &[3, 4]]),
+        ("ij,ij->", &[&[3, 4], &[3, 4]]),
+        ("i,j->ij", &[&[3], &[4]]),
+        ("ii->", &[&[4, 4]]),
+        ("ii->i", &[&[4, 4]]),
+        ("iij->ji", &[&[3, 3, 2]]),
+        ("ijk->kji", &[&[2, 3, 4]]),
+        ("ijk->j", &[&[2, 3, 4]]),
+        ("ij,jk,kl->il", &[&[2, 3], &[3, 4], &[4, 5]]),
+        ("abc,cd,bde->ae", &[&[2, 3, 4], &[4, 5], &[3, 5, 2]]),
+        ("ij,kl->", &[&[2, 3], &[4, 5]]),
+        ("ab,bc,ca->", &[&[3, 4], &[4, 5], &[5, 3]]),
+        ("i,i,i->i", &[&[6], &[6], &[6]]),
+    ];
+    for (k, &(subscripts, shapes)) in cases.iter().enumerate() {
+        let operands = shapes
+            .iter()
+            .enumerate()
+            .map(|(i, &s)| range(s, i + k))
+            .collect::<Vec<_>>();
+        let views = operands.iter().map(|a| a.view()).collect::<Vec<_>>();
+        let result = einsum(subscripts, &views).unwrap();
+        assert_eq!(result, reference_einsum(subscripts, &views), "{}", subscripts);
+
+        // non-standard layouts
+        let reversed = operands
+            .iter()
+            .map(|a| a.t().to_owned())
+            .collect::<Vec<_>>();
+        let views = reversed.iter().map(|a| a.t()).collect::<Vec<_>>();
+        assert_eq!(einsum(subscripts, &views).unwrap(), result, "{}", subscripts);
+    }
+}
+
+#[test]
+fn einsum_implicit_output()
+{
+    let a = range(&[3, 4], 0).into_dimensionality::<Ix2>().unwrap();
+    let b = range(&[4, 2], 1).into_dimensionality::<Ix2>().unwrap();
+    assert_eq!(einsum("ij,jk", &[a.view(), b.view()]).unwrap(), a.dot(&b).into_dyn());
+    // the implicit output is in alphabetical order
+    assert_eq!(einsum("kj,ji", &[b.t(), a.t()]).unwrap(), a.dot(&b).into_dyn());
+    assert_eq!(einsum("ij", &[a.view()]).unwrap(), a.view().into_dyn());
+    assert_eq!(einsum("ji", &[a.view()]).unwrap(), a.t().into_dyn());
+}
+
+#[test]
+fn einsum_builder()
+{
+    let a = Array3::from_shape_fn((2, 3, 4), |(i, j, k)| (i + 2 * j + 3 * k) as f64);
+    let v = Array1::from_shape_fn(4, |i| i as f64 - 1.);
+    let w: Array2<f64> = Einsum::new("bik,k->bi")
+        .unwrap()
+        .operand(&a)
+        .operand(&v)
+        .compute_into()
+        .unwrap();
+    for b in 0..2 {
+        assert_eq!(w.row(b), a.index_axis(Axis(0), b).dot(&v));
+    }
+    let e = Einsum::new("bik,k->bi").unwrap().operand(&a).operand(&v);
+    assert_eq!(e.compute().unwrap(), w.clone().into_dyn());
+    assert_eq!(e.compute_into::<Ix3>(), Err(EinsumError::OutputDimension { expected: 3, found: 2 }));
+    assert_eq!(e.compute_into::<IxDyn>().unwrap(), w.into_dyn());
+}
+
+#[test]
+fn einsum_errors()
+{
+    let a = Array2::<f64>::zeros((2, 3));
+    let b = Array2::<f64>::zeros((2, 3));
+    assert_eq!(
+        einsum("ij,jk->ik", &[a.view(), b.view()]),
+        Err(EinsumError::LengthMismatch {
+            label: 'j',
+            first: 3,
+            second: 2
+        })
+    );
+    assert_eq!(
+        einsum("ij,jk->ik", &[a.view()]),
+        Err(EinsumError::OperandCount { expected: 2, found: 1 })
+    );
+    assert_eq!(
+        einsum("ijk->i", &[a.view()]),
+        Err(EinsumError::OperandDimension {
+            operand: 0,
+            expected: 3,
+            found: 2
+        })
+    );
+    assert_eq!(einsum("ii->i", &[a.view()]), Err(EinsumError::LengthMismatch { label: 'i', first: 2, second: 3 }));
+    assert_eq!(einsum("i1->i", &[a.view()]), Err(EinsumError::InvalidCharacter('1')));
+    assert_eq!(einsum("ij->ij->", &[a.view()]), Err(EinsumError::MultipleArrows));
+    assert_eq!(einsum("ij->jj", &[a.view()]), Err(EinsumError::RepeatedOutputLabel('j')));
+    assert_eq!(einsum("ij->k", &[a.view()]), Err(EinsumError::UnknownOutputLabel('k')));
+}
@@ -0,0 +1,513 @@
+#![cfg(feature = "std")]
+
+use ndarray::linalg::{cholesky, eigh, eigvalsh, lu, qr, svd, Diagonal, InducedNorm, LinalgError, SvdMode, Triangle};
+use ndarray::prelude::*;
+use ndarray::Zip;
+use num_complex::Complex64;
+
+use approx::assert_abs_diff_eq;
+
+/// A matrix with deterministic, irregular elements in [-1, 1].
+fn matrix(m: usize, n: usize, seed: usize) -> Array2<f64>
+{
+    // splitmix64 of the position
+    Array::from_shape_fn((m, n), |(i, j)| {
+        let mut z = ((seed as u64) << 40 | (i as u64) << 20 | j as u64).wrapping_add(0x9e3779b97f4a7c15);
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^= z >> 31;
+        (z >> 11) as f64 / (1u64 << 52) as f64 - 1.
+    })
+}
+
+fn complex_matrix(m: usize, n: usize, seed: usize) -> Array2<Complex64>
+{
+    let re = matrix(m, n, seed);
+    let im = matrix(m, n, seed + 1);
+    Zip::from(&re)
+        .and(&im)
+        .map_collect(|&re, &im| Complex64::new(re, im))
+}
+
+/// A symmetric positive definite matrix.
+fn spd(n: usize) -> Array2<f64>
+{
+    let b = matrix(n, n, 3);
+    b.dot(&b.t()) + Array2::<f64>::eye(n) * n as f64
+}
+
+#[test]
+fn lu_reconstructs()
+{
+    for &n in &[1, 5, 64, 150] {
+        let a = matrix(n, n, n);
+        let lu = lu(&a).unwrap();
+        let l = lu.l();
+        assert_abs_diff_eq!(lu.p().dot(&a), l.dot(&lu.u()), epsilon = 1e-10);
+        // partial pivoting bounds the multipliers
+        assert!(l.iter().all(|x| x.abs() <= 1.));
+        let mut perm = lu.permutation().to_vec();
+        perm.sort();
+        assert_eq!(perm, (0..n).collect::<Vec<_>>());
+    }
+}
+
+#[test]
+fn lu_complex_and_strided()
+{
+    let a = complex_matrix(140, 70, 0);
+    let a = a.slice(s![..;2, ..]);
+    let lu = lu(&a.t()).unwrap();
+    let pa = lu.p().dot(&a.t());
+    assert_abs_diff_eq!(pa.mapv(|x| x.re), lu.l().dot(&lu.u()).mapv(|x| x.re), epsilon = 1e-10);
+    assert_abs_diff_eq!(pa.mapv(|x| x.im), lu.l().dot(&lu.u()).mapv(|x| x.im), epsilon = 1e-10);
+}
+
+#[test]
+fn lu_errors()
+{
+    let a = array![[1., 2., 3.], [2., 4., 6.], [0., 1., 1.]];
+    assert_eq!(lu(&a).unwrap_err(), LinalgError::Singular { index: 2 });
+    let a = Array2::<f32>::zeros((2, 3));
+    assert_eq!(lu(&a).unwrap_err(), LinalgError::NotSquare { rows: 2, cols: 3 });
+}
+
+#[test]
+fn cholesky_reconstructs()
+{
+    for &n in &[1, 7, 64, 130] {
+        let a = spd(n);
+        let l = cholesky(&a).unwrap().into_l();
+        assert_eq!(l, l.tril(0));
+        assert!(l.diag().iter().all(|&x| x > 0.));
+        assert_abs_diff_eq!(l.dot(&l.t()), a, epsilon = 1e-9);
+    }
+}
+
+#[test]
+fn cholesky_uses_lower_triangle()
+{
+    let b = complex_matrix(90, 90, 5);
+    let bh = b.t().mapv(|x| x.conj());
+    let a = b.dot(&bh) + Array2::<Complex64>::eye(90);
+    // garbage above the diagonal is not read
+    let mut lower = a.clone();
+    for i in 0..90 {
+        lower
+            .slice_mut(s![i, i + 1..])
+            .fill(Complex64::new(f64::NAN, 0.));
+    }
+    let l = cholesky(&lower).unwrap().into_l();
+    let llh = l.dot(&l.t().mapv(|x| x.conj()));
+    assert_abs_diff_eq!(llh.mapv(|x| x.re), a.mapv(|x| x.re), epsilon = 1e-9);
+    assert_abs_diff_eq!(llh.mapv(|x| x.im), a.mapv(|x| x.im), epsilon = 1e-9);
+}
+
+#[test]
+fn cholesky_not_positive_definite()
+{
+    let a = array![[4., 2., 0.], [2., 1., 0.], [0., 0., 1.]];
+    assert_eq!(cholesky(&a).unwrap_err(), LinalgError::NotPositiveDefinite { index: 1 });
+    let mut a = spd(100);
+    a[[80, 80]] = -1e4;
+    assert_eq!(cholesky(&a).unwrap_err(), LinalgError::NotPositiveDefinite { index: 80 });
+}
+
+#[test]
+fn qr_shapes()
+{
+    for &(m, n) in &[(1, 1), (6, 3), (3, 6), (150, 100), (70, 140)] {
+        let a = matrix(m, n, m + n);
+        let qr = qr(&a);
+        let (q, r) = (qr.q(), qr.r());
+        let k = m.min(n);
+        assert_eq!(q.dim(), (m, k));
+        assert_eq!(r.dim(), (k, n));
+        assert_eq!(r, r.triu(0));
+        assert_abs_diff_eq!(q.t().dot(&q), Array2::eye(k), epsilon = 1e-10);
+        assert_abs_diff_eq!(q.dot(&r), a, epsilon = 1e-10);
+    }
+}
+
+#[test]
+fn qr_complex()
+{
+    let a = complex_matrix(100, 80, 2);
+    let qr = qr(&a.view());
+    let (q, r) = (qr.q(), qr.r());
+    let qhq = q.t().mapv(|x| x.conj()).dot(&q);
+    assert_abs_diff_eq!(qhq.mapv(|x| x.re), Array2::eye(80), epsilon = 1e-10);
+    assert_abs_diff_eq!(qhq.mapv(|x| x.im), Array2::zeros((80, 80)), epsilon = 1e-10);
+    let qr = q.dot(&r);
+    assert_abs_diff_eq!(qr.mapv(|x| x.re), a.mapv(|x| x.re), epsilon = 1e-10);
+    assert_abs_diff_eq!(qr.mapv(|x| x.im), a.mapv(|x| x.im), epsilon = 1e-10);
+}
+
+#[test]
+fn qr_rank_deficient()
+{
+    let a = array![[1f32, 2.], [2., 4.], [0., 0.]];
+    let qr = qr(&a);
+    assert!(qr.r()[[1, 1]].abs() < 1e-6);
+    assert_abs_diff_eq!(qr.q().dot(&qr.r()), a, epsilon = 1e-6);
+}
+
+#[test]
+fn solve_vector_and_matrix()
+{
+    let a = matrix(120, 120, 1);
+    let x = matrix(120, 3, 2);
+    let b = a.dot(&x);
+    assert_abs_diff_eq!(a.solve(&b).unwrap(), x, epsilon = 1e-9);
+    let x1 = x.column(1);
+    let b1 = a.dot(&x1);
+    assert_abs_diff_eq!(a.solve(&b1).unwrap(), x1, epsilon = 1e-9);
+    // through the factorization, with a strided right-hand side
+    let lu = lu(&a).unwrap();
+    assert_abs_diff_eq!(lu.solve(&b.t().t()), x, epsilon = 1e-9);
+}
+
+#[test]
+fn solve_errors()
+{
+    let a = array![[1., 2.], [2., 4.]];
+    assert_eq!(a.solve(&array![1., 1.]).unwrap_err(), LinalgError::Singular { index: 1 });
+    assert_eq!(a.inv().unwrap_err(), LinalgError::Singular { index: 1 });
+    let a = Array2::<f64>::zeros((2, 3));
+    assert_eq!(a.det().unwrap_err(), LinalgError::NotSquare { rows: 2, cols: 3 });
+}
+
+#[test]
+#[should_panic]
+fn solve_rows_mismatch()
+{
+    let a = Array2::<f64>::eye(3);
+    let _ = a.solve(&Array1::zeros(4));
+}
+
+#[test]
+fn inv_complex()
+{
+    let a = complex_matrix(70, 70, 4);
+    let inv = a.inv().unwrap();
+    let eye = a.dot(&inv);
+    assert_abs_diff_eq!(eye.mapv(|x| x.re), Array2::eye(70), epsilon = 1e-9);
+    assert_abs_diff_eq!(eye.mapv(|x| x.im), Array2::zeros((70, 70)), epsilon = 1e-9);
+}
+
+#[test]
+fn det_and_slogdet()
+{
+    let a = array![[2., 0., 1.], [1., 3., 2.], [1., 1., 2.]];
+    assert_abs_diff_eq!(a.det().unwrap(), 6., epsilon = 1e-12);
+    // swapping two rows changes the sign
+    let b = a.select(Axis(0), &[1, 0, 2]);
+    assert_abs_diff_eq!(b.det().unwrap(), -6., epsilon = 1e-12);
+    let (sign, logabsdet) = (&b * 3.).slogdet().unwrap();
+    assert_eq!(sign, -1.);
+    assert_abs_diff_eq!(logabsdet, 162f64.ln(), epsilon = 1e-12);
+    assert_eq!(array![[0., 1.], [0., 2.]].slogdet().unwrap(), (0., f64::NEG_INFINITY));
+
+    let a = array![[Complex64::new(0., 2.), Complex64::new(1., 0.)], [Complex64::new(0., 0.), Complex64::new(3., 0.)]];
+    assert_eq!(a.det().unwrap(), Complex64::new(0., 6.));
+    let (sign, logabsdet) = a.slogdet().unwrap();
+    assert_abs_diff_eq!(sign.re, 0., epsilon = 1e-12);
+    assert_abs_diff_eq!(sign.im, 1., epsilon = 1e-12);
+    assert_abs_diff_eq!(logabsdet, 6f64.ln(), epsilon = 1e-12);
+}
+
+#[test]
+fn lstsq_overdetermined()
+{
+    let a = matrix(150, 80, 6);
+    let b = matrix(150, 2, 7);
+    let x = a.lstsq(&b).unwrap();
+    assert_eq!(x.dim(), (80, 2));
+    // the residual is orthogonal to the columns of A
+    let residual = a.dot(&x) - &b;
+    assert_abs_diff_eq!(a.t().dot(&residual), Array2::zeros((80, 2)), epsilon = 1e-9);
+    // square: the same as solve
+    let a = matrix(5, 5, 8);
+    let b = array![1., 2., 3., 4., 5.];
+    assert_abs_diff_eq!(a.lstsq(&b).unwrap(), a.solve(&b).unwrap(), epsilon = 1e-9);
+}
+
+#[test]
+fn lstsq_underdetermined()
+{
+    let a = array![[1., 1.]];
+    assert_abs_diff_eq!(a.lstsq(&array![2.]).unwrap(), array![1., 1.], epsilon = 1e-12);
+    let a = complex_matrix(30, 60, 9);
+    let b = complex_matrix(30, 1, 10);
+    let x = a.lstsq(&b).unwrap();
+    let ax = a.dot(&x);
+    assert_abs_diff_eq!(ax.mapv(|x| x.re), b.mapv(|x| x.re), epsilon = 1e-9);
+    assert_abs_diff_eq!(ax.mapv(|x| x.im), b.mapv(|x| x.im), epsilon = 1e-9);
+}
+
+#[test]
+fn lstsq_rank_deficient()
+{
+    let a = array![[1., 2.], [2., 4.], [3., 6.]];
+    assert_eq!(a.lstsq(&array![1., 2., 3.]).unwrap_err(), LinalgError::Singular { index: 1 });
+}
+
+#[test]
+fn solve_triangular_reads_triangle()
+{
+    let a = matrix(90, 90, 11) + Array2::<f64>::eye(90) * 4.;
+    let x = matrix(90, 4, 12);
+    for &triangle in &[Triangle::Upper, Triangle::Lower] {
+        for &diagonal in &[Diagonal::NonUnit, Diagonal::Unit] {
+            let mut t = match triangle {
+                Triangle::Upper => a.triu(0),
+                Triangle::Lower => a.tril(0),
+            };
+            if diagonal == Diagonal::Unit {
+                t.diag_mut().fill(1.);
+            }
+            let b = t.dot(&x);
+            let solved = a.solve_triangular(&b, triangle, diagonal).unwrap();
+            assert_abs_diff_eq!(solved, x, epsilon = 1e-9);
+        }
+    }
+}
+
+#[test]
+fn solve_triangular_zero_diagonal()
+{
+    let a = array![[1., 0.], [5., 0.]];
+    let b = array![1., 1.];
+    assert_eq!(
+        a.solve_triangular(&b, Triangle::Lower, Diagonal::NonUnit).unwrap_err(),
+        LinalgError::Singular { index: 1 }
+    );
+    assert_eq!(a.solve_triangular(&b, Triangle::Lower, Diagonal::Unit).unwrap(), array![1., -4.]);
+}
+
+fn conj_t(a: &Array2<Complex64>) -> Array2<Complex64>
+{
+    a.t().mapv(|x| x.conj())
+}
+
+fn assert_complex_eq(a: &Array2<Complex64>, b: &Array2<Complex64>, epsilon: f64)
+{
+    assert_abs_diff_eq!(a.mapv(|x| x.re), b.mapv(|x| x.re), epsilon = epsilon);
+    assert_abs_diff_eq!(a.mapv(|x| x.im), b.mapv(|x| x.im), epsilon = epsilon);
+}
+
+#[test]
+fn eigh_symmetric()
+{
+    for &n in &[1, 2, 10, 40] {
+        let b = matrix(n, n, 13);
+        let a = &b + &b.t();
+        let (w, v) = eigh(&a).unwrap();
+        assert!(w.windows(2).into_iter().all(|w| w[0] <= w[1]));
+        assert_abs_diff_eq!(v.t().dot(&v), Array2::eye(n), epsilon = 1e-10);
+        assert_abs_diff_eq!(a.dot(&v), &v * &w, epsilon = 1e-10);
+        assert_abs_diff_eq!(eigvalsh(&a).unwrap(), w, epsilon = 1e-10);
+    }
+}
+
+#[test]
+fn eigh_hermitian_lower_triangle()
+{
+    let b = complex_matrix(30, 30, 14);
+    let a = &b + &conj_t(&b);
+    let mut lower = a.clone();
+    for i in 0..30 {
+        lower
+            .slice_mut(s![i, i + 1..])
+            .fill(Complex64::new(f64::NAN, f64::NAN));
+    }
+    let (w, v) = eigh(&lower).unwrap();
+    assert_complex_eq(&conj_t(&v).dot(&v), &Array2::eye(30), 1e-10);
+    assert_complex_eq(&a.dot(&v), &(&v * &w.mapv(Complex64::from)), 1e-10);
+}
+
+#[test]
+fn eigh_known_values()
+{
+    // eigenvalues of the second difference matrix: 2 - 2 cos(k π / (n + 1))
+    let n = 12;
+    let a = Array2::from_shape_fn((n, n), |(i, j)| match i.abs_diff(j) {
+        0 => 2f32,
+        1 => -1.,
+        _ => 0.,
+    });
+    let expected =
+        Array::from_shape_fn(n, |k| 2. - 2. * ((k + 1) as f32 * std::f32::consts::PI / (n + 1) as f32).cos());
+    assert_abs_diff_eq!(eigvalsh(&a).unwrap(), expected, epsilon = 1e-5);
+    assert_eq!(eigh(&Array2::<f64>::zeros((2, 3))).unwrap_err(), LinalgError::NotSquare { rows: 2, cols: 3 });
+}
+
+#[test]
+fn svd_modes()
+{
+    for &(m, n) in &[(1, 1), (8, 5), (5, 8), (40, 25), (25, 40)] {
+        let a = matrix(m, n, 15 + m);
+        let k = m.min(n);
+        let thin = svd(&a, SvdMode::Thin).unwrap();
+        let (u, vt) = (thin.u.unwrap(), thin.vt.unwrap());
+        assert_eq!((u.dim(), vt.dim()), ((m, k), (k, n)));
+        assert!(thin.s.windows(2).into_iter().all(|s| s[0] >= s[1]));
+        assert_abs_diff_eq!(u.t().dot(&u), Array2::eye(k), epsilon = 1e-10);
+        assert_abs_diff_eq!(vt.dot(&vt.t()), Array2::eye(k), epsilon = 1e-10);
+        assert_abs_diff_eq!((&u * &thin.s).dot(&vt), a, epsilon = 1e-10);
+
+        let full = svd(&a, SvdMode::Full).unwrap();
+        let (u, vt) = (full.u.unwrap(), full.vt.unwrap());
+        assert_eq!((u.dim(), vt.dim()), ((m, m), (n, n)));
+        assert_abs_diff_eq!(u.t().dot(&u), Array2::eye(m), epsilon = 1e-10);
+        assert_abs_diff_eq!(vt.dot(&vt.t()), Array2::eye(n), epsilon = 1e-10);
+        assert_abs_diff_eq!(full.s, thin.s, epsilon = 1e-12);
+
+        let values = svd(&a, SvdMode::ValuesOnly).unwrap();
+        assert!(values.u.is_none() && values.vt.is_none());
+        assert_abs_diff_eq!(values.s, thin.s, epsilon = 1e-12);
+    }
+}
+
+#[test]
+fn svd_complex_rank_deficient()
+{
+    // rank 2
+    let a = complex_matrix(20, 2, 16).dot(&complex_matrix(2, 12, 17));
+    let full = svd(&a, SvdMode::Full).unwrap();
+    let (u, vt) = (full.u.unwrap(), full.vt.unwrap());
+    assert!(full.s[1] > 1e-3 && full.s[2] < 1e-12);
+    assert_complex_eq(&conj_t(&u).dot(&u), &Array2::eye(20), 1e-10);
+    assert_complex_eq(&vt.dot(&conj_t(&vt)), &Array2::eye(12), 1e-10);
+    let us = u.slice(s![.., ..12]).to_owned() * &full.s.mapv(Complex64::from);
+    assert_complex_eq(&us.dot(&vt), &a, 1e-10);
+
+    // exactly zero singular values
+    let zero = svd(&Array2::<f64>::zeros((4, 3)), SvdMode::Thin).unwrap();
+    assert_eq!(zero.s, Array1::zeros(3));
+    let u = zero.u.unwrap();
+    assert_abs_diff_eq!(u.t().dot(&u), Array2::eye(3), epsilon = 1e-12);
+}
+
+#[test]
+fn svd_not_finite()
+{
+    let mut a = matrix(4, 4, 18);
+    a[[2, 1]] = f64::NAN;
+    assert_eq!(svd(&a, SvdMode::ValuesOnly).unwrap_err(), LinalgError::NotConverged);
+    assert_eq!(eigh(&a).unwrap_err(), LinalgError::NotConverged);
+}
+
+#[test]
+fn eigh_and_svd_extreme_magnitudes()
+{
+    for &scale in &[1e200, 1e-200] {
+        let a = array![[1., 0.1], [0.1, 1.]] * scale;
+        let w = eigvalsh(&a).unwrap() / scale;
+        assert_abs_diff_eq!(w, array![0.9, 1.1], epsilon = 1e-14);
+        let (w, v) = eigh(&a).unwrap();
+        assert_abs_diff_eq!(a.dot(&v) / scale, &v * &w / scale, epsilon = 1e-14);
+
+        let b = array![[1., 0.1], [0.3, 1.]];
+        let expected = svd(&b, SvdMode::ValuesOnly).unwrap().s;
+        let sb = svd(&(&b * scale), SvdMode::Thin).unwrap();
+        assert_abs_diff_eq!(&sb.s / scale, expected, epsilon = 1e-14);
+        let us = sb.u.unwrap() * &sb.s;
+        assert_abs_diff_eq!(us.dot(&sb.vt.unwrap()) / scale, b, epsilon = 1e-14);
+
+        let c = array![[1., 0.], [0., 0.]] * scale;
+        assert_eq!(svd(&c, SvdMode::ValuesOnly).unwrap().s, array![scale, 0.]);
+        assert_eq!(c.norm_nuclear(), scale);
+        assert_eq!(c.norm_induced(InducedNorm::Two), scale);
+    }
+    let a = array![[1e160, 1e159], [3e159, 1e160]];
+    assert_abs_diff_eq!(a.norm_induced(InducedNorm::Two) / 1e160, array![[1., 0.1], [0.3, 1.]].norm_induced(InducedNorm::Two), epsilon = 1e-14);
+}
+
+#[test]
+fn vector_norms()
+{
+    let a = array![[3., -4.], [0., 12.]];
+    assert_eq!(a.norm_l1(), 19.);
+    assert_eq!(a.norm_l2(), 13.);
+    assert_eq!(a.norm_max(), 12.);
+    assert_eq!(a.t().norm_l2(), 13.);
+    let z = array![Complex64::new(3., 4.), Complex64::new(0., -12.)];
+    assert_eq!(z.norm_l1(), 17.);
+    assert_eq!(z.norm_l2(), 13.);
+    assert_eq!(z.norm_max(), 12.);
+    let empty = Array1::<f64>::zeros(0);
+    assert_eq!((empty.norm_l1(), empty.norm_l2(), empty.norm_max()), (0., 0., 0.));
+
+    // large enough for BLAS, with a layout that BLAS can use and one it can't
+    let a = matrix(40, 30, 0);
+    let expected = a.iter().map(|x| x * x).sum::<f64>().sqrt();
+    assert_abs_diff_eq!(a.norm_l2(), expected, epsilon = 1e-12);
+    let v = a.slice(s![..;-2, ..;3]);
+    assert_abs_diff_eq!(v.norm_l1(), v.mapv(f64::abs).sum(), epsilon = 1e-12);
+}
+
+#[test]
+fn norm_l2_does_not_overflow()
+{
+    assert_eq!(array![1e300, 1e300].norm_l2(), 1e300 * 2f64.sqrt());
+    assert_eq!(array![1e-300, 1e-300].norm_l2(), 1e-300 * 2f64.sqrt());
+    assert_eq!(array![3e30f32, 4e30].norm_l2(), 5e30);
+    assert_eq!(array![1., f64::INFINITY, f64::INFINITY].norm_l2(), f64::INFINITY);
+    assert!(array![1., f64::NAN, f64::INFINITY].norm_l2().is_nan());
+    assert!(array![1., f64::NAN].norm_max().is_nan());
+}
+
+#[test]
+fn matrix_norms()
+{
+    let a: Array2<f64> = array![[1., -2., 0.], [3., 4., -5.]];
+    assert_eq!(a.norm_induced(InducedNorm::One), 6.);
+    assert_eq!(a.norm_induced(InducedNorm::Infinity), 12.);
+    assert_eq!(a.norm_fro(), 55f64.sqrt());
+    let s = svd(&a, SvdMode::ValuesOnly).unwrap().s;
+    assert_abs_diff_eq!(a.norm_induced(InducedNorm::Two), s[0], epsilon = 1e-12);
+    assert_abs_diff_eq!(a.norm_nuclear(), s.sum(), epsilon = 1e-12);
+    // ‖A‖₂² is the largest eigenvalue of Aᵀ A
+    let w = eigvalsh(&a.dot(&a.t())).unwrap();
+    assert_abs_diff_eq!(a.norm_induced(InducedNorm::Two).powi(2), w[1], epsilon = 1e-10);
+    assert!(array![[1., f64::NAN]].norm_nuclear().is_nan());
+}
+
+#[test]
+fn trace_and_matrix_power()
+{
+    let a = matrix(6, 6, 1);
+    assert_abs_diff_eq!(a.trace(), (0..6).map(|i| a[[i, i]]).sum::<f64>(), epsilon = 1e-15);
+    assert_eq!(array![[1., 2., 3.], [4., 5., 6.]].trace(), 6.);
+
+    let mut power = Array2::eye(6);
+    for n in 0..12 {
+        assert_abs_diff_eq!(a.matrix_power(n).unwrap(), power, epsilon = 1e-10);
+        power = power.dot(&a);
+    }
+    let inv = a.inv().unwrap();
+    assert_abs_diff_eq!(a.matrix_power(-3).unwrap(), inv.dot(&inv).dot(&inv), epsilon = 1e-8);
+    assert_eq!(Array2::<f64>::zeros((2, 3)).matrix_power(2).unwrap_err(), LinalgError::NotSquare { rows: 2, cols: 3 });
+    assert!(matches!(Array2::<f64>::zeros((2, 2)).matrix_power(-1), Err(LinalgError::Singular { .. })));
+}
+
+#[test]
+fn cond_est_bounds_condition_number()
+{
+    for &n in &[1, 2, 10, 50] {
+        let a = matrix(n, n, n);
+        let exact = a.norm_induced(InducedNorm::One) * a.inv().unwrap().norm_induced(InducedNorm::One);
+        let estimate = a.cond_est().unwrap();
+        assert!(estimate <= exact * (1. + 1e-10), "{} > {}", estimate, exact);
+        assert!(estimate >= exact / 3., "{} < {} / 3", estimate, exact);
+    }
+    let a = complex_matrix(20, 20, 5);
+    let exact = a.norm_induced(InducedNorm::One) * a.inv().unwrap().norm_induced(InducedNorm::One);
+    let estimate = a.cond_est().unwrap();
+    assert!(estimate <= exact * (1. + 1e-10) && estimate >= exact / 3.);
+
+    assert_eq!(array![[1., 2.], [2., 4.]].cond_est().unwrap(), f64::INFINITY);
+    assert_eq!(Array2::<f64>::zeros((0, 0)).cond_est().unwrap(), 0.);
+    assert!(Array2::<f64>::zeros((1, 2)).cond_est().is_err());
+}
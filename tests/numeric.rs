@@ -3,7 +3,27 @@
 )]
 
 use approx::assert_abs_diff_eq;
-use ndarray::{arr0, arr1, arr2, array, aview1, Array, Array1, Array2, Array3, Axis};
+use ndarray::{
+    arr0,
+    arr1,
+    arr2,
+    array,
+    aview1,
+    s,
+    Array,
+    Array1,
+    Array2,
+    Array3,
+    Axis,
+    Compensated,
+    MinMaxError,
+    Naive,
+    NanPolicy,
+    Pairwise,
+    QuantileMethod,
+    RemoveAxis,
+    ScanMode,
+};
 use std::f64;
 
 #[test]
@@ -475,3 +495,284 @@ fn diff_panic_axis_out_of_bounds()
     let data = array![1, 2, 4, 7];
     data.diff(1, Axis(2));
 }
+
+#[test]
+fn min_max_nan_policies()
+{
+    let a = array![[2., f64::NAN, -1.], [4., -1., f64::NAN]];
+    assert_eq!(a.min(NanPolicy::Skip), Ok(&-1.));
+    assert_eq!(a.max(NanPolicy::Skip), Ok(&4.));
+    assert_eq!(a.argmin(NanPolicy::Skip), Ok((0, 2)));
+    assert_eq!(a.argmax(NanPolicy::Skip), Ok((1, 0)));
+    assert_eq!(a.argmin(NanPolicy::Propagate), Ok((0, 1)));
+    assert!(a.max(NanPolicy::Propagate).unwrap().is_nan());
+    assert_eq!(a.argmax(NanPolicy::Error), Err(MinMaxError::UndefinedOrder));
+
+    // ties resolve to the first element in logical order
+    let b = array![[1, 3], [3, 1]];
+    assert_eq!(b.t().argmax(NanPolicy::Error), Ok((0, 1)));
+    assert_eq!(b.t().argmin(NanPolicy::Error), Ok((0, 0)));
+
+    let empty = Array2::<f64>::zeros((2, 0));
+    assert_eq!(empty.min(NanPolicy::Skip), Err(MinMaxError::EmptyInput));
+    assert_eq!(array![f64::NAN].argmax(NanPolicy::Skip), Err(MinMaxError::EmptyInput));
+}
+
+#[test]
+fn min_max_axis()
+{
+    let a = array![[2., f64::NAN, -1.], [4., -1., f64::NAN]];
+    assert_eq!(a.argmin_axis(Axis(0), NanPolicy::Skip), Ok(array![0, 1, 0]));
+    assert_eq!(a.argmax_axis(Axis(1), NanPolicy::Skip), Ok(array![0, 0]));
+    assert_eq!(a.argmax_axis(Axis(1), NanPolicy::Propagate), Ok(array![1, 2]));
+    assert_eq!(a.min_axis(Axis(1), NanPolicy::Skip), Ok(array![-1., -1.]));
+    assert_eq!(a.max_axis(Axis(0), NanPolicy::Error), Err(MinMaxError::UndefinedOrder));
+
+    let b = Array::from_iter(0..24)
+        .into_shape_with_order((2, 3, 4))
+        .unwrap();
+    assert_eq!(b.max_axis(Axis(1), NanPolicy::Error), Ok(b.index_axis(Axis(1), 2).to_owned()));
+    assert_eq!(b.argmin_axis(Axis(2), NanPolicy::Error), Ok(Array2::zeros((2, 3))));
+
+    let c = array![[f64::NAN], [1.]];
+    assert_eq!(c.min_axis(Axis(1), NanPolicy::Skip), Err(MinMaxError::EmptyInput));
+    assert_eq!(Array2::<i32>::zeros((0, 3)).argmax_axis(Axis(0), NanPolicy::Error), Err(MinMaxError::EmptyInput));
+}
+
+#[test]
+fn test_cumsum_cummin_cummax()
+{
+    let a = array![[1, 5, 2], [4, 0, 6]];
+    assert_eq!(a.cumsum(Axis(0)), array![[1, 5, 2], [5, 5, 8]]);
+    assert_eq!(a.cumsum(Axis(1)), array![[1, 6, 8], [4, 4, 10]]);
+    assert_eq!(a.t().cumsum(Axis(0)), array![[1, 6, 8], [4, 4, 10]].t());
+    assert_eq!(a.cummin(Axis(1)), array![[1, 1, 1], [4, 0, 0]]);
+    assert_eq!(a.cummax(Axis(0)), array![[1, 5, 2], [4, 5, 6]]);
+
+    let b = array![2., f64::NAN, 1., 3.];
+    let cmax = b.cummax(Axis(0));
+    assert_eq!(cmax[0], 2.);
+    assert!(cmax.slice(s![1..]).iter().all(|x| x.is_nan()));
+    assert_eq!(Array2::<f64>::zeros((0, 3)).cumsum(Axis(0)).shape(), &[0, 3]);
+}
+
+#[test]
+fn test_scan_axis()
+{
+    let a = Array::from_iter(1..=12)
+        .into_shape_with_order((2, 3, 2))
+        .unwrap();
+    for ax in 0..3 {
+        let axis = Axis(ax);
+        let inclusive = a.scan_axis(axis, 0, ScanMode::Inclusive, |&acc, &x| acc + x);
+        assert_eq!(inclusive, a.cumsum(axis));
+
+        let exclusive = a.scan_axis(axis, 0, ScanMode::Exclusive, |&acc, &x| acc + x);
+        assert_eq!(exclusive.index_axis(axis, 0), Array::zeros(a.raw_dim().remove_axis(axis)));
+        assert_eq!(exclusive.slice_axis(axis, (1..).into()), inclusive.slice_axis(axis, (..-1).into()));
+
+        let mut b = a.clone();
+        b.scan_axis_inplace(axis, 0, ScanMode::Inclusive, |&acc, &x| acc + x);
+        assert_eq!(b, inclusive);
+        let mut b = a.clone();
+        b.scan_axis_inplace(axis, 0, ScanMode::Exclusive, |&acc, &x| acc + x);
+        assert_eq!(b, exclusive);
+    }
+
+    let s = array!["a", "b", "c"].scan_axis(Axis(0), String::new(), ScanMode::Exclusive, |acc, x| acc.clone() + x);
+    assert_eq!(s, array!["".to_string(), "a".to_string(), "ab".to_string()]);
+}
+
+#[test]
+fn reductions_over_several_axes()
+{
+    let a = Array::from_iter(0..60)
+        .mapv(|x| x as f64)
+        .into_shape_with_order((3, 4, 5))
+        .unwrap();
+
+    // reducing one axis matches the single-axis reductions
+    for ax in 0..3 {
+        let axis = Axis(ax);
+        assert_eq!(a.sum_axes(&[axis], false), a.sum_axis(axis).into_dyn());
+        assert_eq!(a.mean_axes(&[axis], false), a.mean_axis(axis).map(|m| m.into_dyn()));
+    }
+
+    // the order of the axes does not matter
+    let sum = a.sum_axes(&[Axis(2), Axis(0)], false);
+    assert_eq!(sum, a.sum_axis(Axis(2)).sum_axis(Axis(0)).into_dyn());
+    assert_eq!(a.sum_axes(&[Axis(0), Axis(2)], true), sum.into_shape_with_order(vec![1, 4, 1]).unwrap());
+    assert_eq!(a.sum_axes(&[Axis(0), Axis(1), Axis(2)], false), arr0(a.sum()).into_dyn());
+    assert_eq!(a.sum_axes(&[], true), a.clone().into_dyn());
+
+    let fold = a.t().fold_axes(&[Axis(1)], 0., false, |&acc, &x| acc + x);
+    assert_eq!(fold, a.sum_axis(Axis(1)).t().into_dyn());
+    assert_eq!(Array3::<f64>::zeros((2, 0, 3)).mean_axes(&[Axis(0), Axis(1)], false), None);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn var_and_std_over_several_axes()
+{
+    let a = Array::from_iter(0..60)
+        .mapv(|x| x as f64)
+        .into_shape_with_order((3, 4, 5))
+        .unwrap();
+
+    for ax in 0..3 {
+        let axis = Axis(ax);
+        assert_abs_diff_eq!(a.var_axes(&[axis], 1., false), a.var_axis(axis, 1.).into_dyn(), epsilon = 1e-10);
+    }
+
+    let var = a.var_axes(&[Axis(1), Axis(2)], 0., true);
+    assert_eq!(var.shape(), &[3, 1, 1]);
+    for (i, sub) in a.outer_iter().enumerate() {
+        assert_abs_diff_eq!(var[[i, 0, 0]], sub.var(0.), epsilon = 1e-10);
+    }
+    assert_abs_diff_eq!(
+        a.std_axes(&[Axis(1), Axis(2)], 0., false),
+        var.mapv(f64::sqrt).into_shape_with_order(vec![3]).unwrap(),
+        epsilon = 1e-12
+    );
+}
+
+#[test]
+#[should_panic]
+fn reductions_over_repeated_axes()
+{
+    let a = Array2::<f64>::zeros((2, 3));
+    a.sum_axes(&[Axis(1), Axis(1)], false);
+}
+
+#[test]
+fn sum_with_methods()
+{
+    let a = Array::from_shape_fn(100_003, |i| 0.1f32 + (i % 7) as f32 * 1e-3);
+    let exact: f64 = a.iter().map(|&x| x as f64).sum();
+
+    let naive = a.sum_with(Naive);
+    let pairwise = a.sum_with(Pairwise);
+    let compensated = a.sum_with(Compensated);
+    assert_eq!(naive, a.sum());
+    assert!((pairwise as f64 - exact).abs() < 1e-3 * exact.abs().sqrt());
+    assert!((compensated as f64 - exact).abs() <= exact * f32::EPSILON as f64);
+    assert!((compensated as f64 - exact).abs() < (naive as f64 - exact).abs());
+
+    // non-contiguous arrays sum the same elements
+    let b = a
+        .slice(s![..100_002])
+        .into_shape_with_order((2, 50_001))
+        .unwrap();
+    let bt = b.t();
+    assert_eq!(bt.sum_with(Compensated), b.sum_with(Compensated));
+    assert_abs_diff_eq!(bt.sum_with(Pairwise), b.sum_with(Pairwise), epsilon = 1e-1);
+    assert_eq!(bt.sum_axis_with(Axis(0), Compensated), b.sum_axis_with(Axis(1), Compensated));
+    assert_abs_diff_eq!(bt.sum_axis_with(Axis(0), Pairwise), b.sum_axis(Axis(1)), epsilon = 1e-1);
+
+    assert_eq!(Array1::<f32>::zeros(0).mean_with(Pairwise), None);
+    assert_eq!(array![1., 2., 4.].mean_with(Compensated), Some(7. / 3.));
+    let ones = Array1::<f32>::ones(a.len());
+    assert_eq!(a.dot_with(&ones, Compensated), compensated);
+    assert_eq!(a.dot_with(&ones, Naive), a.iter().fold(0., |acc, &x| acc + x));
+}
+
+#[test]
+fn sum_with_not_finite()
+{
+    let inf = f64::INFINITY;
+    for a in [array![1., inf, 2.], array![f64::MAX, f64::MAX], array![-1., 1e300, 1e308, f64::MAX]] {
+        assert_eq!(a.sum_with(Compensated), inf);
+        assert_eq!(a.sum_with(Compensated), a.sum_with(Pairwise));
+        assert_eq!(a.mean_with(Compensated), Some(inf));
+    }
+    assert_eq!(array![1., -inf, 2.].sum_with(Compensated), -inf);
+    assert_eq!(array![-f64::MAX, -f64::MAX, 1.].sum_with(Compensated), -inf);
+    assert!(array![1., inf, -inf].sum_with(Compensated).is_nan());
+    assert!(array![1., f64::NAN, 2.].sum_with(Compensated).is_nan());
+
+    let b = array![[1., inf], [2., 3.]];
+    assert_eq!(b.sum_axis_with(Axis(0), Compensated), array![3., inf]);
+    assert_eq!(b.t().sum_axis_with(Axis(1), Compensated), array![3., inf]);
+    assert_eq!(array![f64::MAX, 2.].dot_with(&array![2., 1.], Compensated), inf);
+    assert_eq!(array![3i32, -5, 7].sum_with(Compensated), 5);
+}
+
+#[test]
+fn quantile_methods()
+{
+    let a = array![10., 1., 7., 4.];
+    // sorted: 1, 4, 7, 10; q = 0.5 is at position 1.5
+    assert_eq!(a.quantile(0.5, QuantileMethod::Lower), Some(4.));
+    assert_eq!(a.quantile(0.5, QuantileMethod::Higher), Some(7.));
+    assert_eq!(a.quantile(0.5, QuantileMethod::Nearest), Some(7.));
+    assert_eq!(a.quantile(0.5, QuantileMethod::Midpoint), Some(5.5));
+    assert_eq!(a.quantile(0.5, QuantileMethod::Linear), Some(5.5));
+    // q = 0.3 is at position 0.9
+    assert_eq!(a.quantile(0.3, QuantileMethod::Nearest), Some(4.));
+    assert_eq!(a.quantile(0.3, QuantileMethod::Midpoint), Some(2.5));
+    assert_abs_diff_eq!(a.quantile(0.3, QuantileMethod::Linear).unwrap(), 3.7, epsilon = 1e-12);
+    assert_eq!(a.quantile(0., QuantileMethod::Higher), Some(1.));
+    assert_eq!(a.quantile(1., QuantileMethod::Lower), Some(10.));
+    // position 2.5 rounds to the even index
+    assert_eq!(array![1, 2, 3, 4, 5, 6].quantile(0.5, QuantileMethod::Nearest), Some(3));
+    assert_eq!(array![1, 2, 3, 4].quantile(0.5, QuantileMethod::Linear), Some(2));
+
+    assert!(array![1., f64::NAN, 2.].median().unwrap().is_nan());
+    let m = array![[1., f64::NAN, 2.], [3., 1., 2.]]
+        .median_axis(Axis(1))
+        .unwrap();
+    assert!(m[0].is_nan());
+    assert_eq!(m[1], 2.);
+    assert_eq!(Array1::<f64>::zeros(0).median(), None);
+}
+
+#[test]
+fn quantile_axis_any_layout()
+{
+    let a = Array::from_shape_fn((4, 5, 3), |(i, j, k)| ((i * 7 + j * 3 + k * 11) % 13) as f64);
+    for axis in 0..3 {
+        let axis = Axis(axis);
+        let q = a.quantile_axis(axis, 0.4, QuantileMethod::Linear).unwrap();
+        let mut sorted = a.clone();
+        sorted.sort_axis_by(axis, |x, y| x.total_cmp(y));
+        let pos = 0.4 * (a.len_of(axis) - 1) as f64;
+        let (i, frac) = (pos as usize, pos.fract());
+        let lo = sorted.index_axis(axis, i);
+        let hi = sorted.index_axis(axis, (i + 1).min(a.len_of(axis) - 1));
+        assert_abs_diff_eq!(q, &lo + &((&hi - &lo) * frac), epsilon = 1e-12);
+
+        let at = a.t();
+        let reversed = Axis(2 - axis.index());
+        assert_eq!(at.median_axis(reversed).unwrap(), a.median_axis(axis).unwrap().t());
+    }
+    assert_eq!(Array2::<f64>::zeros((0, 3)).median_axis(Axis(0)), None);
+    assert_eq!(Array2::<f64>::zeros((0, 3)).median_axis(Axis(1)), Some(Array1::zeros(0)));
+}
+
+#[test]
+#[should_panic]
+fn quantile_out_of_range()
+{
+    array![1., 2.].quantile(1.5, QuantileMethod::Linear);
+}
+
+#[test]
+fn histogram_and_bincount()
+{
+    let a = array![[0.2, 1.0, 5.0], [-1.0, 4.0, f64::NAN], [4.5, 2.0, 1.9]];
+    assert_eq!(a.histogram(&array![0., 1., 2., 5.]), array![1, 2, 4]);
+    assert_eq!(a.t().histogram(&array![1., 4.5]), array![5]);
+
+    let b = array![[2, 0], [2, 5]];
+    assert_eq!(b.bincount(0), array![1, 0, 2, 0, 0, 1]);
+    assert_eq!(b.t().bincount(7), array![1, 0, 2, 0, 0, 1, 0]);
+    assert_eq!(Array1::<usize>::zeros(0).bincount(2), array![0, 0]);
+    assert_eq!(b.bincount_weighted(&array![[1, 2], [3, 4]], 0), array![2, 0, 4, 0, 0, 4]);
+}
+
+#[test]
+#[should_panic]
+fn histogram_unsorted_edges()
+{
+    array![1., 2.].histogram(&array![0., 2., 1.]);
+}
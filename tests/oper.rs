@@ -1,6 +1,9 @@
 #![allow(clippy::many_single_char_names, clippy::deref_addrof, clippy::unreadable_literal)]
+use ndarray::linalg::general_batched_mat_mul;
 use ndarray::linalg::general_mat_mul;
+use ndarray::linalg::general_mat_mul_widening;
 use ndarray::linalg::kron;
+use ndarray::linalg::{outer, tensordot};
 use ndarray::prelude::*;
 #[cfg(feature = "approx")]
 use ndarray::Order;
@@ -459,6 +462,101 @@ fn mat_mul_rev()
     assert_eq!(c1, c2);
 }
 
+#[test]
+fn mat_mul_gram()
+{
+    // a matrix times its own transpose only computes one triangle
+    for &(m, k) in &[(0, 3), (3, 0), (1, 1), (5, 3), (40, 90), (90, 40)] {
+        let a = range_mat::<i64>(m, k);
+        let views = [a.view(), a.t(), a.slice(s![..;-1, ..]), a.slice(s![.., ..;2])];
+        for v in &views {
+            let answer = reference_mat_mul(v, &v.t());
+            assert_eq!(v.dot(&v.t()), answer);
+            assert_eq!(v.t().dot(v).dim(), (v.ncols(), v.ncols()));
+            assert_eq!(v.t().dot(v), reference_mat_mul(&v.t(), v));
+        }
+    }
+    let a = range_mat::<f64>(130, 20) / 100.;
+    let gram = a.t().dot(&a);
+    assert_abs_diff_eq!(gram, reference_mat_mul(&a.t(), &a), epsilon = 1e-9);
+    assert_eq!(gram, gram.t());
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn mat_mul_op_complex()
+{
+    use ndarray::linalg::{general_mat_mul_op, general_mat_vec_mul_op, Transpose};
+    use num_complex::Complex64;
+
+    // integer valued elements, so that the products are exact
+    let cmat = |m, n, s: usize| {
+        Array::from_shape_fn((m, n), |(i, j)| {
+            Complex64::new(((i * s + j) % 7) as f64 - 3., ((i + j * s) % 5) as f64 - 2.)
+        })
+    };
+    let apply = |a: &Array2<Complex64>, trans| match trans {
+        Transpose::NoTrans => a.clone(),
+        Transpose::Trans => a.t().to_owned(),
+        Transpose::ConjTrans => a.t().mapv(|x| x.conj()),
+    };
+    let stored = |m, n, trans| if trans == Transpose::NoTrans { (m, n) } else { (n, m) };
+    let ops = [Transpose::NoTrans, Transpose::Trans, Transpose::ConjTrans];
+    let alpha = Complex64::new(2., -1.);
+    let beta = Complex64::new(0.5, 1.);
+
+    for &(m, k, n) in &[(0, 3, 2), (4, 0, 3), (3, 5, 4), (17, 9, 12)] {
+        for &trans_a in &ops {
+            let (am, an) = stored(m, k, trans_a);
+            let a = cmat(am, an, 3);
+            let opa = apply(&a, trans_a);
+
+            for &trans_b in &ops {
+                let (bm, bn) = stored(k, n, trans_b);
+                let b = cmat(bm, bn, 2);
+                let opb = apply(&b, trans_b);
+                let answer = reference_mat_mul(&opa, &opb);
+                assert_eq!(a.dot_op(trans_a, &b, trans_b), answer);
+
+                for f_order in [false, true] {
+                    let c0 = cmat(m, n, 1);
+                    let mut c = Array2::zeros((m, n).set_f(f_order));
+                    c.assign(&c0);
+                    general_mat_mul_op(alpha, &a, trans_a, &b, trans_b, beta, &mut c);
+                    assert_eq!(c, &answer * alpha + &c0 * beta);
+                }
+            }
+
+            for s in [1isize, -2] {
+                let x = cmat(k * s.unsigned_abs(), 1, 4);
+                let x = x.slice(s![..;s, 0]);
+                let y0 = cmat(m, 1, 5).remove_axis(Axis(1));
+                let mut y = y0.clone();
+                general_mat_vec_mul_op(alpha, &a, trans_a, &x, beta, &mut y);
+                let answer = reference_mat_mul(&opa, &x.insert_axis(Axis(1))).remove_axis(Axis(1));
+                assert_eq!(y, answer * alpha + &y0 * beta);
+            }
+        }
+    }
+
+    // products of a matrix with its own (conjugate) transpose
+    let a = cmat(40, 90, 3);
+    for v in [a.view(), a.t(), a.slice(s![..;-1, ..]), a.slice(s![.., ..;2])] {
+        let v = &v;
+        let vh = v.t().mapv(|x| x.conj());
+        let vc = v.mapv(|x| x.conj());
+        let aah = v.dot_op(Transpose::NoTrans, v, Transpose::ConjTrans);
+        assert_eq!(aah, reference_mat_mul(v, &vh));
+        assert_eq!(aah, aah.t().mapv(|x| x.conj()));
+        assert_eq!(v.dot_op(Transpose::ConjTrans, v, Transpose::NoTrans), reference_mat_mul(&vh, v));
+        assert_eq!(v.dot_op(Transpose::NoTrans, v, Transpose::Trans), reference_mat_mul(v, &v.t()));
+        assert_eq!(
+            v.dot_op(Transpose::ConjTrans, &v.t(), Transpose::ConjTrans),
+            reference_mat_mul(&vh, &vc)
+        );
+    }
+}
+
 // Check that matrix multiplication supports arrays with zero rows or columns
 #[test]
 fn mat_mut_zero_len()
@@ -680,6 +778,92 @@ fn gen_mat_mul_i32()
     }
 }
 
+#[test]
+fn gen_mat_mul_widening_int()
+{
+    let alpha = 3;
+    let beta = -2;
+    let sizes = if cfg!(miri) {
+        vec![(4, 4, 4), (4, 7, 3)]
+    } else {
+        vec![(0, 3, 2), (3, 0, 2), (4, 4, 4), (17, 3, 22), (5, 7, 1), (70, 300, 260), (20, 600, 40)]
+    };
+    for &(m, k, n) in &sizes {
+        let a = Array::from_shape_fn((m, k), |(i, j)| (i * 7 + j * 13) as u8 as i8);
+        let b = Array::from_shape_fn((k, n), |(i, j)| (i * 11 + j * 5 + 3) as u8);
+        let c0 = range_i32(m, n);
+        let answer = alpha * reference_mat_mul(&a.mapv(i32::from), &b.mapv(i32::from)) + beta * &c0;
+
+        let mut c = c0.clone();
+        general_mat_mul_widening(alpha, &a, &b, beta, &mut c);
+        assert_eq!(c, answer);
+
+        // transposed and strided operands
+        let at = a.t().to_owned();
+        let bt = b.t().as_standard_layout().into_owned();
+        let mut c = c0.clone();
+        general_mat_mul_widening(alpha, &at.t(), &bt.t(), beta, &mut c);
+        assert_eq!(c, answer);
+
+        let a2 = a.t().to_owned().reversed_axes();
+        let mut c = Array2::zeros((2 * m, n));
+        c.slice_mut(s![..;2, ..]).assign(&c0);
+        general_mat_mul_widening(alpha, &a2, &b, beta, &mut c.slice_mut(s![..;2, ..]));
+        assert_eq!(c.slice(s![..;2, ..]), answer);
+
+        let aa = a.mapv(i32::from);
+        assert_eq!(a.dot_widening::<i32, _>(&a.t()), aa.dot(&aa.t()));
+
+        // wider inputs and accumulators
+        let a = a.mapv(|x| i16::from(x) * 200);
+        let b = b.mapv(|x| u16::from(x) * 250);
+        let answer = reference_mat_mul(&a.mapv(i64::from), &b.mapv(i64::from));
+        assert_eq!(a.dot_widening::<i64, _>(&b), answer);
+
+        let a = a.mapv(i32::from);
+        let b = b.mapv(i32::from);
+        assert_eq!(a.dot_widening::<i64, _>(&b), answer);
+    }
+
+    // the generic kernel across the edges of its blocks
+    for &(m, k, n) in &[(70, 300, 9), (9, 300, 530)] {
+        let a = Array::from_shape_fn((m, k), |(i, j)| ((i * 3 + j) % 17) as i32 - 8);
+        let b = Array::from_shape_fn((k, n), |(i, j)| ((i + j * 5) % 13) as i32 - 6);
+        let mut c = range_i32(m, n);
+        let answer = alpha * reference_mat_mul(&a, &b) + beta * &c;
+        general_mat_mul_widening(alpha, &a, &b, beta, &mut c);
+        assert_eq!(c, answer);
+    }
+}
+
+#[test]
+fn gen_mat_mul_widening_f32_to_f64()
+{
+    // the product of values of 2⁻¹² is exact in f64 but not in f32
+    let (m, k, n) = (9, 70, 12);
+    let a = Array::from_shape_fn((m, k), |(i, j)| 1. + ((i + j) % 5) as f32 / 4096.);
+    let b = Array::from_shape_fn((k, n), |(i, j)| 1. - ((i * j) % 3) as f32 / 4096.);
+    let answer = reference_mat_mul(&a.mapv(f64::from), &b.mapv(f64::from));
+
+    let c: Array2<f64> = a.dot_widening(&b);
+    assert_eq!(c, answer);
+    assert_ne!(a.dot(&b).mapv(f64::from), answer);
+
+    let mut c = Array2::ones((m, n));
+    general_mat_mul_widening(2., &a, &b, 0.5, &mut c);
+    assert_eq!(c, 2. * answer + 0.5);
+}
+
+#[test]
+#[should_panic]
+fn gen_mat_mul_widening_shape_error()
+{
+    let a = Array2::<i8>::zeros((3, 4));
+    let b = Array2::<i8>::zeros((3, 4));
+    let mut c = Array2::<i32>::zeros((3, 4));
+    general_mat_mul_widening(1, &a, &b, 0, &mut c);
+}
+
 #[cfg(feature = "approx")]
 #[test]
 #[cfg_attr(miri, ignore)] // Takes too long
@@ -812,6 +996,96 @@ fn vec_mat_mul()
     }
 }
 
+#[test]
+fn matmul_batch_broadcast()
+{
+    let a = Array::from_shape_fn((3, 1, 4, 5), |(b, _, i, j)| (b * 7 + i * 3 + j) as f64 % 11.);
+    let b = Array::from_shape_fn((2, 5, 6), |(b, i, j)| (b * 5 + i + j * 2) as f64 % 13.);
+    let c = a.matmul(&b);
+    assert_eq!(c.shape(), &[3, 2, 4, 6]);
+    for i in 0..3 {
+        for j in 0..2 {
+            let ab = reference_mat_mul(&a.slice(s![i, 0, .., ..]), &b.slice(s![j, .., ..]));
+            assert_eq!(c.slice(s![i, j, .., ..]), ab);
+        }
+    }
+
+    // the same matrix for the whole batch, in contiguous and strided batches
+    let w = b.index_axis(Axis(0), 1);
+    let c = a.matmul(&w);
+    assert_eq!(c.shape(), &[3, 1, 4, 6]);
+    let a_strided = a.slice(s![..;-1, .., ..;2, ..]);
+    let c_strided = a_strided.matmul(&w);
+    for i in 0..3 {
+        assert_eq!(c.slice(s![i, 0, .., ..]), reference_mat_mul(&a.slice(s![i, 0, .., ..]), &w));
+        assert_eq!(c_strided.slice(s![2 - i, 0, .., ..]), c.slice(s![i, 0, ..;2, ..]));
+    }
+
+    // integers use the fallback loop
+    let ai = a.mapv(|x| x as i32);
+    let bi = b.mapv(|x| x as i32);
+    assert_eq!(ai.matmul(&bi), a.matmul(&b).mapv(|x| x as i32));
+}
+
+#[test]
+fn matmul_vectors()
+{
+    let a = Array::from_shape_fn((2, 3, 4), |(b, i, j)| (b + i * 4 + j) as f32);
+    let v = array![1., -1., 2., 0.5];
+    let u = array![0.5, 1., -2.];
+
+    let av = a.matmul(&v);
+    assert_eq!(av.shape(), &[2, 3]);
+    for b in 0..2 {
+        assert_eq!(av.index_axis(Axis(0), b), a.index_axis(Axis(0), b).dot(&v).into_dyn());
+    }
+    let ua = u.matmul(&a);
+    assert_eq!(ua.shape(), &[2, 4]);
+    for b in 0..2 {
+        assert_eq!(ua.index_axis(Axis(0), b), u.dot(&a.index_axis(Axis(0), b)).into_dyn());
+    }
+    assert_eq!(v.matmul(&v), arr0(v.dot(&v)).into_dyn());
+    assert_eq!(u.matmul(&a).shape(), u.matmul(&a.view().into_dyn()).shape());
+}
+
+#[test]
+fn batched_mat_mul_alpha_beta()
+{
+    let a = Array::from_shape_fn((4, 3, 2), |(b, i, j)| (b * 6 + i * 2 + j) as f64);
+    let b = Array::from_shape_fn((4, 3, 5), |(b, i, j)| (b + i * j) as f64);
+    let mut c = Array::from_shape_fn((4, 2, 5), |(b, i, j)| (b + i + j) as f64);
+    let mut answer = c.clone();
+    for ((mut ans, a), b) in answer
+        .outer_iter_mut()
+        .zip(a.outer_iter())
+        .zip(b.outer_iter())
+    {
+        let part = 2. * reference_mat_mul(&a.t(), &b) - &ans;
+        ans.assign(&part);
+    }
+    // transposed matrices of `a`
+    general_batched_mat_mul(2., &a.view().permuted_axes([0, 2, 1]), &b, -1., &mut c);
+    assert_eq!(c, answer);
+
+    let mut empty = Array3::<f64>::zeros((0, 2, 5));
+    general_batched_mat_mul(
+        1.,
+        &a.slice(s![..0, .., ..]).permuted_axes([0, 2, 1]),
+        &b.slice(s![..1, .., ..]),
+        0.,
+        &mut empty,
+    );
+}
+
+#[test]
+#[should_panic]
+fn matmul_batch_shape_mismatch()
+{
+    let a = Array3::<f64>::zeros((3, 2, 2));
+    let b = Array3::<f64>::zeros((2, 2, 2));
+    a.matmul(&b);
+}
+
 #[test]
 fn kron_square_f64()
 {
@@ -869,3 +1143,85 @@ fn kron_i64()
     let r = arr2(&[[0, 1, 0, 0], [1, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 1], [0, 0, 1, 0]]);
     assert_eq!(kron(&a, &b), r);
 }
+
+/// Brute-force contraction of axis 1 of `a` with axis 0 of `b` and axis 0 of
+/// `a` with axis 2 of `b`.
+fn reference_tensordot(a: &Array3<f64>, b: &Array3<f64>) -> Array2<f64>
+{
+    let (p, q, m) = a.dim();
+    let (_, n, _) = b.dim();
+    Array::from_shape_fn((m, n), |(i, j)| {
+        let mut sum = 0.;
+        for x in 0..p {
+            for y in 0..q {
+                sum += a[[x, y, i]] * b[[y, j, x]];
+            }
+        }
+        sum
+    })
+}
+
+#[test]
+fn tensordot_layouts()
+{
+    let a = Array::from_shape_fn((3, 4, 5), |(i, j, k)| (i * 20 + j * 5 + k) as f64);
+    let b = Array::from_shape_fn((4, 2, 3), |(i, j, k)| (i * 6 + j * 3 + k) as f64 - 10.);
+    let expected = reference_tensordot(&a, &b);
+
+    // contiguous, through gemm
+    let c = tensordot(&a, &b, &[Axis(0), Axis(1)], &[Axis(2), Axis(0)]);
+    assert_eq!(c, expected.clone().into_dyn());
+
+    // strided, through Zip
+    let a_big = Array::from_shape_fn((3, 8, 5), |(i, j, k)| (i * 20 + (j / 2) * 5 + k) as f64);
+    let a_strided = a_big.slice(s![.., ..;2, ..]);
+    let b_t = b.clone().reversed_axes();
+    let b_t = b_t.as_standard_layout();
+    let c = tensordot(&a_strided, &b_t.t(), &[Axis(0), Axis(1)], &[Axis(2), Axis(0)]);
+    assert_eq!(c, expected.into_dyn());
+}
+
+#[test]
+fn tensordot_matrix_product()
+{
+    let a = Array::from_shape_fn((3, 4), |(i, j)| (i + 2 * j) as f32);
+    let b = Array::from_shape_fn((4, 2), |(i, j)| (3 * i + j) as f32);
+    let c = tensordot(&a, &b, &[Axis(1)], &[Axis(0)]);
+    assert_eq!(c, a.dot(&b).into_dyn());
+    let c = tensordot(&a.t(), &b, &[Axis(0)], &[Axis(0)]);
+    assert_eq!(c, a.dot(&b).into_dyn());
+}
+
+#[test]
+fn outer_shapes()
+{
+    let a = array![[1, 2], [3, 4]];
+    let b = array![5, 6, 7];
+    let c = outer(&a, &b);
+    assert_eq!(c.shape(), &[2, 2, 3]);
+    for ((i, j, k), &x) in c.into_dimensionality::<Ix3>().unwrap().indexed_iter() {
+        assert_eq!(x, a[[i, j]] * b[k]);
+    }
+    let c = outer(&b.slice(s![..;-1]), &a.t());
+    assert_eq!(c.shape(), &[3, 2, 2]);
+    assert_eq!(c[[0, 1, 0]], 7 * 2);
+    let c = outer(&Array1::<i32>::zeros(0), &b);
+    assert_eq!(c.shape(), &[0, 3]);
+}
+
+#[test]
+#[should_panic]
+fn tensordot_length_mismatch()
+{
+    let a = Array2::<f64>::zeros((3, 4));
+    let b = Array2::<f64>::zeros((3, 4));
+    tensordot(&a, &b, &[Axis(1)], &[Axis(0)]);
+}
+
+#[test]
+#[should_panic]
+fn tensordot_repeated_axis()
+{
+    let a = Array2::<f64>::zeros((3, 3));
+    tensordot(&a, &a, &[Axis(0), Axis(0)], &[Axis(0), Axis(1)]);
+}
@@ -0,0 +1,163 @@
+use ndarray::prelude::*;
+use ndarray::{PadMode, Zip};
+
+#[test]
+fn pad_modes_1d()
+{
+    let a = array![1, 2, 3];
+    assert_eq!(a.pad(&[(2, 1)], PadMode::Constant(-1)), array![-1, -1, 1, 2, 3, -1]);
+    assert_eq!(a.pad(&[(2, 2)], PadMode::Edge), array![1, 1, 1, 2, 3, 3, 3]);
+    assert_eq!(a.pad(&[(5, 5)], PadMode::Reflect), array![2, 1, 2, 3, 2, 1, 2, 3, 2, 1, 2, 3, 2]);
+    assert_eq!(a.pad(&[(4, 4)], PadMode::Symmetric), array![3, 3, 2, 1, 1, 2, 3, 3, 2, 1, 1]);
+    assert_eq!(a.pad(&[(4, 4)], PadMode::Wrap), array![3, 1, 2, 3, 1, 2, 3, 1, 2, 3, 1]);
+    assert_eq!(a.pad(&[(0, 0)], PadMode::Wrap), a);
+
+    let one = array![7];
+    for mode in [PadMode::Edge, PadMode::Reflect, PadMode::Symmetric, PadMode::Wrap] {
+        assert_eq!(one.pad(&[(2, 3)], mode), Array1::from_elem(6, 7));
+    }
+}
+
+/// Pad `a` one element at a time, from the definition of each mode.
+fn pad_reference(a: &Array3<i32>, widths: &[(usize, usize)], mode: &PadMode<i32>) -> Array3<i32>
+{
+    let source = |i: usize, axis: usize| -> Option<usize> {
+        let n = a.len_of(Axis(axis)) as isize;
+        let mut i = i as isize - widths[axis].0 as isize;
+        if let PadMode::Constant(_) = mode {
+            return if 0 <= i && i < n { Some(i as usize) } else { None };
+        }
+        while i < 0 || i >= n {
+            i = match mode {
+                PadMode::Edge => i.max(0).min(n - 1),
+                PadMode::Reflect if n == 1 => 0,
+                PadMode::Reflect if i < 0 => -i,
+                PadMode::Reflect => 2 * (n - 1) - i,
+                PadMode::Symmetric if i < 0 => -i - 1,
+                PadMode::Symmetric => 2 * n - 1 - i,
+                PadMode::Wrap if i < 0 => i + n,
+                PadMode::Wrap => i - n,
+                PadMode::Constant(_) => unreachable!(),
+            };
+        }
+        Some(i as usize)
+    };
+    let dim = (
+        a.len_of(Axis(0)) + widths[0].0 + widths[0].1,
+        a.len_of(Axis(1)) + widths[1].0 + widths[1].1,
+        a.len_of(Axis(2)) + widths[2].0 + widths[2].1,
+    );
+    Array::from_shape_fn(dim, |(i, j, k)| match (source(i, 0), source(j, 1), source(k, 2)) {
+        (Some(i), Some(j), Some(k)) => a[[i, j, k]],
+        _ => match mode {
+            PadMode::Constant(c) => *c,
+            _ => unreachable!(),
+        },
+    })
+}
+
+#[test]
+fn pad_and_padded_3d()
+{
+    let base = Array::from_iter(0..60)
+        .into_shape_with_order((3, 4, 5))
+        .unwrap();
+    let arrays = [
+        base.clone(),
+        base.slice(s![..;-1, ..;2, ..]).to_owned(),
+        base.slice(s![1..2, .., ..]).to_owned(),
+        base.view().permuted_axes([2, 0, 1]).to_owned(),
+    ];
+    let modes = [PadMode::Constant(-1), PadMode::Edge, PadMode::Reflect, PadMode::Symmetric, PadMode::Wrap];
+    let widths = [(0, 0), (2, 1), (7, 9)];
+    for a in &arrays {
+        for w0 in widths {
+            for w2 in widths {
+                let w = [w0, (1, 3), w2];
+                for mode in &modes {
+                    let expected = pad_reference(a, &w, mode);
+                    assert_eq!(a.pad(&w, mode.clone()), expected, "{:?} {:?}", w, mode);
+                    assert_eq!(a.t().pad(&[w2, (1, 3), w0], mode.clone()), expected.t());
+
+                    let padded = a.padded(&w, mode.clone());
+                    assert_eq!(padded.shape(), expected.shape());
+                    assert_eq!(Zip::from(padded).map_collect(|x| x), expected);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn pad_empty()
+{
+    let a = Array2::<f32>::zeros((0, 3));
+    assert_eq!(a.pad(&[(1, 1), (0, 1)], PadMode::Constant(2.)), Array2::from_elem((2, 4), 2.));
+    assert_eq!(a.pad(&[(0, 0), (1, 1)], PadMode::Edge).shape(), &[0, 5]);
+    let b = Array3::<f32>::zeros((2, 0, 2));
+    assert_eq!(b.pad(&[(1, 1), (0, 0), (1, 1)], PadMode::Wrap).shape(), &[4, 0, 4]);
+}
+
+#[test]
+#[should_panic]
+fn pad_empty_axis_edge()
+{
+    let a = Array2::<f32>::zeros((0, 3));
+    let _ = a.pad(&[(1, 0), (0, 0)], PadMode::Edge);
+}
+
+#[test]
+#[should_panic]
+fn pad_widths_mismatch()
+{
+    let a = Array2::<f32>::zeros((2, 3));
+    let _ = a.pad(&[(1, 1)], PadMode::Constant(0.));
+}
+
+#[test]
+fn padded_windows()
+{
+    let a = Array::from_iter(0..35)
+        .into_shape_with_order((5, 7))
+        .unwrap();
+    let kernel = array![[1, 2, 1], [0, 0, 0], [-1, -2, -1]];
+    for mode in [PadMode::Constant(0), PadMode::Edge, PadMode::Reflect, PadMode::Symmetric, PadMode::Wrap] {
+        let w = [(1, 1), (2, 0)];
+        let owned = a.pad(&w, mode.clone());
+        let expected = Zip::from(owned.windows((3, 3))).map_collect(|win| (&win * &kernel).sum());
+        let padded = a.padded(&w, mode);
+        let windows = padded.windows((3, 3));
+        let conv = Zip::from(windows).map_collect(|win| {
+            Zip::from(win)
+                .and(&kernel)
+                .fold(0, |acc, x, &k| acc + x * k)
+        });
+        assert_eq!(conv, expected);
+        assert_eq!(conv.shape(), &[5, 7]);
+
+        let mut out = Array2::zeros((5, 7));
+        Zip::from(&mut out)
+            .and(padded.windows((3, 3)))
+            .for_each(|o, win| *o = Zip::from(win).fold(0, |acc, x| acc + x));
+        assert_eq!(out, Zip::from(owned.windows((3, 3))).map_collect(|win| win.sum()));
+    }
+
+    // Windows larger than the padded array
+    let small = array![[1, 2]];
+    let padded = small.padded(&[(0, 0), (1, 1)], PadMode::Edge);
+    assert_eq!(Zip::from(padded.windows((2, 2))).map_collect(|_| 0).shape(), &[0, 3]);
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn padded_windows_parallel()
+{
+    let a = Array::from_iter(0..10_000)
+        .into_shape_with_order((100, 100))
+        .unwrap();
+    let padded = a.padded(&[(2, 2), (2, 2)], PadMode::Symmetric);
+    let serial = Zip::from(padded.windows((5, 5))).map_collect(|win| Zip::from(win).fold(0, |acc, x| acc + x));
+    let parallel = Zip::from(padded.windows((5, 5))).par_map_collect(|win| Zip::from(win).fold(0, |acc, x| acc + x));
+    assert_eq!(serial, parallel);
+    assert_eq!(serial, Zip::from(a.pad(&[(2, 2), (2, 2)], PadMode::Symmetric).windows((5, 5))).map_collect(|win| win.sum()));
+}
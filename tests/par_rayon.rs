@@ -92,3 +92,46 @@ fn test_axis_chunks_iter_mut()
     println!("{:?}", a.slice(s![..10, ..5]));
     assert_abs_diff_eq!(a, b, epsilon = 0.001);
 }
+
+#[test]
+fn test_par_sort_axis()
+{
+    let a = Array::from_shape_fn((M / 10, N), |(i, j)| ((i * 31 + j * 17) % 101) as i32);
+    for axis in 0..2 {
+        let axis = Axis(axis);
+        let mut b = a.clone();
+        b.sort_axis(axis);
+        let mut c = a.clone();
+        c.par_sort_axis(axis);
+        assert_eq!(b, c);
+        let mut c = a.t().to_owned();
+        c.par_sort_unstable_axis(Axis(1 - axis.index()));
+        assert_eq!(b.t(), c);
+        assert_eq!(a.argsort_axis(axis), a.par_argsort_axis(axis));
+    }
+}
+
+#[test]
+fn test_par_select_nth_and_top_k_axis()
+{
+    let a = Array::from_shape_fn((M / 10, N), |(i, j)| ((i * 31 + j * 17) % 101) as i32);
+    for axis in 0..2 {
+        let axis = Axis(axis);
+        let n = a.len_of(axis) / 3;
+        let mut sorted = a.clone();
+        sorted.sort_axis(axis);
+        for b in [a.clone(), a.t().to_owned().reversed_axes()] {
+            let mut c = b.clone();
+            c.par_select_nth_axis(axis, n);
+            assert_eq!(c.index_axis(axis, n), sorted.index_axis(axis, n));
+            for (lane, kth) in c.lanes(axis).into_iter().zip(&c.index_axis(axis, n)) {
+                assert!(lane.iter().take(n).all(|x| x <= kth));
+                assert!(lane.iter().skip(n + 1).all(|x| x >= kth));
+            }
+
+            for k in [0, 1, 7, b.len_of(axis)] {
+                assert_eq!(b.par_top_k_axis(axis, k), a.top_k_axis(axis, k));
+            }
+        }
+    }
+}
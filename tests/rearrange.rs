@@ -0,0 +1,151 @@
+use ndarray::prelude::*;
+use ndarray::Slice;
+
+#[test]
+fn flip_views()
+{
+    let a = Array::from_iter(0..24)
+        .into_shape_with_order((2, 3, 4))
+        .unwrap();
+    let f = a.flip(&[Axis(0), Axis(2)]);
+    assert_eq!(f.shape(), a.shape());
+    for ((i, j, k), &x) in f.indexed_iter() {
+        assert_eq!(x, a[[1 - i, j, 3 - k]]);
+    }
+    assert_eq!(f.flip(&[Axis(2), Axis(0)]), a);
+    assert_eq!(a.flip(&[]), a);
+    assert_eq!(a.flip(&[Axis(1)]), a.slice(s![.., ..;-1, ..]));
+
+    let e = Array2::<f64>::zeros((0, 3));
+    assert_eq!(e.flip(&[Axis(0), Axis(1)]).shape(), &[0, 3]);
+}
+
+#[test]
+#[should_panic]
+fn flip_repeated_axis()
+{
+    let a = Array2::<f64>::zeros((2, 2));
+    let _ = a.flip(&[Axis(1), Axis(1)]);
+}
+
+#[test]
+fn rot90_views()
+{
+    let a = array![[1, 2, 3], [4, 5, 6]];
+    let plane = (Axis(0), Axis(1));
+    assert_eq!(a.rot90(0, plane), a);
+    assert_eq!(a.rot90(1, plane), array![[3, 6], [2, 5], [1, 4]]);
+    assert_eq!(a.rot90(2, plane), array![[6, 5, 4], [3, 2, 1]]);
+    assert_eq!(a.rot90(3, plane), array![[4, 1], [5, 2], [6, 3]]);
+    assert_eq!(a.rot90(-1, plane), a.rot90(3, plane));
+    assert_eq!(a.rot90(5, plane), a.rot90(1, plane));
+    assert_eq!(a.rot90(1, (Axis(1), Axis(0))), a.rot90(-1, plane));
+    assert_eq!(a.rot90(1, plane).rot90(1, plane).rot90(2, plane), a);
+
+    // Rotation in a plane of non-adjacent axes leaves the other axes alone
+    let b = Array::from_iter(0..24)
+        .into_shape_with_order((2, 3, 4))
+        .unwrap();
+    let r = b.rot90(1, (Axis(0), Axis(2)));
+    assert_eq!(r.shape(), &[4, 3, 2]);
+    for j in 0..3 {
+        assert_eq!(r.index_axis(Axis(1), j), b.index_axis(Axis(1), j).rot90(1, plane));
+    }
+}
+
+#[test]
+#[should_panic]
+fn rot90_same_axes()
+{
+    let a = Array2::<f64>::zeros((2, 2));
+    let _ = a.rot90(1, (Axis(1), Axis(1)));
+}
+
+fn roll_reference(a: &ArrayD<i32>, shift: isize, axis: Axis) -> ArrayD<i32>
+{
+    let n = a.len_of(axis) as isize;
+    let mut res = a.clone();
+    for (index, x) in res.indexed_iter_mut() {
+        let mut from = index.clone();
+        from[axis.index()] = (index[axis.index()] as isize - shift).rem_euclid(n) as usize;
+        *x = a[from];
+    }
+    res
+}
+
+#[test]
+fn roll_and_roll_inplace()
+{
+    let base = Array::from_iter(0..60)
+        .into_shape_with_order((3, 4, 5))
+        .unwrap();
+    let layouts = [
+        base.clone().into_dyn(),
+        base.t().to_owned().into_dyn(),
+        base.slice(s![.., ..;-1, ..]).to_owned().into_dyn(),
+        base.view().permuted_axes([1, 0, 2]).to_owned().into_dyn(),
+    ];
+    for a in &layouts {
+        for axis in 0..a.ndim() {
+            let axis = Axis(axis);
+            for shift in [-7, -1, 0, 1, 2, 4, 11] {
+                let expected = roll_reference(a, shift, axis);
+                assert_eq!(a.roll(shift, axis), expected);
+                let flipped = a.flip(&[Axis(0)]);
+                assert_eq!(flipped.roll(shift, axis), roll_reference(&flipped.to_owned(), shift, axis));
+
+                let mut b = a.clone();
+                b.roll_inplace(shift, axis);
+                assert_eq!(b, expected);
+
+                let mut c = a.clone();
+                c.invert_axis(Axis(2));
+                c.roll_inplace(shift, axis);
+                assert_eq!(c, roll_reference(&a.flip(&[Axis(2)]).to_owned(), shift, axis));
+
+                // Not contiguous: every other element along the last axis
+                let mut shape = a.shape().to_vec();
+                shape[2] *= 2;
+                let mut d = ArrayD::zeros(shape);
+                let mut dv = d.slice_each_axis_mut(|ax| Slice::new(0, None, if ax.axis == Axis(2) { 2 } else { 1 }));
+                dv.assign(a);
+                dv.roll_inplace(shift, axis);
+                assert_eq!(dv, expected);
+            }
+        }
+    }
+
+    // Lanes that are not contiguous in a larger array
+    let mut a = Array::from_iter(0..30)
+        .into_shape_with_order((5, 6))
+        .unwrap();
+    let expected = a.slice(s![1.., ..;2]).roll(2, Axis(1));
+    let mut part = a.slice_mut(s![1.., ..;2]);
+    part.roll_inplace(2, Axis(1));
+    assert_eq!(part, expected);
+    assert_eq!(a.row(0), array![0, 1, 2, 3, 4, 5]);
+    assert_eq!(a.column(1), array![1, 7, 13, 19, 25]);
+}
+
+#[test]
+fn roll_inplace_without_clone()
+{
+    #[derive(Debug, PartialEq)]
+    struct NoClone(u8);
+
+    let mut a = Array::from_shape_fn((2, 3), |(i, j)| NoClone((3 * i + j) as u8));
+    a.roll_inplace(-1, Axis(1));
+    assert_eq!(a.map(|x| x.0), array![[1, 2, 0], [4, 5, 3]]);
+    a.view_mut().reversed_axes().roll_inplace(1, Axis(0));
+    assert_eq!(a.map(|x| x.0), array![[0, 1, 2], [3, 4, 5]]);
+}
+
+#[test]
+fn roll_empty()
+{
+    let mut a = Array2::<f64>::zeros((0, 3));
+    assert_eq!(a.roll(2, Axis(0)).shape(), &[0, 3]);
+    assert_eq!(a.roll(2, Axis(1)).shape(), &[0, 3]);
+    a.roll_inplace(1, Axis(1));
+    assert_eq!(a.shape(), &[0, 3]);
+}
@@ -2,7 +2,7 @@ use ndarray::prelude::*;
 
 use itertools::enumerate;
 
-use ndarray::Order;
+use ndarray::{ErrorKind, Order, ShapeArg, ShapeSpec};
 
 #[test]
 fn reshape()
@@ -329,3 +329,102 @@ fn into_shape_clone()
         assert_eq!(s, array![[1, 5], [2, 6], [3, 7], [4, 8]]);
     }
 }
+
+#[test]
+fn reshape_inferred_axis()
+{
+    let a = Array::from_iter(0..24);
+    for spec in [ShapeSpec::from((-1, 3, 4)), ShapeSpec::from((2, -1, 4)), ShapeSpec::from([2, 3, -1])] {
+        assert_eq!(a.to_shape(spec).unwrap().shape(), &[2, 3, 4]);
+        assert_eq!(a.clone().into_shape_with_order(spec).unwrap().shape(), &[2, 3, 4]);
+        assert_eq!(a.clone().into_shape_clone(spec).unwrap().shape(), &[2, 3, 4]);
+    }
+    assert_eq!(a.to_shape(ShapeSpec::from((2, 3, 4))).unwrap().shape(), &[2, 3, 4]);
+
+    let b = a.to_shape(((3, 8), Order::ColumnMajor)).unwrap();
+    let c = b
+        .to_shape((ShapeSpec::from([-1, 4]), Order::ColumnMajor))
+        .unwrap();
+    assert_eq!(c, b.to_shape(((6, 4), Order::ColumnMajor)).unwrap());
+    let d = b
+        .into_owned()
+        .into_shape_with_order((ShapeSpec::from((2, -1)), Order::F))
+        .unwrap();
+    assert_eq!(d.shape(), &[2, 12]);
+    assert_eq!(d.to_shape(ShapeSpec::from([-1])).unwrap().len(), 24);
+
+    let e = a.to_shape(ShapeSpec::from(vec![2, -1, 2, 3])).unwrap();
+    assert_eq!(e.shape(), &[2, 2, 2, 3]);
+    assert_eq!(e.to_shape(ShapeSpec::from(&[-1][..])).unwrap(), a.view().into_dyn());
+    let f = e.to_shape(ShapeSpec::infer(vec![2, 0], Axis(1))).unwrap();
+    assert_eq!(f.shape(), &[2, 12]);
+
+    // Zero elements: the inferred axis can be found unless the other axes have no elements
+    let z = Array::<f32, _>::zeros((0, 3));
+    assert_eq!(z.to_shape(ShapeSpec::from((3, -1))).unwrap().shape(), &[3, 0]);
+    assert_eq!(z.to_shape(ShapeSpec::from((-1, 1, 3))).unwrap().shape(), &[0, 1, 3]);
+    assert!(z.to_shape(ShapeSpec::from((-1, 0))).is_err());
+
+    // The number of elements is needed to infer the length, not to convert the argument
+    assert_eq!((2, 3).into_shape_and_order(), (Dim([2, 3]), None));
+    assert_eq!(
+        (ShapeSpec::from((2, -1)), Order::F).into_shape_and_order(),
+        (Dim([2, 0]), Some(Order::F))
+    );
+}
+
+#[test]
+fn reshape_inferred_axis_errors()
+{
+    let a = Array::from_iter(0..24);
+    let err = a.to_shape(ShapeSpec::from((5, -1))).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::IncompatibleShape);
+    assert_eq!(
+        err.to_string(),
+        "ShapeError/IncompatibleShape: incompatible shapes: the length of axis 1 can't be inferred, \
+         because 24 elements are not a multiple of 5, the number of elements of the other axes"
+    );
+    let err = a
+        .clone()
+        .into_shape_with_order(ShapeSpec::from((-1, 0)))
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "ShapeError/IncompatibleShape: incompatible shapes: the length of axis 0 can't be inferred \
+         when the other axes have no elements"
+    );
+    let err = a
+        .clone()
+        .into_shape_clone(ShapeSpec::from((-1, isize::MAX, 4)))
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "ShapeError/IncompatibleShape: incompatible shapes: an array of 24 elements can't have a shape with too many elements"
+    );
+
+    // Shapes without an inferred axis tell the sizes too
+    let err = a.to_shape((5, 4)).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "ShapeError/IncompatibleShape: incompatible shapes: an array of 24 elements can't have a shape of 20 elements"
+    );
+    let err = a
+        .clone()
+        .into_shape_with_order(ShapeSpec::from((5, 4)))
+        .unwrap_err();
+    assert!(err.to_string().ends_with("an array of 24 elements can't have a shape of 20 elements"));
+}
+
+#[test]
+#[should_panic(expected = "only one axis can be inferred")]
+fn reshape_two_inferred_axes()
+{
+    let _ = ShapeSpec::from((-1, 2, -1));
+}
+
+#[test]
+#[should_panic(expected = "invalid length -2")]
+fn reshape_negative_length()
+{
+    let _ = ShapeSpec::from([3, -2]);
+}
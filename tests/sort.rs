@@ -0,0 +1,105 @@
+use ndarray::prelude::*;
+
+#[test]
+fn sort_axis_any_layout()
+{
+    let a = array![[5, 3, 8, 1], [2, 9, 4, 7], [6, 0, 3, 3]];
+    let sorted_rows = array![[1, 3, 5, 8], [2, 4, 7, 9], [0, 3, 3, 6]];
+    let sorted_cols = array![[2, 0, 3, 1], [5, 3, 4, 3], [6, 9, 8, 7]];
+
+    let mut b = a.clone();
+    b.sort_axis(Axis(1));
+    assert_eq!(b, sorted_rows);
+    let mut b = a.clone();
+    b.sort_unstable_axis(Axis(0));
+    assert_eq!(b, sorted_cols);
+
+    // transposed and strided lanes
+    let mut at = a.t().to_owned();
+    at.sort_axis(Axis(0));
+    assert_eq!(at, sorted_rows.t());
+    let mut c = Array::zeros((3, 8));
+    c.slice_mut(s![.., ..;2]).assign(&a);
+    c.slice_mut(s![.., ..;2]).sort_unstable_axis(Axis(1));
+    assert_eq!(c.slice(s![.., ..;2]), sorted_rows);
+    assert!(c.slice(s![.., 1..;2]).iter().all(|&x| x == 0));
+
+    let mut e = Array2::<i32>::zeros((0, 3));
+    e.sort_axis(Axis(0));
+    e.sort_axis(Axis(1));
+}
+
+#[test]
+fn sort_axis_by_is_stable()
+{
+    let mut a = array![[(1, 'a'), (0, 'b'), (1, 'c'), (0, 'd')]];
+    a.sort_axis_by(Axis(1), |x, y| x.0.cmp(&y.0));
+    assert_eq!(a, array![[(0, 'b'), (0, 'd'), (1, 'a'), (1, 'c')]]);
+
+    let a = array![[1, 0, 1, 0], [2, 2, 2, 2]];
+    assert_eq!(a.argsort_axis(Axis(1)), array![[1, 3, 0, 2], [0, 1, 2, 3]]);
+    assert_eq!(a.t().argsort_axis(Axis(0)), array![[1, 3, 0, 2], [0, 1, 2, 3]].t());
+    assert_eq!(a.argsort_axis_by(Axis(1), |x, y| y.cmp(x)), array![[0, 2, 1, 3], [0, 1, 2, 3]]);
+}
+
+#[test]
+fn argsort_sorts()
+{
+    let a = Array::from_shape_fn((4, 5, 6), |(i, j, k)| (i * 7 + j * 13 + k * 29) % 17);
+    for axis in 0..3 {
+        let axis = Axis(axis);
+        let indices = a.argsort_axis(axis);
+        let mut sorted = a.clone();
+        sorted.sort_axis(axis);
+        for ((lane, index_lane), sorted_lane) in a
+            .lanes(axis)
+            .into_iter()
+            .zip(indices.lanes(axis))
+            .zip(sorted.lanes(axis))
+        {
+            let gathered: Array1<_> = index_lane.iter().map(|&i| lane[i]).collect();
+            assert_eq!(gathered, sorted_lane);
+        }
+    }
+}
+
+#[test]
+fn select_nth_and_top_k()
+{
+    let a = Array::from_shape_fn((5, 7), |(i, j)| (i * 3 + j * 5) % 11);
+    for n in 0..7 {
+        let mut b = a.t().to_owned();
+        b.select_nth_axis(Axis(0), n);
+        let mut sorted = a.clone();
+        sorted.sort_axis(Axis(1));
+        assert_eq!(b.row(n), sorted.column(n));
+    }
+
+    let (values, indices) = a.top_k_axis(Axis(1), 3);
+    assert_eq!(values.shape(), &[5, 3]);
+    for i in 0..5 {
+        let mut row = a.row(i).to_vec();
+        row.sort_unstable_by(|x, y| y.cmp(x));
+        assert_eq!(values.row(i).to_vec(), &row[..3]);
+        for (&v, &j) in values.row(i).iter().zip(&indices.row(i)) {
+            assert_eq!(a[[i, j]], v);
+        }
+    }
+    let (values, indices) = a.top_k_axis(Axis(0), 0);
+    assert_eq!(values.shape(), &[0, 7]);
+    assert_eq!(indices.shape(), &[0, 7]);
+    let (values, _) = a.top_k_axis(Axis(0), 5);
+    assert_eq!(values.slice(s![..;-1, ..]), {
+        let mut s = a.clone();
+        s.sort_axis(Axis(0));
+        s
+    });
+}
+
+#[test]
+#[should_panic]
+fn top_k_too_large()
+{
+    let a = Array2::<i32>::zeros((2, 3));
+    a.top_k_axis(Axis(1), 4);
+}
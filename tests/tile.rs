@@ -0,0 +1,82 @@
+use ndarray::prelude::*;
+use ndarray::{concatenate, Repeats, Slice};
+
+#[test]
+fn tile_shapes_and_layouts()
+{
+    let a = Array::from_iter(0..6)
+        .into_shape_with_order((2, 3))
+        .unwrap();
+    let views = [a.view(), a.t().reversed_axes(), a.slice(s![..;-1, ..])];
+    for v in &views {
+        let t = v.tile((3, 2));
+        assert_eq!(t.shape(), &[6, 6]);
+        assert!(t.is_standard_layout());
+        for ((i, j), &x) in t.indexed_iter() {
+            assert_eq!(x, v[[i % 2, j % 3]]);
+        }
+    }
+    assert_eq!(a.t().tile((1, 2)), concatenate(Axis(1), &[a.t(), a.t()]).unwrap());
+    assert_eq!(a.tile((1, 1)), a);
+    assert_eq!(a.tile((0, 4)).shape(), &[0, 12]);
+
+    let b = array![1, 2].into_dyn();
+    assert_eq!(b.tile(&[3][..]), array![1, 2, 1, 2, 1, 2].into_dyn());
+    let c = Array3::<u8>::zeros((2, 0, 1));
+    assert_eq!(c.tile((2, 5, 3)).shape(), &[4, 0, 3]);
+}
+
+#[test]
+#[should_panic]
+fn tile_reps_mismatch()
+{
+    let a = array![1, 2].into_dyn();
+    let _ = a.tile(&[2, 2][..]);
+}
+
+/// Repeat the subviews of `a` along `axis` by concatenating them.
+fn repeat_reference(a: &Array3<i32>, counts: &[usize], axis: Axis) -> Array3<i32>
+{
+    let views: Vec<_> = counts
+        .iter()
+        .enumerate()
+        .flat_map(|(i, &c)| std::iter::repeat(a.slice_axis(axis, Slice::from(i..i + 1))).take(c))
+        .collect();
+    if views.is_empty() {
+        let mut dim = a.raw_dim();
+        dim[axis.index()] = 0;
+        return Array3::zeros(dim);
+    }
+    concatenate(axis, &views).unwrap()
+}
+
+#[test]
+fn repeat_each_and_counts()
+{
+    let a = Array::from_iter(0..24)
+        .into_shape_with_order((2, 3, 4))
+        .unwrap();
+    for axis in 0..3 {
+        let axis = Axis(axis);
+        let n = a.len_of(axis);
+        for r in [0, 1, 3] {
+            let res = a.repeat(r, axis);
+            assert_eq!(res, repeat_reference(&a, &vec![r; n], axis));
+            assert!(res.is_standard_layout());
+            assert_eq!(a.t().repeat(r, Axis(2 - axis.index())), res.t());
+        }
+
+        let counts: Vec<usize> = (0..n).map(|i| (i * 7) % 3).collect();
+        assert_eq!(a.repeat(&counts, axis), repeat_reference(&a, &counts, axis));
+        assert_eq!(a.repeat(Repeats::Counts(&vec![2; n]), axis), a.repeat(2, axis));
+    }
+    assert_eq!(array![1, 2, 3].repeat(&[1, 0, 2], Axis(0)), array![1, 3, 3]);
+}
+
+#[test]
+#[should_panic]
+fn repeat_counts_mismatch()
+{
+    let a = array![1, 2, 3];
+    let _ = a.repeat(&[1, 2], Axis(0));
+}
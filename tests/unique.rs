@@ -0,0 +1,72 @@
+use ndarray::prelude::*;
+
+#[test]
+fn unique_sorted()
+{
+    let a = array![4, 1, 4, 0, 1, 4];
+    assert_eq!(a.unique(), array![0, 1, 4]);
+    let (unique, counts) = a.unique_counts();
+    assert_eq!(unique, array![0, 1, 4]);
+    assert_eq!(counts, array![1, 2, 3]);
+    let (unique, inverse) = a.slice(s![..;-1]).unique_inverse();
+    assert_eq!(unique, array![0, 1, 4]);
+    assert_eq!(inverse, array![2, 1, 0, 2, 1, 2]);
+
+    let e = Array1::<i32>::zeros(0);
+    assert_eq!(e.unique(), e);
+    assert_eq!(e.unique_counts().1, Array1::<usize>::zeros(0));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn unique_by_hash_first_occurrence()
+{
+    let a = array![[4, 1], [4, 0], [1, 4]];
+    let col = a.column(0);
+    assert_eq!(col.unique_by_hash(), array![4, 1]);
+    let (unique, counts) = a.row(2).unique_counts_by_hash();
+    assert_eq!((unique, counts), (array![1, 4], array![1, 1]));
+    let flat = a.flatten();
+    let (unique, inverse) = flat.unique_inverse_by_hash();
+    assert_eq!(unique, array![4, 1, 0]);
+    assert_eq!(inverse, array![0, 1, 0, 2, 1, 0]);
+    assert_eq!(unique.select(Axis(0), inverse.as_slice().unwrap()), flat);
+}
+
+#[test]
+fn unique_axis_rows_and_columns()
+{
+    let a = array![[1, 2, 1], [3, 0, 3], [1, 2, 1], [0, 9, 0]];
+    assert_eq!(a.unique_axis(Axis(0)), array![[0, 9, 0], [1, 2, 1], [3, 0, 3]]);
+    let (unique, inverse) = a.unique_axis_inverse(Axis(1));
+    assert_eq!(unique, array![[1, 2], [3, 0], [1, 2], [0, 9]]);
+    assert_eq!(inverse, array![0, 1, 0]);
+    assert_eq!(a.t().unique_axis(Axis(1)), a.unique_axis(Axis(0)).t());
+
+    let b = Array3::from_shape_fn((3, 2, 2), |(i, j, k)| (i % 2) * 10 + j + k);
+    let (unique, inverse) = b.unique_axis_inverse(Axis(0));
+    assert_eq!(unique, b.slice(s![..2, .., ..]));
+    assert_eq!(inverse, array![0, 1, 0]);
+
+    let e = Array2::<i32>::zeros((0, 3));
+    assert_eq!(e.unique_axis(Axis(0)).shape(), &[0, 3]);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn unique_axis_by_hash_first_occurrence()
+{
+    let a = array![[1, 2, 1], [3, 0, 3], [1, 2, 1], [0, 9, 0]];
+    assert_eq!(a.unique_axis_by_hash(Axis(0)), array![[1, 2, 1], [3, 0, 3], [0, 9, 0]]);
+    let (unique, inverse) = a.unique_axis_inverse_by_hash(Axis(1));
+    assert_eq!(unique, array![[1, 2], [3, 0], [1, 2], [0, 9]]);
+    assert_eq!(inverse, array![0, 1, 0]);
+    assert_eq!(a.t().unique_axis_by_hash(Axis(1)), a.unique_axis_by_hash(Axis(0)).t());
+
+    let (unique, inverse) = a.slice(s![..;-1, ..]).unique_axis_inverse_by_hash(Axis(0));
+    assert_eq!(unique, array![[0, 9, 0], [1, 2, 1], [3, 0, 3]]);
+    assert_eq!(inverse, array![0, 1, 2, 1]);
+
+    let e = Array2::<i32>::zeros((0, 3));
+    assert_eq!(e.unique_axis_by_hash(Axis(0)).shape(), &[0, 3]);
+}